@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::model::problem::{ContestSeries, DifficultyBand, Problem};
+
+// 解説のない問題へのリクエスト。1人1問につき1件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplanationRequest {
+    pub user_id: String,
+    pub problem_id: String,
+    // 最初に投稿された解説。投稿されるとリクエストした人に通知される
+    pub fulfilled_solution_id: Option<Uuid>,
+    pub fulfilled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WantedProblemFilter {
+    pub series: ContestSeries,
+    pub band: Option<DifficultyBand>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+// 解説がなくリクエストのある問題
+#[derive(Debug, Clone)]
+pub struct WantedProblem {
+    pub problem: Problem,
+    pub request_count: i64,
+    pub last_requested_at: DateTime<Utc>,
+}
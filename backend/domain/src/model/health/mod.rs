@@ -0,0 +1,13 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub applied_version: Option<i64>,
+    // バイナリに埋め込まれたマイグレーションの最新バージョン
+    pub expected_version: Option<i64>,
+    pub pending_versions: Vec<i64>,
+}
+
+impl MigrationStatus {
+    pub fn is_behind(&self) -> bool {
+        !self.pending_versions.is_empty()
+    }
+}
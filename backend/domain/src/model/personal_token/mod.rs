@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+// Firebase ID tokenと区別するためのprefix
+pub const PERSONAL_TOKEN_PREFIX: &str = "aspat_";
+
+#[derive(Debug, Display, EnumString, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenScope {
+    #[strum(serialize = "read")]
+    Read,
+    #[strum(serialize = "write:solutions")]
+    WriteSolutions,
+    #[strum(serialize = "write:comments")]
+    WriteComments,
+}
+
+#[derive(Debug, Clone)]
+pub struct PersonalToken {
+    pub id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    // 一覧で見分けるために平文の先頭だけ保持する
+    pub token_prefix: String,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewPersonalToken {
+    pub id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub token_hash: String,
+    pub token_prefix: String,
+}
@@ -1,91 +1,619 @@
-use strum::Display;
+use std::{borrow::Cow, cmp::Ordering, fmt::Display};
+
+use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
+use strum::{Display, EnumString};
 use thiserror::Error;
 
+pub mod url;
+
 #[derive(Clone, Debug)]
 pub struct Problem {
     pub id: String,
     pub contest_code: String,
-    pub problem_index: String,
+    pub problem_index: ProblemIndex,
     pub title: String,
     pub difficulty: Option<i32>,
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Display)]
-pub enum ContestSeries {
-    ABC,
-    ARC,
-    AGC,
-    AHC,
-    AWC,
-    OTHER,
-}
-
-impl TryFrom<&str> for ContestSeries {
-    type Error = ContestSeriesParseError;
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let lower = value.to_ascii_lowercase();
-        if lower.starts_with("abc") {
-            Ok(ContestSeries::ABC)
-        } else if lower.starts_with("arc") {
-            Ok(ContestSeries::ARC)
-        } else if lower.starts_with("agc") {
-            Ok(ContestSeries::AGC)
-        } else if lower.starts_with("ahc") {
-            Ok(ContestSeries::AHC)
-        } else if lower.starts_with("awc") {
-            Ok(ContestSeries::AWC)
-        } else if lower.starts_with("other") {
-            Ok(ContestSeries::OTHER)
-        } else {
-            Err(ContestSeriesParseError::Invalid {
-                input: value.into(),
-            })
+// 問題のindex。"A".."H", "Ex"のほか、"1"のような数字、"a1"のような英数字、"AA"のような2文字のものがある
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProblemIndex(String);
+
+impl ProblemIndex {
+    pub fn new(index: impl Into<String>) -> Self {
+        Self(index.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ProblemIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for ProblemIndex {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for ProblemIndex {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<ProblemIndex> for String {
+    fn from(value: ProblemIndex) -> Self {
+        value.0
+    }
+}
+
+// 数字と英字の並びごとに比べる。数字は数の大きさで、英字は短い順("Z" < "AA")に大文字小文字を区別せずに比べ、
+// "Ex"は"H"の次に置く。数字は英字より前に置く
+impl Ord for ProblemIndex {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut a_chunks = natural_chunks(&self.0);
+        let mut b_chunks = natural_chunks(&other.0);
+        loop {
+            let ordering = match (a_chunks.next(), b_chunks.next()) {
+                (None, None) => return self.0.cmp(&other.0),
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(x), Some(y)) => match (is_digits(x), is_digits(y)) {
+                    (true, true) => cmp_digits(x, y),
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => letters_key(x).cmp(&letters_key(y)),
+                },
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
         }
     }
 }
-impl TryFrom<String> for ContestSeries {
-    type Error = ContestSeriesParseError;
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        ContestSeries::try_from(value.as_str())
+
+impl PartialOrd for ProblemIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl From<ContestSeries> for String {
-    fn from(value: ContestSeries) -> Self {
-        match value {
-            ContestSeries::ABC => "ABC".into(),
-            ContestSeries::ARC => "ARC".into(),
-            ContestSeries::AGC => "AGC".into(),
-            ContestSeries::AHC => "AHC".into(),
-            ContestSeries::AWC => "AWC".into(),
-            _ => "OTHER".into(),
+// (文字数, 小文字にした並び, Exかどうか)
+fn letters_key(chunk: &str) -> (usize, String, bool) {
+    if chunk.eq_ignore_ascii_case("ex") {
+        return (1, "h".to_string(), true);
+    }
+    (chunk.chars().count(), chunk.to_ascii_lowercase(), false)
+}
+
+// コンテストでの出題。ADTやABC/ARCの同時開催では同じ問題が複数のコンテストに出題される
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProblemAppearance {
+    pub contest_code: String,
+    pub problem_index: ProblemIndex,
+    // 出題された問題のid。解説はこのidに紐づく
+    pub problem_id: String,
+}
+
+// ADTの問題は元のコンテストの問題として扱う。問題idの"_"より前が元のコンテスト
+pub fn contest_code_from_problem(problem_id: &str, contest_id: &str) -> String {
+    if !contest_id.starts_with("adt_") {
+        return contest_id.to_string();
+    }
+
+    problem_id
+        .rsplit_once('_')
+        .map(|(contest_code, _)| contest_code.to_string())
+        .unwrap_or_else(|| contest_id.to_string())
+}
+
+pub fn problem_index_from_problem(
+    problem_id: &str,
+    contest_id: &str,
+    problem_index: &str,
+) -> String {
+    if !contest_id.starts_with("adt_") {
+        return problem_index.to_string();
+    }
+
+    problem_id
+        .rsplit_once('_')
+        .map(|(_, derived_problem_index)| derived_problem_index.to_ascii_uppercase())
+        .unwrap_or_else(|| problem_index.to_string())
+}
+
+// AtCoder Problemsの表示と同じく、400未満のdifficultyは400 / exp(1 - d / 400)に補正する
+pub fn clip_difficulty(difficulty: i32) -> i32 {
+    if difficulty >= 400 {
+        return difficulty;
+    }
+    (400.0 / (1.0 - f64::from(difficulty) / 400.0).exp()).round() as i32
+}
+
+// difficultyの絞り込み。補正後の値で比較し、両端を含む
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DifficultyRange {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+}
+
+impl DifficultyRange {
+    pub fn new(min: Option<i32>, max: Option<i32>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.min.is_none() && self.max.is_none()
+    }
+
+    // difficultyのない問題は範囲の指定があれば含まない
+    pub fn contains(&self, difficulty: Option<i32>) -> bool {
+        if self.is_unbounded() {
+            return true;
         }
+        difficulty.map(clip_difficulty).is_some_and(|clipped| {
+            self.min.is_none_or(|min| clipped >= min) && self.max.is_none_or(|max| clipped <= max)
+        })
     }
 }
 
-impl From<ContestSeries> for &str {
-    fn from(value: ContestSeries) -> Self {
-        match value {
-            ContestSeries::ABC => "ABC",
-            ContestSeries::ARC => "ARC",
-            ContestSeries::AGC => "AGC",
-            ContestSeries::AHC => "AHC",
-            ContestSeries::AWC => "AWC",
-            _ => "OTHER",
+// 補正後のdifficultyの色帯。400刻みで、2800以上はすべてred
+#[derive(Debug, Display, EnumString, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum DifficultyBand {
+    Gray,
+    Brown,
+    Green,
+    Cyan,
+    Blue,
+    Yellow,
+    Orange,
+    Red,
+}
+
+impl DifficultyBand {
+    const ALL: [DifficultyBand; 8] = [
+        DifficultyBand::Gray,
+        DifficultyBand::Brown,
+        DifficultyBand::Green,
+        DifficultyBand::Cyan,
+        DifficultyBand::Blue,
+        DifficultyBand::Yellow,
+        DifficultyBand::Orange,
+        DifficultyBand::Red,
+    ];
+
+    pub fn from_difficulty(difficulty: i32) -> Self {
+        let step = (clip_difficulty(difficulty) / 400) as usize;
+        Self::ALL[step.min(Self::ALL.len() - 1)]
+    }
+
+    // この色帯に入る補正後のdifficultyの範囲。下限は含み上限は含まない。grayは下限なし、redは上限なし
+    pub fn difficulty_range(self) -> (Option<i32>, Option<i32>) {
+        let step = self as i32;
+        let lower = (self != DifficultyBand::Gray).then_some(step * 400);
+        let upper = (self != DifficultyBand::Red).then_some((step + 1) * 400);
+        (lower, upper)
+    }
+}
+
+// AtCoder Problemsのcontests.json由来のコンテスト情報
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContestMetadata {
+    pub code: String,
+    pub title: String,
+    pub start_at: DateTime<Utc>,
+    pub duration_second: i64,
+    // レート変動の対象範囲。"-"はunrated、"All"は全員、" ~ 1999"のように範囲で入る
+    pub rate_change: String,
+}
+
+// コンテストの並び順。開始時刻の古い順で、開始時刻が分からないものはそれより前に置く。
+// 開始時刻が同じか分からない場合はコードを数字の大きさで比べる
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContestSortKey {
+    pub start_at: Option<DateTime<Utc>>,
+    pub code: String,
+}
+
+impl Ord for ContestSortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.start_at
+            .cmp(&other.start_at)
+            .then_with(|| natural_cmp(&self.code, &other.code))
+    }
+}
+
+impl PartialOrd for ContestSortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// 数字の並びを数として比べる("abc99" < "abc400")。英字は大文字小文字を区別しない
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chunks = natural_chunks(a);
+    let mut b_chunks = natural_chunks(b);
+    loop {
+        let ordering = match (a_chunks.next(), b_chunks.next()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (is_digits(x), is_digits(y)) {
+                (true, true) => cmp_digits(x, y),
+                _ => x
+                    .chars()
+                    .map(|c| c.to_ascii_lowercase())
+                    .cmp(y.chars().map(|c| c.to_ascii_lowercase())),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
         }
     }
 }
 
+fn cmp_digits(x: &str, y: &str) -> Ordering {
+    let x = x.trim_start_matches('0');
+    let y = y.trim_start_matches('0');
+    x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+}
+
+fn is_digits(chunk: &str) -> bool {
+    chunk.bytes().all(|b| b.is_ascii_digit())
+}
+
+// 数字とそれ以外の境目で区切る
+fn natural_chunks(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let digits = first.is_ascii_digit();
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != digits)
+            .unwrap_or(rest.len());
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
+// contest_seriesのcode。定義はDBにあり、ContestSeriesCatalogで解決する
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct ContestSeries(Cow<'static, str>);
+
+impl ContestSeries {
+    pub const ABC: ContestSeries = ContestSeries(Cow::Borrowed("ABC"));
+    pub const ARC: ContestSeries = ContestSeries(Cow::Borrowed("ARC"));
+    pub const AGC: ContestSeries = ContestSeries(Cow::Borrowed("AGC"));
+    pub const AHC: ContestSeries = ContestSeries(Cow::Borrowed("AHC"));
+    pub const AWC: ContestSeries = ContestSeries(Cow::Borrowed("AWC"));
+    // どの定義にも当てはまらないコンテストの行き先。削除できない
+    pub const OTHER: ContestSeries = ContestSeries(Cow::Borrowed("OTHER"));
+
+    pub fn new(code: impl Into<String>) -> Self {
+        Self(Cow::Owned(code.into().to_ascii_uppercase()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ContestSeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<ContestSeries> for String {
+    fn from(value: ContestSeries) -> Self {
+        value.0.into_owned()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContestSeriesDefinition {
+    pub code: ContestSeries,
+    pub display_name: String,
+    // コンテストのcodeに対する正規表現。OTHERのみ持たない
+    pub code_pattern: Option<String>,
+    // 小さいものから順に照合する
+    pub sort_order: i32,
+}
+
+// 既存コンテストのシリーズの付け替え
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContestReclassification {
+    pub contest_code: String,
+    pub from: ContestSeries,
+    pub to: ContestSeries,
+}
+
+// 起動時にcontest_seriesから読み込み、コンテストの分類とパスのシリーズ解決に使う
+#[derive(Clone, Debug)]
+pub struct ContestSeriesCatalog {
+    definitions: Vec<ContestSeriesDefinition>,
+    patterns: Vec<(ContestSeries, Regex)>,
+}
+
+impl ContestSeriesCatalog {
+    pub fn new(
+        mut definitions: Vec<ContestSeriesDefinition>,
+    ) -> Result<Self, ContestSeriesParseError> {
+        definitions.sort_by(|a, b| {
+            a.sort_order
+                .cmp(&b.sort_order)
+                .then_with(|| a.code.as_str().cmp(b.code.as_str()))
+        });
+        let patterns = definitions
+            .iter()
+            .filter_map(|definition| {
+                definition
+                    .code_pattern
+                    .as_deref()
+                    .map(|pattern| (definition.code.clone(), pattern))
+            })
+            .map(|(code, pattern)| {
+                RegexBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map(|regex| (code, regex))
+                    .map_err(|e| ContestSeriesParseError::InvalidPattern {
+                        pattern: pattern.to_string(),
+                        message: e.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            definitions,
+            patterns,
+        })
+    }
+
+    pub fn definitions(&self) -> &[ContestSeriesDefinition] {
+        &self.definitions
+    }
+
+    // パスなどで受け取ったシリーズ名。大文字小文字は区別しない
+    pub fn parse(&self, input: &str) -> Result<ContestSeries, ContestSeriesParseError> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.code.as_str().eq_ignore_ascii_case(input.trim()))
+            .map(|definition| definition.code.clone())
+            .ok_or_else(|| ContestSeriesParseError::Invalid {
+                input: input.into(),
+            })
+    }
+
+    // 最初に当てはまったシリーズ。どれにも当てはまらなければNone
+    pub fn find(&self, contest_code: &str) -> Option<&ContestSeries> {
+        self.patterns
+            .iter()
+            .find(|(_, regex)| regex.is_match(contest_code))
+            .map(|(code, _)| code)
+    }
+
+    pub fn classify(&self, contest_code: &str) -> ContestSeries {
+        self.find(contest_code)
+            .cloned()
+            .unwrap_or(ContestSeries::OTHER)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ContestSeriesParseError {
     #[error("invalid contest series: {input}")]
     Invalid { input: String },
+    #[error("invalid contest series pattern {pattern}: {message}")]
+    InvalidPattern { pattern: String, message: String },
 }
 
 impl ContestSeriesParseError {
     pub fn msg(&self) -> String {
         match self {
             ContestSeriesParseError::Invalid { input } => input.to_string(),
+            ContestSeriesParseError::InvalidPattern { .. } => self.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{
+        ContestSeries, ContestSeriesCatalog, ContestSeriesDefinition, ContestSortKey,
+        DifficultyBand, DifficultyRange, ProblemIndex, clip_difficulty, contest_code_from_problem,
+        natural_cmp, problem_index_from_problem,
+    };
+
+    fn sorted(indexes: &[&str]) -> Vec<String> {
+        let mut indexes = indexes
+            .iter()
+            .map(|&index| ProblemIndex::from(index))
+            .collect::<Vec<_>>();
+        indexes.sort();
+        indexes.into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn problem_index_puts_ex_after_h() {
+        assert_eq!(
+            sorted(&["Ex", "H", "B", "A", "G"]),
+            vec!["A", "B", "G", "H", "Ex"]
+        );
+        assert_eq!(sorted(&["ex", "g", "a"]), vec!["a", "g", "ex"]);
+    }
+
+    #[test]
+    fn problem_index_compares_numbers_by_value() {
+        assert_eq!(sorted(&["10", "2", "1", "02"]), vec!["1", "02", "2", "10"]);
+        assert_eq!(
+            sorted(&["b1", "a10", "a2", "a77", "a1"]),
+            vec!["a1", "a2", "a10", "a77", "b1"]
+        );
+    }
+
+    #[test]
+    fn problem_index_orders_longer_letters_later() {
+        assert_eq!(
+            sorted(&["AB", "Z", "AA", "B", "CL", "A"]),
+            vec!["A", "B", "Z", "AA", "AB", "CL"]
+        );
+        assert_eq!(sorted(&["A", "a"]), vec!["A", "a"]);
+        assert_eq!(
+            ProblemIndex::from("A").cmp(&ProblemIndex::from("A")),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn natural_cmp_compares_numbers_by_value() {
+        assert_eq!(natural_cmp("abc99", "abc400"), Ordering::Less);
+        assert_eq!(natural_cmp("abc400", "abc399"), Ordering::Greater);
+        assert_eq!(natural_cmp("ABC100", "abc100"), Ordering::Less);
+        assert_eq!(natural_cmp("abc001", "abc1"), Ordering::Less);
+        assert_eq!(natural_cmp("arc100", "abc200"), Ordering::Greater);
+        assert_eq!(natural_cmp("typical90", "typical90_b"), Ordering::Less);
+        assert_eq!(natural_cmp("abc", "abc"), Ordering::Equal);
+    }
+
+    #[test]
+    fn contest_sort_key_orders_by_start_time_then_code() {
+        let key = |code: &str, start_at: Option<&str>| ContestSortKey {
+            start_at: start_at.map(|s| s.parse().unwrap()),
+            code: code.into(),
+        };
+        let mut keys = [
+            key("abc400", Some("2025-04-05T12:00:00Z")),
+            key("practice2", None),
+            key("abc99", Some("2018-04-28T12:00:00Z")),
+            key("past10", None),
+            key("past9", None),
+        ];
+        keys.sort();
+        let codes = keys.iter().map(|k| k.code.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec!["past9", "past10", "practice2", "abc99", "abc400"]
+        );
+    }
+
+    fn definition(code: &str, pattern: Option<&str>, sort_order: i32) -> ContestSeriesDefinition {
+        ContestSeriesDefinition {
+            code: ContestSeries::new(code),
+            display_name: code.into(),
+            code_pattern: pattern.map(Into::into),
+            sort_order,
+        }
+    }
+
+    #[test]
+    fn catalog_classifies_by_sort_order_and_falls_back_to_other() {
+        let catalog = ContestSeriesCatalog::new(vec![
+            definition("OTHER", None, 1000),
+            definition("ABC", Some("^abc"), 10),
+            definition("PAST", Some(r"^past\d+"), 60),
+            definition("ABCLIKE", Some("^abc"), 20),
+        ])
+        .unwrap();
+
+        assert_eq!(catalog.classify("abc400"), ContestSeries::ABC);
+        assert_eq!(catalog.classify("PAST202412"), ContestSeries::new("PAST"));
+        assert_eq!(catalog.classify("typical90"), ContestSeries::OTHER);
+        assert!(catalog.find("typical90").is_none());
+        assert_eq!(catalog.definitions()[0].code, ContestSeries::ABC);
+    }
+
+    #[test]
+    fn catalog_parses_known_codes_only() {
+        let catalog = ContestSeriesCatalog::new(vec![
+            definition("ABC", Some("^abc"), 10),
+            definition("OTHER", None, 1000),
+        ])
+        .unwrap();
+
+        assert_eq!(catalog.parse("abc").unwrap(), ContestSeries::ABC);
+        assert_eq!(catalog.parse("Other").unwrap(), ContestSeries::OTHER);
+        assert!(catalog.parse("abcd").is_err());
+        assert!(ContestSeriesCatalog::new(vec![definition("BROKEN", Some("(abc"), 1)]).is_err());
+    }
+
+    #[test]
+    fn difficulty_band_follows_400_steps() {
+        assert_eq!(DifficultyBand::from_difficulty(-1200), DifficultyBand::Gray);
+        assert_eq!(DifficultyBand::from_difficulty(399), DifficultyBand::Gray);
+        assert_eq!(DifficultyBand::from_difficulty(800), DifficultyBand::Green);
+        assert_eq!(DifficultyBand::from_difficulty(1599), DifficultyBand::Cyan);
+        assert_eq!(DifficultyBand::from_difficulty(4000), DifficultyBand::Red);
+
+        assert_eq!(DifficultyBand::Gray.difficulty_range(), (None, Some(400)));
+        assert_eq!(
+            DifficultyBand::Cyan.difficulty_range(),
+            (Some(1200), Some(1600))
+        );
+        assert_eq!(DifficultyBand::Red.difficulty_range(), (Some(2800), None));
+        assert_eq!(
+            "Green".parse::<DifficultyBand>().unwrap(),
+            DifficultyBand::Green
+        );
+        assert!("silver".parse::<DifficultyBand>().is_err());
+    }
+
+    #[test]
+    fn low_difficulties_are_clipped_like_atcoder_problems() {
+        assert_eq!(clip_difficulty(400), 400);
+        assert_eq!(clip_difficulty(2345), 2345);
+        assert_eq!(clip_difficulty(399), 399);
+        assert_eq!(clip_difficulty(0), 147);
+        assert_eq!(clip_difficulty(-1000), 12);
+        assert!(clip_difficulty(-100_000) >= 0);
+
+        let range = DifficultyRange::new(Some(100), Some(799));
+        assert!(range.contains(Some(-100)));
+        assert!(!range.contains(Some(-1000)));
+        assert!(range.contains(Some(799)));
+        assert!(!range.contains(Some(800)));
+        assert!(!range.contains(None));
+        assert!(DifficultyRange::default().contains(None));
+    }
+
+    #[test]
+    fn contest_code_and_problem_index_are_rewritten_only_for_adt() {
+        for (problem_id, contest_id, problem_index, expected_contest, expected_index) in [
+            ("abc395_a", "adt_easy_20250430_3", "B", "abc395", "A"),
+            ("abc001_1", "abc001", "A", "abc001", "A"),
+            ("abc007_3", "atc002", "A", "atc002", "A"),
+            (
+                "tessoku_book_fj",
+                "tessoku-book",
+                "a1",
+                "tessoku-book",
+                "a1",
+            ),
+            ("APG4bPython_ak", "APG4bPython", "A", "APG4bPython", "A"),
+            (
+                "problem-without-separator",
+                "fallback_contest",
+                "A",
+                "fallback_contest",
+                "A",
+            ),
+        ] {
+            let contest = contest_code_from_problem(problem_id, contest_id);
+            let index = problem_index_from_problem(problem_id, contest_id, problem_index);
+            assert_eq!(
+                (contest.as_str(), index.as_str()),
+                (expected_contest, expected_index)
+            );
         }
     }
 }
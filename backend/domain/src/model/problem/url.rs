@@ -0,0 +1,207 @@
+use thiserror::Error;
+
+use super::contest_code_from_problem;
+
+// 拡張機能やユーザーが貼るAtCoderのURLから取り出した問題
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtcoderTaskUrl {
+    // URLに含まれるコンテスト。ADTのコンテストのこともある
+    pub contest_code: String,
+    // AtCoderのtask screen name。問題のidと同じ
+    pub task_id: String,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AtcoderTaskUrlError {
+    #[error("url is not an AtCoder url: {0}")]
+    NotAtcoder(String),
+    #[error("url does not point to a task: {0}")]
+    NoTask(String),
+    #[error("submission url does not name its task: {0}")]
+    SubmissionWithoutTask(String),
+}
+
+impl AtcoderTaskUrl {
+    // 問題ページ、提出一覧(f.Task)、提出ページ(taskScreenName)と旧形式の問題ページを受け付ける
+    pub fn parse(input: &str) -> Result<Self, AtcoderTaskUrlError> {
+        let url = input.trim();
+        let without_scheme = strip_prefix_ignore_case(url, "https://")
+            .or_else(|| strip_prefix_ignore_case(url, "http://"))
+            .unwrap_or(url);
+        let without_fragment = without_scheme
+            .split_once('#')
+            .map_or(without_scheme, |(rest, _)| rest);
+        let (location, query) = without_fragment
+            .split_once('?')
+            .unwrap_or((without_fragment, ""));
+        let (host, path) = location.split_once('/').unwrap_or((location, ""));
+        let host = host.to_ascii_lowercase();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let not_a_task = || AtcoderTaskUrlError::NoTask(url.to_string());
+        let (contest_code, task_id) = if host == "atcoder.jp" || host == "www.atcoder.jp" {
+            match segments.as_slice() {
+                ["contests", contest, "tasks", task] => (*contest, *task),
+                ["contests", contest, "submissions", ..] => {
+                    let task = query_value(query, "f.Task").ok_or_else(|| {
+                        AtcoderTaskUrlError::SubmissionWithoutTask(url.to_string())
+                    })?;
+                    (*contest, task)
+                }
+                ["contests", contest, "submit"] => (
+                    *contest,
+                    query_value(query, "taskScreenName").ok_or_else(not_a_task)?,
+                ),
+                _ => return Err(not_a_task()),
+            }
+        } else if let Some(contest) = host.strip_suffix(".contest.atcoder.jp") {
+            match segments.as_slice() {
+                ["tasks", task] => (contest, *task),
+                _ => return Err(not_a_task()),
+            }
+        } else {
+            return Err(AtcoderTaskUrlError::NotAtcoder(url.to_string()));
+        };
+
+        if !is_screen_name(contest_code) || !is_screen_name(task_id) {
+            return Err(not_a_task());
+        }
+        Ok(Self {
+            contest_code: contest_code.to_string(),
+            task_id: task_id.to_string(),
+        })
+    }
+
+    // ADTなら元のコンテスト
+    pub fn canonical_contest_code(&self) -> String {
+        contest_code_from_problem(&self.task_id, &self.contest_code)
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+fn query_value<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v)
+        .filter(|v| !v.is_empty())
+}
+
+fn is_screen_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AtcoderTaskUrl, AtcoderTaskUrlError};
+
+    #[test]
+    fn parses_task_submission_and_legacy_urls() {
+        for (url, contest, task) in [
+            (
+                "https://atcoder.jp/contests/abc300/tasks/abc300_d",
+                "abc300",
+                "abc300_d",
+            ),
+            (
+                "  https://atcoder.jp/contests/abc300/tasks/abc300_d?lang=en  ",
+                "abc300",
+                "abc300_d",
+            ),
+            (
+                "atcoder.jp/contests/abc300/tasks/abc300_d/",
+                "abc300",
+                "abc300_d",
+            ),
+            (
+                "https://atcoder.jp/contests/abc300/submissions?f.Task=abc300_d&f.LanguageName=&f.Status=AC",
+                "abc300",
+                "abc300_d",
+            ),
+            (
+                "https://atcoder.jp/contests/abc300/submissions/me?f.Task=abc300_d",
+                "abc300",
+                "abc300_d",
+            ),
+            (
+                "https://atcoder.jp/contests/abc300/submit?taskScreenName=abc300_d",
+                "abc300",
+                "abc300_d",
+            ),
+            (
+                "http://abc001.contest.atcoder.jp/tasks/abc001_1",
+                "abc001",
+                "abc001_1",
+            ),
+            (
+                "https://atcoder.jp/contests/tessoku-book/tasks/tessoku_book_a#section",
+                "tessoku-book",
+                "tessoku_book_a",
+            ),
+        ] {
+            let parsed = AtcoderTaskUrl::parse(url).expect(url);
+            assert_eq!(
+                (parsed.contest_code.as_str(), parsed.task_id.as_str()),
+                (contest, task),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn adt_urls_resolve_to_the_original_contest() {
+        let parsed = AtcoderTaskUrl::parse(
+            "https://atcoder.jp/contests/adt_easy_20250430_3/tasks/abc395_a?lang=ja",
+        )
+        .unwrap();
+        assert_eq!(parsed.contest_code, "adt_easy_20250430_3");
+        assert_eq!(parsed.task_id, "abc395_a");
+        assert_eq!(parsed.canonical_contest_code(), "abc395");
+
+        let parsed =
+            AtcoderTaskUrl::parse("https://atcoder.jp/contests/abc300/tasks/abc300_d").unwrap();
+        assert_eq!(parsed.canonical_contest_code(), "abc300");
+    }
+
+    #[test]
+    fn rejects_urls_without_a_task() {
+        for (url, expected) in [
+            (
+                "https://example.com/contests/abc300/tasks/abc300_d",
+                AtcoderTaskUrlError::NotAtcoder(
+                    "https://example.com/contests/abc300/tasks/abc300_d".into(),
+                ),
+            ),
+            (
+                "https://atcoder.jp/contests/abc300",
+                AtcoderTaskUrlError::NoTask("https://atcoder.jp/contests/abc300".into()),
+            ),
+            (
+                "https://atcoder.jp/contests/abc300/tasks",
+                AtcoderTaskUrlError::NoTask("https://atcoder.jp/contests/abc300/tasks".into()),
+            ),
+            (
+                "https://atcoder.jp/contests/abc300/tasks/abc300%2Fd",
+                AtcoderTaskUrlError::NoTask(
+                    "https://atcoder.jp/contests/abc300/tasks/abc300%2Fd".into(),
+                ),
+            ),
+            (
+                "https://atcoder.jp/contests/abc300/submissions/41234567",
+                AtcoderTaskUrlError::SubmissionWithoutTask(
+                    "https://atcoder.jp/contests/abc300/submissions/41234567".into(),
+                ),
+            ),
+        ] {
+            assert_eq!(AtcoderTaskUrl::parse(url), Err(expected));
+        }
+    }
+}
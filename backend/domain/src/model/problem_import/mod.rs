@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use strum::{Display, EnumString};
+use uuid::Uuid;
+
+#[derive(Debug, Display, EnumString, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum ImportOutcome {
+    Success,
+    Failure,
+}
+
+// 取得結果とproblemsの差分
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProblemImportChanges {
+    pub inserted: i32,
+    // タイトル、問題番号、コンテスト、難易度のいずれかが変わった
+    pub updated: i32,
+    pub unchanged: i32,
+    // problemsにあるが取得結果に無い。削除はしない
+    pub removed: i32,
+    pub difficulty_fetch_failures: i32,
+    // 取得件数の上限を超え、次回以降に回した難易度
+    pub difficulty_fetch_deferred: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProblemImportRun {
+    pub id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: ImportOutcome,
+    // 取得前に失敗した場合はNone
+    pub problem_count: Option<i32>,
+    // 失敗した場合はNone
+    pub changes: Option<ProblemImportChanges>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewProblemImportRun {
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: ImportOutcome,
+    pub problem_count: Option<i32>,
+    pub changes: Option<ProblemImportChanges>,
+    pub error_message: Option<String>,
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+// サインイン1回分(ID tokenのauth_time単位)のセッション
+#[derive(Debug, Clone)]
+pub struct UserSession {
+    pub id: Uuid,
+    pub user_id: String,
+    pub authenticated_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionActivity {
+    pub user_id: String,
+    pub authenticated_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TouchedSession {
+    pub id: Uuid,
+    pub revoked: bool,
+}
+
+// 認証のたびに読むセッションの状態
+#[derive(Debug, Clone)]
+pub struct SessionStatus {
+    pub revoked: bool,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl SessionStatus {
+    // 最終アクセスは間隔を空けて更新する
+    pub fn needs_touch(&self, now: DateTime<Utc>, interval: TimeDelta) -> bool {
+        now - self.last_seen_at >= interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use super::SessionStatus;
+
+    #[test]
+    fn needs_touch_after_the_interval() {
+        let last_seen_at = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let status = SessionStatus {
+            revoked: false,
+            last_seen_at,
+        };
+        let interval = TimeDelta::minutes(5);
+        assert!(!status.needs_touch(last_seen_at + TimeDelta::minutes(4), interval));
+        assert!(status.needs_touch(last_seen_at + TimeDelta::minutes(5), interval));
+    }
+}
@@ -1,10 +1,12 @@
 use async_trait::async_trait;
 
 use crate::error::external::ExternalError;
-use crate::model::problem::Problem;
+use crate::model::problem::{ContestMetadata, Problem, ProblemAppearance};
 
 #[async_trait]
 pub trait AtcoderProblemsPort: Send + Sync {
     async fn fetch_problems(&self) -> Result<Vec<Problem>, ExternalError>;
+    async fn fetch_contests(&self) -> Result<Vec<ContestMetadata>, ExternalError>;
+    async fn fetch_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, ExternalError>;
     async fn fetch_difficulty(&self, problem_id: &str) -> Result<Option<i32>, ExternalError>;
 }
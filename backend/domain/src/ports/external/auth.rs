@@ -1,10 +1,38 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use thiserror::Error;
+use uuid::Uuid;
+
+use crate::model::personal_token::TokenScope;
 
 pub struct Principal {
     pub uid: String,
-    pub email: String,
+    pub email: Option<String>,
     pub issued_at: i64,
+    // サインインした時刻。ID tokenの更新では変わらない
+    pub authenticated_at: i64,
+    pub credential: Credential,
+}
+
+pub enum Credential {
+    IdToken,
+    PersonalToken {
+        token_id: Uuid,
+        scopes: Vec<TokenScope>,
+    },
+}
+
+impl Principal {
+    pub fn has_scope(&self, scope: TokenScope) -> bool {
+        match &self.credential {
+            Credential::IdToken => true,
+            Credential::PersonalToken { scopes, .. } => scopes.contains(&scope),
+        }
+    }
+
+    pub fn is_personal_token(&self) -> bool {
+        matches!(self.credential, Credential::PersonalToken { .. })
+    }
 }
 
 #[derive(Error, Debug)]
@@ -15,7 +43,32 @@ pub enum AuthError {
     TemporarilyUnavailable,
 }
 
+// 検証鍵(JWKS)のキャッシュ状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySetStatus {
+    // 鍵をリモートから取得しない認証方式
+    NotApplicable,
+    NotLoaded,
+    Loaded {
+        fetched_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    },
+}
+
+impl KeySetStatus {
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        match self {
+            KeySetStatus::NotApplicable => true,
+            KeySetStatus::NotLoaded => false,
+            KeySetStatus::Loaded { expires_at, .. } => now < *expires_at,
+        }
+    }
+}
+
 #[async_trait]
 pub trait AuthenticatorPort: Send + Sync {
     async fn verify_id_token(&self, token: &str) -> Result<Principal, AuthError>;
+    async fn key_set_status(&self) -> KeySetStatus {
+        KeySetStatus::NotApplicable
+    }
 }
@@ -2,4 +2,5 @@ use uuid::Uuid;
 
 pub trait IdProviderPort: Send + Sync {
     fn new_solution_id(&self) -> Uuid;
+    fn new_personal_token_id(&self) -> Uuid;
 }
@@ -1,3 +1,4 @@
 pub mod atcoder_problems;
 pub mod auth;
 pub mod id;
+pub mod personal_token;
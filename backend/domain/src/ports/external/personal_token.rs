@@ -0,0 +1,10 @@
+pub struct GeneratedPersonalToken {
+    // 発行時に一度だけ利用者へ返す平文
+    pub plain: String,
+    pub hash: String,
+    pub prefix: String,
+}
+
+pub trait PersonalTokenGeneratorPort: Send + Sync {
+    fn generate(&self) -> GeneratedPersonalToken;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::error::repository::RepositoryError;
+use crate::model::problem::{ContestReclassification, ContestSeries, ContestSeriesDefinition};
+
+#[async_trait]
+pub trait ContestSeriesRepository: Send + Sync {
+    async fn list(&self) -> Result<Vec<ContestSeriesDefinition>, RepositoryError>;
+    // コンテストのcodeと現在のシリーズ
+    async fn list_contest_assignments(
+        &self,
+    ) -> Result<Vec<(String, ContestSeries)>, RepositoryError>;
+    // シリーズの追加と既存コンテストの付け替えを同じトランザクションで行う
+    async fn create_and_reclassify(
+        &self,
+        definition: &ContestSeriesDefinition,
+        reclassifications: &[ContestReclassification],
+    ) -> Result<(), RepositoryError>;
+}
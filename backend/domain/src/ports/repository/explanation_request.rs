@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::error::repository::RepositoryError;
+use crate::model::explanation_request::{ExplanationRequest, WantedProblem, WantedProblemFilter};
+
+#[async_trait]
+pub trait ExplanationRequestRepository: Send + Sync {
+    // 新たにリクエストした場合と、達成済みのリクエストを未達成に戻した場合はtrue
+    async fn create(&self, user_id: &str, problem_id: &str) -> Result<bool, RepositoryError>;
+    async fn delete(&self, user_id: &str, problem_id: &str) -> Result<(), RepositoryError>;
+    async fn list_by_user(&self, user_id: &str)
+    -> Result<Vec<ExplanationRequest>, RepositoryError>;
+    // 表示中の解説がない問題を、リクエストの多い順に返す
+    async fn list_wanted(
+        &self,
+        filter: &WantedProblemFilter,
+    ) -> Result<Vec<WantedProblem>, RepositoryError>;
+}
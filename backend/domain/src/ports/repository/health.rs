@@ -1,6 +1,10 @@
 use async_trait::async_trait;
 
+use crate::error::repository::RepositoryError;
+use crate::model::health::MigrationStatus;
+
 #[async_trait]
 pub trait HealthCheckRepository: Send + Sync {
     async fn check_db(&self) -> bool;
+    async fn migration_status(&self) -> Result<MigrationStatus, RepositoryError>;
 }
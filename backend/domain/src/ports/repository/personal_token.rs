@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::repository::RepositoryError;
+use crate::model::personal_token::{NewPersonalToken, PersonalToken};
+
+#[async_trait]
+pub trait PersonalTokenRepository: Send + Sync {
+    async fn create(&self, token: &NewPersonalToken) -> Result<PersonalToken, RepositoryError>;
+    async fn list_by_user(&self, user_id: &str) -> Result<Vec<PersonalToken>, RepositoryError>;
+    async fn revoke(&self, user_id: &str, token_id: Uuid) -> Result<(), RepositoryError>;
+    async fn find_active_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalToken>, RepositoryError>;
+    async fn touch_last_used(&self, token_id: Uuid) -> Result<(), RepositoryError>;
+}
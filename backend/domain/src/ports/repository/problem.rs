@@ -3,11 +3,20 @@ pub mod tx;
 use async_trait::async_trait;
 
 use crate::error::repository::RepositoryError;
-use crate::model::problem::{ContestSeries, Problem};
+use crate::model::problem::{
+    ContestMetadata, ContestSeries, ContestSeriesCatalog, ContestSortKey, DifficultyRange, Problem,
+    ProblemAppearance,
+};
 
 #[async_trait]
 pub trait ProblemRepository: Send + Sync {
-    async fn create_records(&self, problems: Vec<Problem>) -> Result<(), RepositoryError>;
+    // シリーズの分類は呼び出し側が読み込んだcatalogで行う
+    async fn create_records(
+        &self,
+        problems: Vec<Problem>,
+        catalog: &ContestSeriesCatalog,
+    ) -> Result<(), RepositoryError>;
+    async fn get_all_problems(&self) -> Result<Vec<Problem>, RepositoryError>;
     async fn get_problem_ids_with_difficulty(
         &self,
         problem_ids: &[String],
@@ -16,22 +25,43 @@ pub trait ProblemRepository: Send + Sync {
         &self,
         series: ContestSeries,
     ) -> Result<Vec<Problem>, RepositoryError>;
+    // difficultyの範囲を指定した場合は、範囲内の問題があるコンテストに限る
     async fn get_contest_codes_by_series(
         &self,
         series: ContestSeries,
+        difficulty: DifficultyRange,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<String>, RepositoryError>;
     async fn get_problems_by_contest_codes(
         &self,
         contest_codes: &[String],
+        difficulty: DifficultyRange,
     ) -> Result<Vec<Problem>, RepositoryError>;
     async fn search_problems_by_contest_series(
         &self,
         series: ContestSeries,
         query: &str,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<Problem>, RepositoryError>;
     async fn get_problem_by_id(&self, problem_id: &str) -> Result<Problem, RepositoryError>;
+    // 出題されたコンテストでのcontest_codeとproblem_indexで返す
     async fn get_problems_by_contest(&self, contest: &str)
     -> Result<Vec<Problem>, RepositoryError>;
+    async fn get_all_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, RepositoryError>;
+    // 出題から元の問題のidを引く
+    async fn get_problem_id_by_appearance(
+        &self,
+        contest_code: &str,
+        problem_index: &str,
+    ) -> Result<String, RepositoryError>;
+    async fn get_contest_sort_keys(
+        &self,
+        contest_codes: &[String],
+    ) -> Result<Vec<ContestSortKey>, RepositoryError>;
+    async fn get_all_contest_metadata(&self) -> Result<Vec<ContestMetadata>, RepositoryError>;
+    async fn get_contest_metadata(
+        &self,
+        contest: &str,
+    ) -> Result<Option<ContestMetadata>, RepositoryError>;
 }
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
 use crate::error::repository::RepositoryError;
-use crate::model::problem::Problem;
+use crate::model::problem::{ContestMetadata, Problem, ProblemAppearance};
 
 #[async_trait]
 pub trait ProblemRepositoryTx: Send + Sync {
@@ -23,6 +23,15 @@ pub trait ProblemRepositoryTx: Send + Sync {
         contests: &[(String, String)],
     ) -> Result<(), RepositoryError>;
     async fn upsert_problems_bulk(&mut self, problems: &[Problem]) -> Result<(), RepositoryError>;
+    // 既存のコンテストにだけ反映する
+    async fn update_contest_metadata_bulk(
+        &mut self,
+        contests: &[ContestMetadata],
+    ) -> Result<(), RepositoryError>;
+    async fn upsert_problem_appearances_bulk(
+        &mut self,
+        appearances: &[ProblemAppearance],
+    ) -> Result<(), RepositoryError>;
 }
 
 #[async_trait]
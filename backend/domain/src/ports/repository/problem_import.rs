@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::error::repository::RepositoryError;
+use crate::model::problem_import::{NewProblemImportRun, ProblemImportRun};
+
+#[async_trait]
+pub trait ProblemImportRunRepository: Send + Sync {
+    async fn record(&self, run: &NewProblemImportRun) -> Result<ProblemImportRun, RepositoryError>;
+    async fn latest(&self) -> Result<Option<ProblemImportRun>, RepositoryError>;
+    // 新しい順
+    async fn list_recent(&self, limit: i64) -> Result<Vec<ProblemImportRun>, RepositoryError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::error::repository::RepositoryError;
+use crate::model::session::{SessionActivity, SessionStatus, TouchedSession, UserSession};
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    // 無ければ作成し、あれば最終アクセスを更新する
+    async fn touch(&self, activity: &SessionActivity) -> Result<TouchedSession, RepositoryError>;
+    // 書き込まずに失効と最終アクセスを確かめる
+    async fn find_status(
+        &self,
+        user_id: &str,
+        authenticated_at: DateTime<Utc>,
+    ) -> Result<Option<SessionStatus>, RepositoryError>;
+    async fn list_active_by_user(&self, user_id: &str)
+    -> Result<Vec<UserSession>, RepositoryError>;
+    async fn revoke(&self, user_id: &str, session_id: Uuid) -> Result<(), RepositoryError>;
+    async fn revoke_all_by_user(&self, user_id: &str) -> Result<(), RepositoryError>;
+}
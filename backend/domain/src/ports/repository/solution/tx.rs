@@ -16,6 +16,8 @@ pub trait SolutionRespositoryTx: Send + Sync {
         submit_url: &str,
     ) -> Result<(), RepositoryError>;
     async fn delete(&mut self, solution_id: Uuid) -> Result<(), RepositoryError>;
+    // 管理者による非表示。存在しない場合はNotFound
+    async fn set_hidden(&mut self, solution_id: Uuid, hidden: bool) -> Result<(), RepositoryError>;
     async fn replace_tags(
         &mut self,
         solution_id: Uuid,
@@ -30,7 +32,8 @@ pub trait TagRepositoryTx: Send + Sync {
 
 #[async_trait]
 pub trait VoteRepositoryTx: Send + Sync {
-    async fn like(&mut self, user_id: &str, solution_id: Uuid) -> Result<(), RepositoryError>;
+    // 新たに投票した場合はtrue
+    async fn like(&mut self, user_id: &str, solution_id: Uuid) -> Result<bool, RepositoryError>;
     async fn unlike(&mut self, user_id: &str, solution_id: Uuid) -> Result<(), RepositoryError>;
 }
 
@@ -58,6 +61,21 @@ pub trait CommentRepositoryTx: Send + Sync {
         body_md: &str,
     ) -> Result<CreatedComment, RepositoryError>;
     async fn delete_comment(&mut self, comment_id: Uuid) -> Result<(), RepositoryError>;
+    async fn set_comment_hidden(
+        &mut self,
+        comment_id: Uuid,
+        hidden: bool,
+    ) -> Result<(), RepositoryError>;
+}
+
+#[async_trait]
+pub trait ExplanationRequestRepositoryTx: Send + Sync {
+    // 未達成のリクエストを解説で達成済みにし、その件数を返す
+    async fn fulfill_explanation_requests(
+        &mut self,
+        problem_id: &str,
+        solution_id: Uuid,
+    ) -> Result<u64, RepositoryError>;
 }
 
 #[async_trait]
@@ -66,6 +84,7 @@ pub trait UnitOfWork: Send + Sync {
     fn tags(&mut self) -> &mut dyn TagRepositoryTx;
     fn votes(&mut self) -> &mut dyn VoteRepositoryTx;
     fn comments(&mut self) -> &mut dyn CommentRepositoryTx;
+    fn explanation_requests(&mut self) -> &mut dyn ExplanationRequestRepositoryTx;
 
     async fn commit(self: Box<Self>) -> Result<(), RepositoryError>;
     async fn rollback(self: Box<Self>) -> Result<(), RepositoryError>;
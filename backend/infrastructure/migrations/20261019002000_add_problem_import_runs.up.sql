@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS problem_import_runs (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  started_at TIMESTAMPTZ NOT NULL,
+  finished_at TIMESTAMPTZ NOT NULL,
+  outcome TEXT NOT NULL CHECK (outcome IN ('success', 'failure')),
+  problem_count INTEGER,
+  error_message TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_problem_import_runs_finished_at ON problem_import_runs (finished_at DESC);
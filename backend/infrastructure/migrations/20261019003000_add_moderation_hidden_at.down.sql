@@ -0,0 +1,2 @@
+ALTER TABLE comments DROP COLUMN IF EXISTS hidden_at;
+ALTER TABLE solutions DROP COLUMN IF EXISTS hidden_at;
@@ -0,0 +1,3 @@
+-- 管理者が非表示にした解説・コメント。NULLの場合は公開
+ALTER TABLE solutions ADD COLUMN IF NOT EXISTS hidden_at TIMESTAMPTZ;
+ALTER TABLE comments ADD COLUMN IF NOT EXISTS hidden_at TIMESTAMPTZ;
@@ -0,0 +1,6 @@
+ALTER TABLE problem_import_runs
+  DROP COLUMN IF EXISTS difficulty_fetch_failures,
+  DROP COLUMN IF EXISTS removed_count,
+  DROP COLUMN IF EXISTS unchanged_count,
+  DROP COLUMN IF EXISTS updated_count,
+  DROP COLUMN IF EXISTS inserted_count;
@@ -0,0 +1,6 @@
+ALTER TABLE problem_import_runs
+  ADD COLUMN IF NOT EXISTS inserted_count INTEGER,
+  ADD COLUMN IF NOT EXISTS updated_count INTEGER,
+  ADD COLUMN IF NOT EXISTS unchanged_count INTEGER,
+  ADD COLUMN IF NOT EXISTS removed_count INTEGER,
+  ADD COLUMN IF NOT EXISTS difficulty_fetch_failures INTEGER;
@@ -0,0 +1,2 @@
+ALTER TABLE problem_import_runs
+  DROP COLUMN IF EXISTS difficulty_fetch_deferred;
@@ -0,0 +1,2 @@
+ALTER TABLE problem_import_runs
+  ADD COLUMN IF NOT EXISTS difficulty_fetch_deferred INTEGER;
@@ -0,0 +1,5 @@
+ALTER TABLE contests
+  DROP COLUMN IF EXISTS rate_change,
+  DROP COLUMN IF EXISTS duration_second,
+  DROP COLUMN IF EXISTS start_at,
+  DROP COLUMN IF EXISTS title;
@@ -0,0 +1,5 @@
+ALTER TABLE contests
+  ADD COLUMN IF NOT EXISTS title TEXT,
+  ADD COLUMN IF NOT EXISTS start_at TIMESTAMPTZ,
+  ADD COLUMN IF NOT EXISTS duration_second BIGINT,
+  ADD COLUMN IF NOT EXISTS rate_change TEXT;
@@ -0,0 +1,10 @@
+ALTER TABLE contest_series
+  DROP CONSTRAINT IF EXISTS contest_series_pattern_check,
+  DROP CONSTRAINT IF EXISTS contest_series_code_format_check,
+  DROP COLUMN IF EXISTS sort_order,
+  DROP COLUMN IF EXISTS code_pattern,
+  DROP COLUMN IF EXISTS display_name;
+
+ALTER TABLE contest_series
+ADD CONSTRAINT contest_series_code_check
+CHECK (code IN ('ABC', 'ARC', 'AGC', 'AHC', 'AWC', 'OTHER'));
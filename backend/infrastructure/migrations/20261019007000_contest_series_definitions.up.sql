@@ -0,0 +1,35 @@
+ALTER TABLE contest_series
+DROP CONSTRAINT IF EXISTS contest_series_code_check;
+
+ALTER TABLE contest_series
+  ADD COLUMN IF NOT EXISTS display_name TEXT,
+  ADD COLUMN IF NOT EXISTS code_pattern TEXT,
+  ADD COLUMN IF NOT EXISTS sort_order INTEGER NOT NULL DEFAULT 0;
+
+INSERT INTO contest_series (code)
+VALUES ('ABC'), ('ARC'), ('AGC'), ('AHC'), ('AWC'), ('OTHER')
+ON CONFLICT (code) DO NOTHING;
+
+UPDATE contest_series s
+SET display_name = d.display_name,
+    code_pattern = d.code_pattern,
+    sort_order = d.sort_order
+FROM (
+  VALUES
+    ('ABC', 'AtCoder Beginner Contest', '^abc', 10),
+    ('ARC', 'AtCoder Regular Contest', '^arc', 20),
+    ('AGC', 'AtCoder Grand Contest', '^agc', 30),
+    ('AHC', 'AtCoder Heuristic Contest', '^ahc', 40),
+    ('AWC', 'AWC', '^awc', 50),
+    ('OTHER', 'Other Contests', NULL, 1000)
+) AS d(code, display_name, code_pattern, sort_order)
+WHERE s.code = d.code;
+
+UPDATE contest_series
+SET display_name = code
+WHERE display_name IS NULL;
+
+ALTER TABLE contest_series
+  ALTER COLUMN display_name SET NOT NULL,
+  ADD CONSTRAINT contest_series_code_format_check CHECK (code ~ '^[A-Z][A-Z0-9_]{0,31}$'),
+  ADD CONSTRAINT contest_series_pattern_check CHECK ((code = 'OTHER') = (code_pattern IS NULL));
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS problem_appearances (
+  contest_code TEXT NOT NULL REFERENCES contests(code) ON DELETE RESTRICT,
+  problem_index TEXT NOT NULL,
+  problem_id TEXT NOT NULL REFERENCES problems(id) ON DELETE CASCADE,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  PRIMARY KEY (contest_code, problem_index)
+);
+
+CREATE INDEX IF NOT EXISTS problem_appearances_problem_id_idx
+ON problem_appearances (problem_id);
+
+DROP TRIGGER IF EXISTS problem_appearances_updated_at_trigger ON problem_appearances;
+CREATE TRIGGER problem_appearances_updated_at_trigger
+  BEFORE UPDATE ON problem_appearances FOR EACH ROW
+  EXECUTE PROCEDURE set_updated_at();
+
+-- 既存の問題は自分のコンテストに出題されたものとして登録する
+INSERT INTO problem_appearances (contest_code, problem_index, problem_id)
+SELECT contest_code, problem_index, id
+FROM problems
+ON CONFLICT (contest_code, problem_index) DO NOTHING;
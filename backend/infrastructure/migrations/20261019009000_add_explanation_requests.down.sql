@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS explanation_requests_problem_id_idx;
+DROP TABLE IF EXISTS explanation_requests;
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS explanation_requests (
+  user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  problem_id TEXT NOT NULL REFERENCES problems(id) ON DELETE CASCADE,
+  -- 最初に投稿された解説。削除されてもfulfilled_atは残す
+  fulfilled_solution_id UUID REFERENCES solutions(id) ON DELETE SET NULL,
+  fulfilled_at TIMESTAMPTZ,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  PRIMARY KEY (user_id, problem_id)
+);
+
+CREATE INDEX IF NOT EXISTS explanation_requests_problem_id_idx
+ON explanation_requests (problem_id);
@@ -0,0 +1,8 @@
+-- domain::model::problem::clip_difficultyと同じ補正。絞り込みを補正後の値で行うために使う
+CREATE OR REPLACE FUNCTION clip_difficulty(difficulty INTEGER) RETURNS INTEGER
+LANGUAGE SQL IMMUTABLE AS $$
+  SELECT CASE
+    WHEN difficulty >= 400 THEN difficulty
+    ELSE ROUND(400 / EXP(1 - difficulty / 400.0))::INTEGER
+  END
+$$;
@@ -0,0 +1,9 @@
+-- 同じindexの出題は問題idの小さいものだけ残す
+DELETE FROM problem_appearances a
+USING problem_appearances b
+WHERE a.contest_code = b.contest_code
+  AND a.problem_index = b.problem_index
+  AND a.problem_id > b.problem_id;
+
+ALTER TABLE problem_appearances DROP CONSTRAINT IF EXISTS problem_appearances_pkey;
+ALTER TABLE problem_appearances ADD PRIMARY KEY (contest_code, problem_index);
@@ -0,0 +1,10 @@
+-- 同じコンテストの同じindexに複数の問題があっても全て一覧に出すため、出題は問題ごとに持つ
+ALTER TABLE problem_appearances DROP CONSTRAINT IF EXISTS problem_appearances_pkey;
+ALTER TABLE problem_appearances ADD PRIMARY KEY (contest_code, problem_id);
+
+-- indexが重なって登録されなかった問題も自分のコンテストに出題されたものとして登録する
+INSERT INTO problem_appearances (contest_code, problem_index, problem_id)
+SELECT contest_code, problem_index, id
+FROM problems
+ON CONFLICT (contest_code, problem_id) DO UPDATE
+SET problem_index = EXCLUDED.problem_index;
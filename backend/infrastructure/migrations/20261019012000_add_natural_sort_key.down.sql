@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS contests_series_sort_idx;
+DROP FUNCTION IF EXISTS natural_sort_key(TEXT);
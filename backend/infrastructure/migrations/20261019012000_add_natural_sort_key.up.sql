@@ -0,0 +1,23 @@
+-- domain::model::problem::natural_cmpと同じ並びになるキー。数字の並びは0埋めして数の大きさで比べられるようにする。
+-- "C"照合順序で比べること
+CREATE OR REPLACE FUNCTION natural_sort_key(code TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE AS $$
+  SELECT COALESCE(string_agg(
+    CASE
+      WHEN chunk[1] ~ '^[0-9]+$' THEN
+        lpad(ltrim(chunk[1], '0'), GREATEST(length(ltrim(chunk[1], '0')), 20), '0')
+      ELSE lower(chunk[1])
+    END,
+    '' ORDER BY n
+  ), '')
+  FROM regexp_matches(code, '([0-9]+|[^0-9]+)', 'g') WITH ORDINALITY AS t(chunk, n)
+$$;
+
+-- シリーズのコンテスト一覧をSQLでページングするため
+CREATE INDEX IF NOT EXISTS contests_series_sort_idx
+ON contests (
+  series_code,
+  start_at DESC NULLS LAST,
+  natural_sort_key(code) COLLATE "C" DESC,
+  code COLLATE "C" DESC
+);
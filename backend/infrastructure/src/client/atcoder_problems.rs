@@ -1,7 +1,17 @@
 use shared::config::AtcoderProblemsConfig;
 
-use crate::ports::external::atcoder_problems::AtcoderProblemsClient;
+use crate::ports::external::atcoder_problems::{AtcoderProblemsClient, DifficultyFetchPolicy};
 
 pub fn build_atcoder_problems_client(cfg: &AtcoderProblemsConfig) -> AtcoderProblemsClient {
-    AtcoderProblemsClient::new(cfg.base_endpoint.as_str(), cfg.difficulty_endpoint.as_str())
+    AtcoderProblemsClient::with_timeouts(
+        cfg.base_endpoint.as_str(),
+        cfg.difficulty_endpoint.as_str(),
+        cfg.connect_timeout,
+        cfg.request_timeout,
+    )
+    .with_difficulty_policy(DifficultyFetchPolicy {
+        requests_per_second: cfg.requests_per_second,
+        max_retries: cfg.max_retries,
+        retry_backoff: cfg.retry_backoff,
+    })
 }
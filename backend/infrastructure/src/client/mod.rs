@@ -1 +1,2 @@
 pub mod atcoder_problems;
+pub mod trace;
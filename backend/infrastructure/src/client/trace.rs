@@ -0,0 +1,80 @@
+use opentelemetry::global;
+use opentelemetry_http::HeaderInjector;
+use reqwest::{RequestBuilder, Response};
+use tracing::{Instrument, field::Empty, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+// 外部HTTP呼び出しをclient spanで包み、traceparentを伝播する
+pub async fn send_traced(builder: RequestBuilder) -> Result<Response, reqwest::Error> {
+    let (client, request) = builder.build_split();
+    let mut request = request?;
+
+    let method = request.method().clone();
+    let host = request.url().host_str().unwrap_or_default().to_string();
+    let span = info_span!(
+        "http.client",
+        otel.name = %format!("{method} {host}"),
+        otel.kind = "client",
+        http.request.method = %method,
+        url.full = %request.url(),
+        http.response.status_code = Empty,
+        otel.status_code = Empty,
+    );
+
+    let cx = span.context();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&cx, &mut HeaderInjector(request.headers_mut()))
+    });
+
+    let result = client.execute(request).instrument(span.clone()).await;
+    match &result {
+        Ok(resp) => {
+            span.record("http.response.status_code", resp.status().as_u16());
+            if resp.status().is_server_error() {
+                span.record("otel.status_code", "ERROR");
+            }
+        }
+        Err(_) => {
+            span.record("otel.status_code", "ERROR");
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::{global, trace::TracerProvider};
+    use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider};
+    use tracing::{Instrument, info_span};
+    use tracing_subscriber::layer::SubscriberExt;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header_exists, method},
+    };
+
+    use super::send_traced;
+
+    #[tokio::test]
+    async fn injects_traceparent_into_outbound_request() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header_exists("traceparent"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let parent = info_span!("parent");
+        let resp = send_traced(reqwest::Client::new().get(server.uri()))
+            .instrument(parent)
+            .await
+            .expect("should be success");
+        // traceparentが無い場合はモックに一致せず404になる
+        assert_eq!(resp.status(), 200);
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use shared::config::DatabaseConfig;
+use sqlx::{
+    Connection, PgConnection,
+    migrate::{MigrateError, Migration},
+};
+use tracing::info;
+
+use super::{MIGRATOR, UNDEFINED_TABLE, make_pg_connect_options};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    // 途中で失敗した
+    Dirty,
+    // 適用後にファイルが変更された
+    Modified,
+    // DBには適用済みだが、このバイナリに含まれない
+    Unknown,
+}
+
+impl MigrationState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MigrationState::Applied => "applied",
+            MigrationState::Pending => "pending",
+            MigrationState::Dirty => "dirty",
+            MigrationState::Modified => "modified",
+            MigrationState::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationEntry {
+    pub version: i64,
+    pub description: String,
+    pub state: MigrationState,
+}
+
+// 同時に起動したインスタンス間で、未適用分の判定から適用までを直列化する
+const MIGRATION_LOCK_KEY: i64 = 0x6174_636f_6465_7201;
+
+struct AppliedRow {
+    success: bool,
+    checksum: Vec<u8>,
+}
+
+// マイグレーション用に、プールとは別の接続を使う
+// 失敗時もadvisory lockは接続と一緒に解放される
+pub async fn connect(cfg: &DatabaseConfig) -> Result<PgConnection, sqlx::Error> {
+    PgConnection::connect_with(&make_pg_connect_options(cfg)).await
+}
+
+fn up_migrations() -> impl DoubleEndedIterator<Item = &'static Migration> {
+    MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+}
+
+async fn applied_rows(conn: &mut PgConnection) -> Result<HashMap<i64, AppliedRow>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (i64, bool, Vec<u8>)>(
+        r#"
+        SELECT version, success, checksum
+        FROM _sqlx_migrations
+        "#,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+    // 管理テーブルは初回の適用時に作られる
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some(UNDEFINED_TABLE) => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    Ok(rows
+        .into_iter()
+        .map(|(version, success, checksum)| (version, AppliedRow { success, checksum }))
+        .collect())
+}
+
+pub async fn status(conn: &mut PgConnection) -> Result<Vec<MigrationEntry>, sqlx::Error> {
+    let mut applied = applied_rows(conn).await?;
+    let mut entries = up_migrations()
+        .map(|m| {
+            let state = match applied.remove(&m.version) {
+                None => MigrationState::Pending,
+                Some(row) if !row.success => MigrationState::Dirty,
+                Some(row) if row.checksum != *m.checksum => MigrationState::Modified,
+                Some(_) => MigrationState::Applied,
+            };
+            MigrationEntry {
+                version: m.version,
+                description: m.description.to_string(),
+                state,
+            }
+        })
+        .collect::<Vec<_>>();
+    entries.extend(applied.into_keys().map(|version| MigrationEntry {
+        version,
+        description: String::new(),
+        state: MigrationState::Unknown,
+    }));
+    entries.sort_by_key(|e| e.version);
+    Ok(entries)
+}
+
+// dry-run用。upで適用されるもの
+pub async fn pending(conn: &mut PgConnection) -> Result<Vec<MigrationEntry>, sqlx::Error> {
+    Ok(status(conn)
+        .await?
+        .into_iter()
+        .filter(|e| e.state == MigrationState::Pending)
+        .collect())
+}
+
+// dry-run用。down <version> で戻されるもの (新しい順)
+pub async fn revertible(
+    conn: &mut PgConnection,
+    target: i64,
+) -> Result<Vec<MigrationEntry>, MigrateError> {
+    validate_target(target)?;
+    let mut entries = status(conn)
+        .await?
+        .into_iter()
+        .filter(|e| e.version > target && e.state != MigrationState::Pending)
+        .collect::<Vec<_>>();
+    entries.reverse();
+    Ok(entries)
+}
+
+async fn lock(conn: &mut PgConnection) -> Result<(), sqlx::Error> {
+    // 他のインスタンスが適用中の場合は解放を待つ
+    sqlx::query("SELECT pg_advisory_lock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+async fn unlock(conn: &mut PgConnection) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+pub async fn up(conn: &mut PgConnection) -> Result<Vec<MigrationEntry>, MigrateError> {
+    lock(conn).await?;
+    let result: Result<_, MigrateError> = async {
+        let pending = pending(conn).await?;
+        MIGRATOR.run(&mut *conn).await?;
+        Ok(pending)
+    }
+    .await;
+    unlock(conn).await?;
+    let pending = result?;
+    for entry in &pending {
+        info!(version = entry.version, description = %entry.description, "migration applied");
+    }
+    Ok(pending)
+}
+
+pub async fn down_to(
+    conn: &mut PgConnection,
+    target: i64,
+) -> Result<Vec<MigrationEntry>, MigrateError> {
+    lock(conn).await?;
+    let result: Result<_, MigrateError> = async {
+        let reverted = revertible(conn, target).await?;
+        MIGRATOR.undo(&mut *conn, target).await?;
+        Ok(reverted)
+    }
+    .await;
+    unlock(conn).await?;
+    let reverted = result?;
+    for entry in &reverted {
+        info!(version = entry.version, description = %entry.description, "migration reverted");
+    }
+    Ok(reverted)
+}
+
+// 0 は全て戻す
+fn validate_target(target: i64) -> Result<(), MigrateError> {
+    if target == 0 || up_migrations().any(|m| m.version == target) {
+        Ok(())
+    } else {
+        Err(MigrateError::VersionNotPresent(target))
+    }
+}
+
+// AppConfigで有効にした場合に、起動時に未適用分を適用する
+pub async fn migrate_on_startup(cfg: &DatabaseConfig) -> Result<(), MigrateError> {
+    let mut conn = connect(cfg).await?;
+    let applied = up(&mut conn).await?;
+    info!(applied = applied.len(), "startup migrations finished");
+    conn.close().await?;
+    Ok(())
+}
@@ -1,7 +1,19 @@
+pub mod migrate;
+
+use metrics::gauge;
 use shared::config::DatabaseConfig;
-use sqlx::{PgPool, postgres::PgConnectOptions};
+use sqlx::{
+    PgPool,
+    migrate::Migrator,
+    postgres::{PgConnectOptions, PgPoolOptions},
+};
 use std::str::FromStr;
 
+// 42P01: undefined_table
+pub(crate) const UNDEFINED_TABLE: &str = "42P01";
+
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
 fn make_pg_connect_options(cfg: &DatabaseConfig) -> PgConnectOptions {
     if let Some(url) = &cfg.app_database_url {
         return PgConnectOptions::from_str(url)
@@ -26,8 +38,22 @@ impl ConnectionPool {
     pub fn inner_ref(&self) -> &PgPool {
         &self.0
     }
+    pub async fn close(&self) {
+        self.0.close().await;
+    }
+    pub fn record_metrics(&self) {
+        let size = self.0.size();
+        let idle = self.0.num_idle() as u32;
+        gauge!("db_pool_connections", "state" => "idle").set(idle);
+        gauge!("db_pool_connections", "state" => "active").set(size.saturating_sub(idle));
+        gauge!("db_pool_max_connections").set(self.0.options().get_max_connections());
+    }
 }
 
 pub fn connect_database_with(cfg: &DatabaseConfig) -> ConnectionPool {
-    ConnectionPool(PgPool::connect_lazy_with(make_pg_connect_options(cfg)))
+    let pool = PgPoolOptions::new()
+        .max_connections(cfg.max_connections)
+        .acquire_timeout(cfg.acquire_timeout)
+        .connect_lazy_with(make_pg_connect_options(cfg));
+    ConnectionPool(pool)
 }
@@ -6,6 +6,10 @@ pub struct ContestListItemViewRaw {
     pub id: Uuid,
     pub code: String,
     pub series_code: String,
+    pub title: Option<String>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub duration_second: Option<i64>,
+    pub rate_change: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -15,6 +19,10 @@ impl From<ContestListItemViewRaw> for ContestListItem {
         Self {
             code: value.code,
             series_code: value.series_code,
+            title: value.title,
+            start_at: value.start_at,
+            duration_second: value.duration_second,
+            rate_change: value.rate_change,
         }
     }
 }
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use domain::model::{explanation_request::WantedProblem, problem::Problem};
+
+pub struct WantedProblemRow {
+    pub id: String,
+    pub contest_code: String,
+    pub problem_index: String,
+    pub title: String,
+    pub difficulty: Option<i32>,
+    pub request_count: i64,
+    pub last_requested_at: DateTime<Utc>,
+}
+
+impl From<WantedProblemRow> for WantedProblem {
+    fn from(value: WantedProblemRow) -> Self {
+        Self {
+            problem: Problem {
+                id: value.id,
+                contest_code: value.contest_code,
+                problem_index: value.problem_index.into(),
+                title: value.title,
+                difficulty: value.difficulty,
+            },
+            request_count: value.request_count,
+            last_requested_at: value.last_requested_at,
+        }
+    }
+}
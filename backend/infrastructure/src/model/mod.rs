@@ -1,4 +1,8 @@
 pub mod contests;
+pub mod explanation_request;
+pub mod personal_token;
+pub mod problem_import;
 pub mod problems;
+pub mod session;
 pub mod solution;
 pub mod user;
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use domain::{
+    error::repository::RepositoryError,
+    model::personal_token::{PersonalToken, TokenScope},
+};
+use uuid::Uuid;
+
+pub struct PersonalTokenRow {
+    pub id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub token_prefix: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<PersonalTokenRow> for PersonalToken {
+    type Error = RepositoryError;
+    fn try_from(value: PersonalTokenRow) -> Result<PersonalToken, Self::Error> {
+        let scopes = value
+            .scopes
+            .iter()
+            .map(|s| TokenScope::from_str(s))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+        Ok(PersonalToken {
+            id: value.id,
+            user_id: value.user_id,
+            name: value.name,
+            scopes,
+            token_prefix: value.token_prefix,
+            last_used_at: value.last_used_at,
+            created_at: value.created_at,
+        })
+    }
+}
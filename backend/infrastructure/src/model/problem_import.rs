@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use domain::{
+    error::repository::RepositoryError,
+    model::problem_import::{ImportOutcome, ProblemImportChanges, ProblemImportRun},
+};
+use uuid::Uuid;
+
+pub struct ProblemImportRunRow {
+    pub id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: String,
+    pub problem_count: Option<i32>,
+    pub inserted_count: Option<i32>,
+    pub updated_count: Option<i32>,
+    pub unchanged_count: Option<i32>,
+    pub removed_count: Option<i32>,
+    pub difficulty_fetch_failures: Option<i32>,
+    pub difficulty_fetch_deferred: Option<i32>,
+    pub error_message: Option<String>,
+}
+
+impl TryFrom<ProblemImportRunRow> for ProblemImportRun {
+    type Error = RepositoryError;
+    fn try_from(value: ProblemImportRunRow) -> Result<Self, Self::Error> {
+        let outcome = ImportOutcome::from_str(value.outcome.as_str())
+            .map_err(|e| RepositoryError::Unexpected(e.to_string()))?;
+        let changes = match (
+            value.inserted_count,
+            value.updated_count,
+            value.unchanged_count,
+            value.removed_count,
+            value.difficulty_fetch_failures,
+        ) {
+            (
+                Some(inserted),
+                Some(updated),
+                Some(unchanged),
+                Some(removed),
+                Some(difficulty_fetch_failures),
+            ) => Some(ProblemImportChanges {
+                inserted,
+                updated,
+                unchanged,
+                removed,
+                difficulty_fetch_failures,
+                // 上限の導入前の記録には無い
+                difficulty_fetch_deferred: value.difficulty_fetch_deferred.unwrap_or(0),
+            }),
+            _ => None,
+        };
+        Ok(Self {
+            id: value.id,
+            started_at: value.started_at,
+            finished_at: value.finished_at,
+            outcome,
+            problem_count: value.problem_count,
+            changes,
+            error_message: value.error_message,
+        })
+    }
+}
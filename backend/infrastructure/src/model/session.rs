@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use domain::model::session::UserSession;
+use uuid::Uuid;
+
+pub struct UserSessionRow {
+    pub id: Uuid,
+    pub user_id: String,
+    pub authenticated_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl From<UserSessionRow> for UserSession {
+    fn from(value: UserSessionRow) -> Self {
+        Self {
+            id: value.id,
+            user_id: value.user_id,
+            authenticated_at: value.authenticated_at,
+            user_agent: value.user_agent,
+            ip_address: value.ip_address,
+            created_at: value.created_at,
+            last_seen_at: value.last_seen_at,
+        }
+    }
+}
@@ -1,15 +1,22 @@
+use std::sync::Mutex;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use chrono::DateTime;
 use domain::{
-    error::external::ExternalError, model::problem::Problem,
+    error::external::ExternalError,
+    model::problem::{
+        ContestMetadata, Problem, ProblemAppearance, contest_code_from_problem,
+        problem_index_from_problem,
+    },
     ports::external::atcoder_problems::AtcoderProblemsPort,
 };
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, de::DeserializeOwned};
+use tokio::time::{Instant, sleep, sleep_until};
 use tracing::warn;
 
-use crate::error::map_reqwest_error;
+use crate::{client::trace::send_traced, error::map_reqwest_error};
 
 #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
 struct ApiProblem {
@@ -19,32 +26,26 @@ struct ApiProblem {
     name: String,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-struct ApiProblemDifficulty {
-    difficulty: Option<i32>,
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+struct ApiContest {
+    id: String,
+    start_epoch_second: i64,
+    duration_second: i64,
+    title: String,
+    rate_change: String,
 }
 
-fn contest_code_from_problem(problem_id: &str, contest_id: &str) -> String {
-    if !contest_id.starts_with("adt_") {
-        return contest_id.to_string();
-    }
-
-    problem_id
-        .rsplit_once('_')
-        .map(|(contest_code, _)| contest_code.to_string())
-        .unwrap_or_else(|| contest_id.to_string())
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+struct ApiContestProblem {
+    contest_id: String,
+    problem_id: String,
+    problem_index: String,
 }
 
-fn problem_index_from_problem(problem_id: &str, contest_id: &str, problem_index: &str) -> String {
-    if !contest_id.starts_with("adt_") {
-        return problem_index.to_string();
-    }
-
-    problem_id
-        .rsplit_once('_')
-        .map(|(_, derived_problem_index)| derived_problem_index.to_ascii_uppercase())
-        .unwrap_or_else(|| problem_index.to_string())
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ApiProblemDifficulty {
+    difficulty: Option<i32>,
 }
 
 impl From<ApiProblem> for Problem {
@@ -55,46 +56,172 @@ impl From<ApiProblem> for Problem {
         Self {
             id: value.id,
             contest_code,
-            problem_index,
+            problem_index: problem_index.into(),
             title: value.name,
             difficulty: None,
         }
     }
 }
 
+// ADTでもそのコンテストでのindexのまま使う
+impl From<ApiContestProblem> for ProblemAppearance {
+    fn from(value: ApiContestProblem) -> Self {
+        Self {
+            contest_code: value.contest_id,
+            problem_index: value.problem_index.into(),
+            problem_id: value.problem_id,
+        }
+    }
+}
+
+impl TryFrom<ApiContest> for ContestMetadata {
+    type Error = ExternalError;
+    fn try_from(value: ApiContest) -> Result<Self, Self::Error> {
+        let start_at = DateTime::from_timestamp(value.start_epoch_second, 0).ok_or_else(|| {
+            ExternalError::InvalidJson(format!(
+                "invalid start_epoch_second for {}: {}",
+                value.id, value.start_epoch_second
+            ))
+        })?;
+        Ok(Self {
+            code: value.id,
+            title: value.title,
+            start_at,
+            duration_second: value.duration_second,
+            rate_change: value.rate_change.trim().to_string(),
+        })
+    }
+}
+
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
+// 難易度APIへのリクエストの間隔と再試行
+#[derive(Debug, Clone, Copy)]
+pub struct DifficultyFetchPolicy {
+    pub requests_per_second: f64,
+    pub max_retries: u32,
+    pub retry_backoff: Duration,
+}
+
+impl Default for DifficultyFetchPolicy {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 1.0,
+            max_retries: 3,
+            retry_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl DifficultyFetchPolicy {
+    // retry_backoff から倍々に増やし、MAX_RETRY_BACKOFF で頭打ちにする
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_backoff
+            .saturating_mul(1 << attempt.min(16))
+            .min(MAX_RETRY_BACKOFF)
+    }
+}
+
+// 並列に呼ばれても、リクエストの開始を一定間隔に並べる
+struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: f64) -> Self {
+        Self {
+            interval: Duration::from_secs_f64(1.0 / requests_per_second),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+        sleep_until(slot).await;
+    }
+}
+
 pub struct AtcoderProblemsClient {
     client: Client,
     base_endpoint: String,
     difficulty_endpoint: String,
+    difficulty_policy: DifficultyFetchPolicy,
+    difficulty_rate_limiter: RateLimiter,
 }
 
 impl AtcoderProblemsClient {
     pub fn new(base: &str, difficulty_endpoint: &str) -> Self {
+        Self::with_timeouts(
+            base,
+            difficulty_endpoint,
+            Duration::from_secs(10),
+            Duration::from_secs(30),
+        )
+    }
+
+    pub fn with_timeouts(
+        base: &str,
+        difficulty_endpoint: &str,
+        connect_timeout: Duration,
+        request_timeout: Duration,
+    ) -> Self {
         let client = Client::builder()
             .gzip(true)
             .brotli(true)
             .deflate(true)
-            .connect_timeout(Duration::from_secs(10))
-            .timeout(Duration::from_secs(30))
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout)
             .build()
             .expect("reqwest client build failed");
 
+        let difficulty_policy = DifficultyFetchPolicy::default();
         Self {
             client,
             base_endpoint: base.into(),
             difficulty_endpoint: difficulty_endpoint.into(),
+            difficulty_policy,
+            difficulty_rate_limiter: RateLimiter::new(difficulty_policy.requests_per_second),
         }
     }
 
-    async fn fetch_difficulty(&self, problem_id: &str) -> Result<Option<i32>, ExternalError> {
-        let resp = self
-            .client
-            .get(self.difficulty_endpoint.as_str())
-            .query(&[("problemId", problem_id)])
-            .send()
+    pub fn with_difficulty_policy(mut self, policy: DifficultyFetchPolicy) -> Self {
+        self.difficulty_policy = policy;
+        self.difficulty_rate_limiter = RateLimiter::new(policy.requests_per_second);
+        self
+    }
+
+    async fn fetch_resource<T: DeserializeOwned>(&self, name: &str) -> Result<T, ExternalError> {
+        let json_endpoint = format!("{}/resources/{}", self.base_endpoint, name);
+        let resp = send_traced(self.client.get(json_endpoint))
             .await
+            .map_err(map_reqwest_error)?
+            .error_for_status()
             .map_err(map_reqwest_error)?;
 
+        resp.json().await.map_err(|e| {
+            if e.is_decode() {
+                ExternalError::InvalidJson(e.to_string())
+            } else {
+                map_reqwest_error(e)
+            }
+        })
+    }
+
+    async fn request_difficulty(&self, problem_id: &str) -> Result<Option<i32>, ExternalError> {
+        let resp = send_traced(
+            self.client
+                .get(self.difficulty_endpoint.as_str())
+                .query(&[("problemId", problem_id)]),
+        )
+        .await
+        .map_err(map_reqwest_error)?;
+
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
             return Ok(None);
         }
@@ -114,42 +241,57 @@ impl AtcoderProblemsClient {
 #[async_trait]
 impl AtcoderProblemsPort for AtcoderProblemsClient {
     async fn fetch_problems(&self) -> Result<Vec<Problem>, ExternalError> {
-        let json_endpoint = format!("{}/resources/problems.json", self.base_endpoint);
-        let resp = self
-            .client
-            .get(json_endpoint)
-            .send()
-            .await
-            .map_err(map_reqwest_error)?
-            .error_for_status()
-            .map_err(map_reqwest_error)?;
-
-        let json: Vec<ApiProblem> = resp.json().await.map_err(|e| {
-            if e.is_decode() {
-                ExternalError::InvalidJson(e.to_string())
-            } else {
-                map_reqwest_error(e)
-            }
-        })?;
+        let json: Vec<ApiProblem> = self.fetch_resource("problems.json").await?;
         Ok(json
             .into_iter()
             .map(Problem::from)
             .collect::<Vec<Problem>>())
     }
 
+    async fn fetch_contests(&self) -> Result<Vec<ContestMetadata>, ExternalError> {
+        let json: Vec<ApiContest> = self.fetch_resource("contests.json").await?;
+        json.into_iter().map(ContestMetadata::try_from).collect()
+    }
+
+    async fn fetch_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, ExternalError> {
+        let json: Vec<ApiContestProblem> = self.fetch_resource("contest-problem.json").await?;
+        Ok(json.into_iter().map(ProblemAppearance::from).collect())
+    }
+
     async fn fetch_difficulty(&self, problem_id: &str) -> Result<Option<i32>, ExternalError> {
-        self.fetch_difficulty(problem_id)
-            .await
-            .inspect_err(|error| {
-                warn!(problem_id, error = ?error, "difficulty fetch failed");
-            })
+        let mut attempt = 0;
+        loop {
+            self.difficulty_rate_limiter.acquire().await;
+            match self.request_difficulty(problem_id).await {
+                Err(error @ (ExternalError::RateLimited | ExternalError::ExternalUnavailable))
+                    if attempt < self.difficulty_policy.max_retries =>
+                {
+                    let backoff = self.difficulty_policy.backoff(attempt);
+                    attempt += 1;
+                    warn!(
+                        problem_id,
+                        attempt,
+                        backoff_ms = backoff.as_millis() as u64,
+                        error = ?error,
+                        "difficulty fetch retrying"
+                    );
+                    sleep(backoff).await;
+                }
+                result => {
+                    return result.inspect_err(|error| {
+                        warn!(problem_id, attempt, error = ?error, "difficulty fetch failed");
+                    });
+                }
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use domain::{
-        error::external::ExternalError, model::problem::Problem,
+        error::external::ExternalError,
+        model::problem::{ContestMetadata, Problem, ProblemAppearance},
         ports::external::atcoder_problems::AtcoderProblemsPort,
     };
     use wiremock::{
@@ -157,9 +299,9 @@ mod tests {
         matchers::{method, path},
     };
 
-    use super::{
-        ApiProblem, AtcoderProblemsClient, contest_code_from_problem, problem_index_from_problem,
-    };
+    use std::time::{Duration, Instant};
+
+    use super::{ApiProblem, AtcoderProblemsClient, DifficultyFetchPolicy};
 
     async fn server_and_client() -> (MockServer, AtcoderProblemsClient) {
         let server = MockServer::start().await;
@@ -186,40 +328,78 @@ mod tests {
         let p1 = &got[0];
         assert_eq!(p1.id, "abc234_a");
         assert_eq!(p1.contest_code, "abc234");
-        assert_eq!(p1.problem_index, "A");
+        assert_eq!(p1.problem_index.as_str(), "A");
         assert_eq!(p1.title, "Weird Function");
         assert_eq!(p1.difficulty, None);
     }
 
-    #[test]
-    fn contest_code_and_problem_index_are_rewritten_only_for_adt() {
-        for (problem_id, contest_id, problem_index, expected_contest, expected_index) in [
-            ("abc395_a", "adt_easy_20250430_3", "B", "abc395", "A"),
-            ("abc001_1", "abc001", "A", "abc001", "A"),
-            ("abc007_3", "atc002", "A", "atc002", "A"),
-            (
-                "tessoku_book_fj",
-                "tessoku-book",
-                "a1",
-                "tessoku-book",
-                "a1",
-            ),
-            ("APG4bPython_ak", "APG4bPython", "A", "APG4bPython", "A"),
-            (
-                "problem-without-separator",
-                "fallback_contest",
-                "A",
-                "fallback_contest",
-                "A",
-            ),
-        ] {
-            let contest = contest_code_from_problem(problem_id, contest_id);
-            let index = problem_index_from_problem(problem_id, contest_id, problem_index);
-            assert_eq!(
-                (contest.as_str(), index.as_str()),
-                (expected_contest, expected_index)
-            );
-        }
+    #[tokio::test]
+    async fn fetch_contests_ok() {
+        let (server, client) = server_and_client().await;
+        let body_str = include_str!("../../../../tests/fixtures/contests.json");
+        let body_val: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/resources/contests.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body_val))
+            .mount(&server)
+            .await;
+
+        let got = client.fetch_contests().await.expect("should be success");
+        assert_eq!(got.len(), 3);
+        assert_eq!(
+            got[0],
+            ContestMetadata {
+                code: "abc234".into(),
+                title: "AtCoder Beginner Contest 234".into(),
+                start_at: "2022-01-01T12:00:00Z".parse().unwrap(),
+                duration_second: 6000,
+                rate_change: "~ 1999".into(),
+            }
+        );
+        assert_eq!(got[2].rate_change, "-");
+    }
+
+    #[tokio::test]
+    async fn fetch_problem_appearances_ok() {
+        let (server, client) = server_and_client().await;
+        let body_str = include_str!("../../../../tests/fixtures/contest-problem.json");
+        let body_val: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/resources/contest-problem.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body_val))
+            .mount(&server)
+            .await;
+
+        let got = client
+            .fetch_problem_appearances()
+            .await
+            .expect("should be success");
+        assert_eq!(got.len(), 4);
+        assert_eq!(
+            got[1],
+            ProblemAppearance {
+                contest_code: "adt_easy_20250430_3".into(),
+                problem_index: "B".into(),
+                problem_id: "abc395_a".into(),
+            }
+        );
+        assert_eq!(got[3].contest_code, "abc042");
+        assert_eq!(got[3].problem_id, "arc058_a");
+    }
+
+    #[tokio::test]
+    async fn fetch_contests_invalid_json() {
+        let (server, client) = server_and_client().await;
+        Mock::given(method("GET"))
+            .and(path("/resources/contests.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "id": "abc001" }])),
+            )
+            .mount(&server)
+            .await;
+
+        let err = client.fetch_contests().await.expect_err("should fail");
+        assert!(matches!(err, ExternalError::InvalidJson(_)));
     }
 
     #[test]
@@ -233,7 +413,7 @@ mod tests {
 
         assert_eq!(problem.id, "abc395_a");
         assert_eq!(problem.contest_code, "abc395");
-        assert_eq!(problem.problem_index, "A");
+        assert_eq!(problem.problem_index.as_str(), "A");
         assert_eq!(problem.title, "Strictly Increasing?");
         assert_eq!(problem.difficulty, None);
     }
@@ -311,4 +491,117 @@ mod tests {
             .expect("should be success");
         assert_eq!(got, None);
     }
+
+    fn fast_retry_policy(max_retries: u32) -> DifficultyFetchPolicy {
+        DifficultyFetchPolicy {
+            requests_per_second: 1_000.0,
+            max_retries,
+            retry_backoff: Duration::from_millis(10),
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_difficulty_retries_after_rate_limit() {
+        let server = MockServer::start().await;
+        let client = AtcoderProblemsClient::new(server.uri().as_str(), server.uri().as_str())
+            .with_difficulty_policy(fast_retry_policy(3));
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "difficulty": 800 })),
+            )
+            .mount(&server)
+            .await;
+
+        let got = client
+            .fetch_difficulty("abc300_a")
+            .await
+            .expect("should succeed after retries");
+        assert_eq!(got, Some(800));
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn fetch_difficulty_gives_up_after_max_retries() {
+        let server = MockServer::start().await;
+        let client = AtcoderProblemsClient::new(server.uri().as_str(), server.uri().as_str())
+            .with_difficulty_policy(fast_retry_policy(2));
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let err = client
+            .fetch_difficulty("abc300_a")
+            .await
+            .expect_err("should fail");
+        assert!(matches!(err, ExternalError::ExternalUnavailable));
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn fetch_difficulty_does_not_retry_other_errors() {
+        let server = MockServer::start().await;
+        let client = AtcoderProblemsClient::new(server.uri().as_str(), server.uri().as_str())
+            .with_difficulty_policy(fast_retry_policy(3));
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        let err = client
+            .fetch_difficulty("abc300_a")
+            .await
+            .expect_err("should fail");
+        assert!(matches!(err, ExternalError::Forbidden));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fetch_difficulty_spaces_concurrent_requests() {
+        let server = MockServer::start().await;
+        let client = AtcoderProblemsClient::new(server.uri().as_str(), server.uri().as_str())
+            .with_difficulty_policy(DifficultyFetchPolicy {
+                requests_per_second: 20.0,
+                ..fast_retry_policy(0)
+            });
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "difficulty": 100 })),
+            )
+            .mount(&server)
+            .await;
+
+        let started = Instant::now();
+        let results =
+            futures::future::join_all((0..5).map(|i| {
+                client.fetch_difficulty(if i % 2 == 0 { "abc300_a" } else { "abc300_b" })
+            }))
+            .await;
+
+        assert!(results.iter().all(|r| matches!(r, Ok(Some(100)))));
+        // 5件を20件/秒で送ると、最初と最後の開始の間に4間隔(200ms)空く
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn backoff_doubles_and_is_capped() {
+        let policy = DifficultyFetchPolicy {
+            requests_per_second: 1.0,
+            max_retries: 10,
+            retry_backoff: Duration::from_secs(1),
+        };
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(4));
+        assert_eq!(policy.backoff(10), Duration::from_secs(30));
+    }
 }
@@ -7,7 +7,10 @@ use std::{
 use async_trait::async_trait;
 use base64::Engine;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
-use domain::ports::external::auth::{AuthError, AuthenticatorPort, Principal};
+use chrono::{DateTime, Utc};
+use domain::ports::external::auth::{
+    AuthError, AuthenticatorPort, Credential, KeySetStatus, Principal,
+};
 use jsonwebtoken::{
     Algorithm, DecodingKey, Validation, decode, decode_header,
     jwk::{AlgorithmParameters, JwkSet},
@@ -15,6 +18,8 @@ use jsonwebtoken::{
 use tokio::sync::RwLock;
 use tracing::error;
 
+use crate::client::trace::send_traced;
+
 #[derive(Clone)]
 pub struct FirebaseAuthConfig {
     pub project_id: String,
@@ -39,7 +44,7 @@ impl JwksCache {
         {
             return Ok(set);
         }
-        let resp = reqwest::get(self.url.as_str())
+        let resp = send_traced(reqwest::Client::new().get(self.url.as_str()))
             .await
             .map_err(|_| AuthError::TemporarilyUnavailable)?;
         let ttl = resp
@@ -62,6 +67,16 @@ impl JwksCache {
         *self.inner.write().await = Some((set.clone(), SystemTime::now(), ttl));
         Ok(set)
     }
+
+    pub async fn status(&self) -> KeySetStatus {
+        match self.inner.read().await.as_ref() {
+            None => KeySetStatus::NotLoaded,
+            Some((_, at, ttl)) => KeySetStatus::Loaded {
+                fetched_at: DateTime::<Utc>::from(*at),
+                expires_at: DateTime::<Utc>::from(*at + *ttl),
+            },
+        }
+    }
 }
 
 #[derive(serde::Deserialize, Clone, Debug)]
@@ -69,6 +84,7 @@ struct FirebaseClaims {
     sub: String,
     email: Option<String>,
     iat: Option<i64>,
+    auth_time: Option<i64>,
 }
 
 #[derive(Clone)]
@@ -128,10 +144,17 @@ impl AuthenticatorPort for FirebaseAuthenticator {
             AuthError::Unauthorized
         })?;
 
+        let issued_at = data.claims.iat.ok_or(AuthError::Unauthorized)?;
         Ok(Principal {
             uid: data.claims.sub,
-            email: data.claims.email.ok_or(AuthError::Unauthorized)?,
-            issued_at: data.claims.iat.ok_or(AuthError::Unauthorized)?,
+            email: Some(data.claims.email.ok_or(AuthError::Unauthorized)?),
+            issued_at,
+            authenticated_at: data.claims.auth_time.unwrap_or(issued_at),
+            credential: Credential::IdToken,
         })
     }
+
+    async fn key_set_status(&self) -> KeySetStatus {
+        self.jwks.status().await
+    }
 }
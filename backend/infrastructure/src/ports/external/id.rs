@@ -9,4 +9,7 @@ impl IdProviderPort for UuidProvider {
     fn new_solution_id(&self) -> Uuid {
         Uuid::now_v7()
     }
+    fn new_personal_token_id(&self) -> Uuid {
+        Uuid::now_v7()
+    }
 }
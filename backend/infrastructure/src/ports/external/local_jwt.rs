@@ -0,0 +1,179 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use domain::ports::external::auth::{AuthError, AuthenticatorPort, Credential, Principal};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+    jwk::JwkSet,
+};
+use serde::{Deserialize, Serialize};
+use shared::config::{LocalJwtConfig, LocalJwtKey};
+use thiserror::Error;
+use tracing::error;
+
+#[derive(Error, Debug)]
+pub enum LocalJwtError {
+    #[error("failed to read key file {0}: {1}")]
+    Io(String, String),
+    #[error("invalid key: {0}")]
+    InvalidKey(String),
+    #[error("failed to sign token: {0}")]
+    Sign(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct LocalJwtClaims {
+    sub: String,
+    email: Option<String>,
+    iat: i64,
+    #[serde(default)]
+    auth_time: Option<i64>,
+    exp: i64,
+    iss: String,
+    aud: String,
+}
+
+enum LocalDecodingKeys {
+    Hmac(DecodingKey),
+    Rsa(DecodingKey),
+    Jwks(JwkSet),
+}
+
+fn read_key_file(path: &str) -> Result<String, LocalJwtError> {
+    std::fs::read_to_string(path).map_err(|e| LocalJwtError::Io(path.into(), e.to_string()))
+}
+
+// Firebaseを経由せず、ローカルの鍵でHS256/RS256のトークンを検証する
+pub struct LocalJwtAuthenticator {
+    issuer: String,
+    audience: String,
+    keys: LocalDecodingKeys,
+}
+
+impl LocalJwtAuthenticator {
+    pub fn from_config(config: &LocalJwtConfig) -> Result<Self, LocalJwtError> {
+        let keys = match &config.key {
+            LocalJwtKey::Secret(secret) => {
+                LocalDecodingKeys::Hmac(DecodingKey::from_secret(secret.as_bytes()))
+            }
+            LocalJwtKey::RsaPublicKeyPemPath(path) => LocalDecodingKeys::Rsa(
+                DecodingKey::from_rsa_pem(read_key_file(path)?.as_bytes())
+                    .map_err(|e| LocalJwtError::InvalidKey(e.to_string()))?,
+            ),
+            LocalJwtKey::JwksPath(path) => LocalDecodingKeys::Jwks(
+                serde_json::from_str(&read_key_file(path)?)
+                    .map_err(|e| LocalJwtError::InvalidKey(e.to_string()))?,
+            ),
+        };
+        Ok(Self {
+            issuer: config.issuer.to_owned(),
+            audience: config.audience.to_owned(),
+            keys,
+        })
+    }
+
+    fn decoding_key(&self, kid: Option<&str>) -> Result<(DecodingKey, Algorithm), AuthError> {
+        match &self.keys {
+            LocalDecodingKeys::Hmac(key) => Ok((key.to_owned(), Algorithm::HS256)),
+            LocalDecodingKeys::Rsa(key) => Ok((key.to_owned(), Algorithm::RS256)),
+            LocalDecodingKeys::Jwks(set) => {
+                // kidが無い場合は鍵が1つだけのときに限りそれを使う
+                let jwk = match kid {
+                    Some(kid) => set.find(kid),
+                    None if set.keys.len() == 1 => set.keys.first(),
+                    None => None,
+                }
+                .ok_or(AuthError::Unauthorized)?;
+                let key = DecodingKey::from_jwk(jwk).map_err(|_| AuthError::Unauthorized)?;
+                Ok((key, Algorithm::RS256))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AuthenticatorPort for LocalJwtAuthenticator {
+    async fn verify_id_token(&self, token: &str) -> Result<Principal, AuthError> {
+        let header = decode_header(token).map_err(|_| AuthError::Unauthorized)?;
+        let (key, algorithm) = self.decoding_key(header.kid.as_deref())?;
+        if header.alg != algorithm {
+            return Err(AuthError::Unauthorized);
+        }
+
+        let mut val = Validation::new(algorithm);
+        val.set_audience(&[self.audience.as_str()]);
+        val.set_issuer(&[self.issuer.as_str()]);
+        let data = decode::<LocalJwtClaims>(token, &key, &val).map_err(|e| {
+            error!(error = ?e);
+            AuthError::Unauthorized
+        })?;
+
+        Ok(Principal {
+            uid: data.claims.sub,
+            email: data.claims.email,
+            issued_at: data.claims.iat,
+            authenticated_at: data.claims.auth_time.unwrap_or(data.claims.iat),
+            credential: Credential::IdToken,
+        })
+    }
+}
+
+// LocalJwtAuthenticatorで検証できるトークンを発行する
+pub struct LocalJwtIssuer {
+    issuer: String,
+    audience: String,
+    header: Header,
+    key: EncodingKey,
+}
+
+impl LocalJwtIssuer {
+    // RS256の場合は公開鍵/JWKSに対応する秘密鍵(PEM)が必要
+    pub fn from_config(
+        config: &LocalJwtConfig,
+        private_key_pem_path: Option<&str>,
+        kid: Option<String>,
+    ) -> Result<Self, LocalJwtError> {
+        let (header, key) = match &config.key {
+            LocalJwtKey::Secret(secret) => (
+                Header::new(Algorithm::HS256),
+                EncodingKey::from_secret(secret.as_bytes()),
+            ),
+            LocalJwtKey::RsaPublicKeyPemPath(_) | LocalJwtKey::JwksPath(_) => {
+                let path = private_key_pem_path.ok_or_else(|| {
+                    LocalJwtError::InvalidKey("RS256 requires a private key".into())
+                })?;
+                let key = EncodingKey::from_rsa_pem(read_key_file(path)?.as_bytes())
+                    .map_err(|e| LocalJwtError::InvalidKey(e.to_string()))?;
+                (Header::new(Algorithm::RS256), key)
+            }
+        };
+        Ok(Self {
+            issuer: config.issuer.to_owned(),
+            audience: config.audience.to_owned(),
+            header: Header { kid, ..header },
+            key,
+        })
+    }
+
+    pub fn mint(
+        &self,
+        uid: &str,
+        email: Option<&str>,
+        ttl: Duration,
+    ) -> Result<String, LocalJwtError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| LocalJwtError::Sign(e.to_string()))?
+            .as_secs() as i64;
+        let claims = LocalJwtClaims {
+            sub: uid.into(),
+            email: email.map(str::to_string),
+            iat: now,
+            auth_time: Some(now),
+            exp: now + ttl.as_secs() as i64,
+            iss: self.issuer.to_owned(),
+            aud: self.audience.to_owned(),
+        };
+        encode(&self.header, &claims, &self.key).map_err(|e| LocalJwtError::Sign(e.to_string()))
+    }
+}
@@ -1,3 +1,5 @@
 pub mod atcoder_problems;
 pub mod auth;
 pub mod id;
+pub mod local_jwt;
+pub mod personal_token;
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use derive_new::new;
+use domain::{
+    model::personal_token::PERSONAL_TOKEN_PREFIX,
+    ports::{
+        external::{
+            auth::{AuthError, AuthenticatorPort, Credential, Principal},
+            personal_token::{GeneratedPersonalToken, PersonalTokenGeneratorPort},
+        },
+        repository::personal_token::PersonalTokenRepository,
+    },
+};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+// 一覧表示用に保持する平文の先頭の長さ
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+pub fn hash_personal_token(plain: &str) -> String {
+    format!("{:x}", Sha256::digest(plain.as_bytes()))
+}
+
+#[derive(new)]
+pub struct RandomPersonalTokenGenerator;
+
+impl PersonalTokenGeneratorPort for RandomPersonalTokenGenerator {
+    fn generate(&self) -> GeneratedPersonalToken {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        let plain = format!("{}{}", PERSONAL_TOKEN_PREFIX, URL_SAFE_NO_PAD.encode(bytes));
+        GeneratedPersonalToken {
+            hash: hash_personal_token(&plain),
+            prefix: plain[..DISPLAY_PREFIX_LEN].to_string(),
+            plain,
+        }
+    }
+}
+
+#[derive(new)]
+pub struct PersonalTokenAuthenticator {
+    personal_token_repository: Arc<dyn PersonalTokenRepository>,
+}
+
+#[async_trait]
+impl AuthenticatorPort for PersonalTokenAuthenticator {
+    async fn verify_id_token(&self, token: &str) -> Result<Principal, AuthError> {
+        if !token.starts_with(PERSONAL_TOKEN_PREFIX) {
+            return Err(AuthError::Unauthorized);
+        }
+        let found = self
+            .personal_token_repository
+            .find_active_by_hash(&hash_personal_token(token))
+            .await
+            .map_err(|_| AuthError::TemporarilyUnavailable)?
+            .ok_or(AuthError::Unauthorized)?;
+
+        // 最終利用日時の記録に失敗しても認証自体は通す
+        if let Err(error) = self
+            .personal_token_repository
+            .touch_last_used(found.id)
+            .await
+        {
+            warn!(token_id = %found.id, error = ?error, "failed to record personal token usage");
+        }
+
+        Ok(Principal {
+            uid: found.user_id,
+            email: None,
+            issued_at: found.created_at.timestamp(),
+            authenticated_at: found.created_at.timestamp(),
+            credential: Credential::PersonalToken {
+                token_id: found.id,
+                scopes: found.scopes,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        model::personal_token::PERSONAL_TOKEN_PREFIX,
+        ports::external::personal_token::PersonalTokenGeneratorPort,
+    };
+
+    use super::{RandomPersonalTokenGenerator, hash_personal_token};
+
+    #[test]
+    fn generated_token_has_prefix_and_matching_hash() {
+        let generated = RandomPersonalTokenGenerator::new().generate();
+
+        assert!(generated.plain.starts_with(PERSONAL_TOKEN_PREFIX));
+        assert!(generated.plain.starts_with(&generated.prefix));
+        assert_eq!(generated.hash, hash_personal_token(&generated.plain));
+        assert_ne!(generated.hash, generated.plain);
+    }
+
+    #[test]
+    fn generated_tokens_are_unique() {
+        let generator = RandomPersonalTokenGenerator::new();
+        assert_ne!(generator.generate().plain, generator.generate().plain);
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    error::repository::RepositoryError,
+    model::problem::{ContestReclassification, ContestSeries, ContestSeriesDefinition},
+    ports::repository::contest_series::ContestSeriesRepository,
+};
+use tracing::instrument;
+
+use crate::database::ConnectionPool;
+use crate::error::map_sqlx_error;
+
+#[derive(new)]
+pub struct ContestSeriesRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl ContestSeriesRepository for ContestSeriesRepositoryImpl {
+    #[instrument(name = "ContestSeriesRepository::list", skip_all)]
+    async fn list(&self) -> Result<Vec<ContestSeriesDefinition>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT code, display_name, code_pattern, sort_order
+            FROM contest_series
+            ORDER BY sort_order, code
+            "#
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ContestSeriesDefinition {
+                code: ContestSeries::new(row.code),
+                display_name: row.display_name,
+                code_pattern: row.code_pattern,
+                sort_order: row.sort_order,
+            })
+            .collect())
+    }
+
+    #[instrument(name = "ContestSeriesRepository::list_contest_assignments", skip_all)]
+    async fn list_contest_assignments(
+        &self,
+    ) -> Result<Vec<(String, ContestSeries)>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT code, series_code
+            FROM contests
+            "#
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.code, ContestSeries::new(row.series_code)))
+            .collect())
+    }
+
+    #[instrument(name = "ContestSeriesRepository::create_and_reclassify", skip_all)]
+    async fn create_and_reclassify(
+        &self,
+        definition: &ContestSeriesDefinition,
+        reclassifications: &[ContestReclassification],
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self.db.inner_ref().begin().await.map_err(map_sqlx_error)?;
+        sqlx::query!(
+            r#"
+            INSERT INTO contest_series (code, display_name, code_pattern, sort_order)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            definition.code.as_str(),
+            definition.display_name,
+            definition.code_pattern,
+            definition.sort_order
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let codes = reclassifications
+            .iter()
+            .map(|r| r.contest_code.clone())
+            .collect::<Vec<_>>();
+        let series_codes = reclassifications
+            .iter()
+            .map(|r| r.to.to_string())
+            .collect::<Vec<_>>();
+        sqlx::query!(
+            r#"
+            UPDATE contests c
+            SET series_code = r.series_code, updated_at = CURRENT_TIMESTAMP(3)
+            FROM UNNEST($1::text[], $2::text[]) AS r(code, series_code)
+            WHERE c.code = r.code
+            "#,
+            &codes,
+            &series_codes
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(())
+    }
+}
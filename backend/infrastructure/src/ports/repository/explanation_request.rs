@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    error::repository::RepositoryError,
+    model::explanation_request::{ExplanationRequest, WantedProblem, WantedProblemFilter},
+    ports::repository::explanation_request::ExplanationRequestRepository,
+};
+use tracing::instrument;
+
+use crate::error::map_sqlx_error;
+use crate::{database::ConnectionPool, model::explanation_request::WantedProblemRow};
+
+#[derive(new)]
+pub struct ExplanationRequestRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl ExplanationRequestRepository for ExplanationRequestRepositoryImpl {
+    #[instrument(name = "ExplanationRequestRepository::create", skip_all)]
+    async fn create(&self, user_id: &str, problem_id: &str) -> Result<bool, RepositoryError> {
+        // 解説が非表示や削除で無くなった後のリクエストは、達成済みのものを未達成に戻す
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO explanation_requests (user_id, problem_id)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id, problem_id) DO UPDATE
+            SET fulfilled_solution_id = NULL,
+                fulfilled_at = NULL,
+                created_at = NOW()
+            WHERE explanation_requests.fulfilled_at IS NOT NULL
+            "#,
+            user_id,
+            problem_id,
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(name = "ExplanationRequestRepository::delete", skip_all)]
+    async fn delete(&self, user_id: &str, problem_id: &str) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM explanation_requests
+            WHERE user_id = $1
+              AND problem_id = $2
+            "#,
+            user_id,
+            problem_id,
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    #[instrument(name = "ExplanationRequestRepository::list_by_user", skip_all)]
+    async fn list_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<ExplanationRequest>, RepositoryError> {
+        // 達成された時刻、未達成なら作成時刻の新しい順
+        let requests = sqlx::query_as!(
+            ExplanationRequest,
+            r#"
+            SELECT user_id, problem_id, fulfilled_solution_id, fulfilled_at, created_at
+            FROM explanation_requests
+            WHERE user_id = $1
+            ORDER BY COALESCE(fulfilled_at, created_at) DESC, problem_id
+            "#,
+            user_id,
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(requests)
+    }
+
+    #[instrument(name = "ExplanationRequestRepository::list_wanted", skip_all)]
+    async fn list_wanted(
+        &self,
+        filter: &WantedProblemFilter,
+    ) -> Result<Vec<WantedProblem>, RepositoryError> {
+        // 達成済みのリクエストは数えない。シリーズは一覧と同じく出題で判定し、そのシリーズでの出題を返す。
+        // 色帯は補正後のdifficultyで判定し、difficultyのない問題は範囲比較で除かれる
+        let (min_difficulty, max_difficulty) = filter
+            .band
+            .map(|band| band.difficulty_range())
+            .unwrap_or((None, None));
+        let rows = sqlx::query_as!(
+            WantedProblemRow,
+            r#"
+            SELECT
+                p.id,
+                a.contest_code AS "contest_code!",
+                a.problem_index AS "problem_index!",
+                p.title,
+                p.difficulty,
+                COUNT(*) AS "request_count!",
+                MAX(r.created_at) AS "last_requested_at!"
+            FROM explanation_requests r
+            JOIN problems p ON p.id = r.problem_id
+            JOIN LATERAL (
+                SELECT pa.contest_code, pa.problem_index
+                FROM problem_appearances pa
+                JOIN contests c ON c.code = pa.contest_code
+                WHERE pa.problem_id = p.id
+                  AND c.series_code = $1
+                ORDER BY pa.contest_code = p.contest_code DESC, pa.contest_code
+                LIMIT 1
+            ) a ON TRUE
+            WHERE r.fulfilled_at IS NULL
+              AND ($2::INT IS NULL OR clip_difficulty(p.difficulty) >= $2)
+              AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) < $3)
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM solutions s
+                  WHERE s.problem_id = p.id
+                    AND s.hidden_at IS NULL
+              )
+            GROUP BY p.id, a.contest_code, a.problem_index
+            ORDER BY COUNT(*) DESC, MAX(r.created_at) DESC, p.id
+            LIMIT $4 OFFSET $5
+            "#,
+            filter.series.as_str(),
+            min_difficulty,
+            max_difficulty,
+            filter.limit,
+            filter.offset,
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(rows.into_iter().map(WantedProblem::from).collect())
+    }
+}
@@ -1,8 +1,15 @@
+use std::collections::HashSet;
+
 use async_trait::async_trait;
 use derive_new::new;
-use domain::ports::repository::health::HealthCheckRepository;
+use domain::{
+    error::repository::RepositoryError, model::health::MigrationStatus,
+    ports::repository::health::HealthCheckRepository,
+};
+use tracing::instrument;
 
-use crate::database::ConnectionPool;
+use crate::database::{ConnectionPool, MIGRATOR, UNDEFINED_TABLE};
+use crate::error::map_sqlx_error;
 
 #[derive(new)]
 pub struct HealthCheckRepositoryImpl {
@@ -11,10 +18,47 @@ pub struct HealthCheckRepositoryImpl {
 
 #[async_trait]
 impl HealthCheckRepository for HealthCheckRepositoryImpl {
+    #[instrument(name = "HealthCheckRepository::check_db", skip_all)]
     async fn check_db(&self) -> bool {
         sqlx::query("SELECT 1")
             .fetch_one(self.db.inner_ref())
             .await
             .is_ok()
     }
+
+    #[instrument(name = "HealthCheckRepository::migration_status", skip_all)]
+    async fn migration_status(&self) -> Result<MigrationStatus, RepositoryError> {
+        let applied = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT version
+            FROM _sqlx_migrations
+            WHERE success
+            "#,
+        )
+        .fetch_all(self.db.inner_ref())
+        .await;
+        // 一度もマイグレーションしていないDBには管理テーブルが無い
+        let applied = match applied {
+            Ok(versions) => versions.into_iter().collect::<HashSet<i64>>(),
+            Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some(UNDEFINED_TABLE) => {
+                HashSet::new()
+            }
+            Err(e) => return Err(map_sqlx_error(e)),
+        };
+
+        let embedded = MIGRATOR
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .map(|m| m.version)
+            .collect::<Vec<i64>>();
+
+        Ok(MigrationStatus {
+            applied_version: applied.iter().max().copied(),
+            expected_version: embedded.iter().max().copied(),
+            pending_versions: embedded
+                .into_iter()
+                .filter(|v| !applied.contains(v))
+                .collect(),
+        })
+    }
 }
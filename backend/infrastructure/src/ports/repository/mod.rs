@@ -1,4 +1,9 @@
+pub mod contest_series;
+pub mod explanation_request;
 pub mod health;
+pub mod personal_token;
 pub mod problem;
+pub mod problem_import;
+pub mod session;
 pub mod solution;
 pub mod user;
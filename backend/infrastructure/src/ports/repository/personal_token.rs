@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    error::repository::RepositoryError,
+    model::personal_token::{NewPersonalToken, PersonalToken},
+    ports::repository::personal_token::PersonalTokenRepository,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::error::map_sqlx_error;
+use crate::{database::ConnectionPool, model::personal_token::PersonalTokenRow};
+
+#[derive(new)]
+pub struct PersonalTokenRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl PersonalTokenRepository for PersonalTokenRepositoryImpl {
+    #[instrument(name = "PersonalTokenRepository::create", skip_all)]
+    async fn create(&self, token: &NewPersonalToken) -> Result<PersonalToken, RepositoryError> {
+        let scopes: Vec<String> = token.scopes.iter().map(|s| s.to_string()).collect();
+        let row = sqlx::query_as!(
+            PersonalTokenRow,
+            r#"
+            INSERT INTO personal_access_tokens (id, user_id, name, token_hash, token_prefix, scopes)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, name, token_prefix, scopes, last_used_at, created_at
+            "#,
+            token.id,
+            token.user_id,
+            token.name,
+            token.token_hash,
+            token.token_prefix,
+            &scopes,
+        )
+        .fetch_one(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.try_into()
+    }
+
+    #[instrument(name = "PersonalTokenRepository::list_by_user", skip_all)]
+    async fn list_by_user(&self, user_id: &str) -> Result<Vec<PersonalToken>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            PersonalTokenRow,
+            r#"
+            SELECT id, user_id, name, token_prefix, scopes, last_used_at, created_at
+            FROM personal_access_tokens
+            WHERE user_id = $1
+              AND revoked_at IS NULL
+            ORDER BY created_at DESC, id DESC
+            "#,
+            user_id,
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(PersonalToken::try_from).collect()
+    }
+
+    #[instrument(name = "PersonalTokenRepository::revoke", skip_all)]
+    async fn revoke(&self, user_id: &str, token_id: Uuid) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE personal_access_tokens
+            SET revoked_at = NOW()
+            WHERE id = $1
+              AND user_id = $2
+              AND revoked_at IS NULL
+            "#,
+            token_id,
+            user_id,
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "personal token not found: {}",
+                token_id
+            )));
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "PersonalTokenRepository::find_active_by_hash", skip_all)]
+    async fn find_active_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<PersonalToken>, RepositoryError> {
+        let row = sqlx::query_as!(
+            PersonalTokenRow,
+            r#"
+            SELECT id, user_id, name, token_prefix, scopes, last_used_at, created_at
+            FROM personal_access_tokens
+            WHERE token_hash = $1
+              AND revoked_at IS NULL
+            "#,
+            token_hash,
+        )
+        .fetch_optional(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(PersonalToken::try_from).transpose()
+    }
+
+    #[instrument(name = "PersonalTokenRepository::touch_last_used", skip_all)]
+    async fn touch_last_used(&self, token_id: Uuid) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            UPDATE personal_access_tokens
+            SET last_used_at = NOW()
+            WHERE id = $1
+            "#,
+            token_id,
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+}
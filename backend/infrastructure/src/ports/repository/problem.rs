@@ -6,10 +6,14 @@ use async_trait::async_trait;
 use derive_new::new;
 use domain::{
     error::repository::RepositoryError,
-    model::problem::{ContestSeries, Problem},
+    model::problem::{
+        ContestMetadata, ContestSeries, ContestSeriesCatalog, ContestSortKey, DifficultyRange,
+        Problem, ProblemAppearance,
+    },
     ports::repository::problem::ProblemRepository,
 };
 use sqlx::PgPool;
+use tracing::instrument;
 
 use crate::database::ConnectionPool;
 use crate::error::map_sqlx_error;
@@ -21,26 +25,44 @@ pub struct ProblemRepositoryImpl {
 
 #[async_trait]
 impl ProblemRepository for ProblemRepositoryImpl {
-    async fn create_records(&self, problems: Vec<Problem>) -> Result<(), RepositoryError> {
+    #[instrument(name = "ProblemRepository::create_records", skip_all)]
+    async fn create_records(
+        &self,
+        problems: Vec<Problem>,
+        catalog: &ContestSeriesCatalog,
+    ) -> Result<(), RepositoryError> {
         let contests = problems
             .iter()
-            .map(|e| {
-                let series = ContestSeries::try_from(e.contest_code.as_str())
-                    .unwrap_or(ContestSeries::OTHER);
-                (e.contest_code.as_str(), series)
-            })
+            .map(|e| (e.contest_code.as_str(), catalog.classify(&e.contest_code)))
             .collect::<HashSet<(&str, ContestSeries)>>();
 
         for contest in contests.iter() {
-            safe_insert_contest(contest.0, contest.1.into(), self.db.inner_ref()).await?;
+            safe_insert_contest(contest.0, contest.1.as_str(), self.db.inner_ref()).await?;
         }
 
         for problem in problems.iter() {
             upsert_problem(problem, self.db.inner_ref()).await?;
+            upsert_own_appearance(problem, self.db.inner_ref()).await?;
         }
         Ok(())
     }
 
+    #[instrument(name = "ProblemRepository::get_all_problems", skip_all)]
+    async fn get_all_problems(&self) -> Result<Vec<Problem>, RepositoryError> {
+        let problems = sqlx::query_as!(
+            Problem,
+            r#"
+            SELECT id, contest_code, problem_index, title, difficulty
+            FROM problems
+            "#
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(problems)
+    }
+
+    #[instrument(name = "ProblemRepository::get_problem_ids_with_difficulty", skip_all)]
     async fn get_problem_ids_with_difficulty(
         &self,
         problem_ids: &[String],
@@ -65,6 +87,7 @@ impl ProblemRepository for ProblemRepositoryImpl {
         Ok(rows)
     }
 
+    #[instrument(name = "ProblemRepository::get_problems_by_contest_series", skip_all)]
     async fn get_problems_by_contest_series(
         &self,
         series: ContestSeries,
@@ -72,12 +95,13 @@ impl ProblemRepository for ProblemRepositoryImpl {
         let problems: Vec<Problem> = sqlx::query_as!(
             Problem,
             r#"
-            SELECT p.id, p.contest_code, p.problem_index, p.title, p.difficulty
-            FROM problems p
-            JOIN contests c ON c.code = p.contest_code
+            SELECT p.id, a.contest_code, a.problem_index, p.title, p.difficulty
+            FROM problem_appearances a
+            JOIN problems p ON p.id = a.problem_id
+            JOIN contests c ON c.code = a.contest_code
             JOIN contest_series s ON s.code = c.series_code
             WHERE s.code = $1
-            ORDER BY p.contest_code DESC
+            ORDER BY a.contest_code DESC
             "#,
             series.to_string(),
         )
@@ -87,35 +111,56 @@ impl ProblemRepository for ProblemRepositoryImpl {
         Ok(problems)
     }
 
+    #[instrument(name = "ProblemRepository::get_contest_codes_by_series", skip_all)]
     async fn get_contest_codes_by_series(
         &self,
         series: ContestSeries,
+        difficulty: DifficultyRange,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<String>, RepositoryError> {
-        let contest_codes = sqlx::query_scalar!(
+        // ContestSortKeyの新しい順。コードの比較はnatural_sort_keyで数字の大きさを反映する
+        let codes = sqlx::query_scalar!(
             r#"
             SELECT c.code
             FROM contests c
             JOIN contest_series s ON s.code = c.series_code
             WHERE s.code = $1
-            ORDER BY c.code DESC
-            LIMIT $2 OFFSET $3
+              AND (
+                  ($2::INT IS NULL AND $3::INT IS NULL)
+                  OR EXISTS (
+                      SELECT 1
+                      FROM problem_appearances a
+                      JOIN problems p ON p.id = a.problem_id
+                      WHERE a.contest_code = c.code
+                        AND ($2::INT IS NULL OR clip_difficulty(p.difficulty) >= $2)
+                        AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) <= $3)
+                  )
+              )
+            ORDER BY
+                c.start_at DESC NULLS LAST,
+                natural_sort_key(c.code) COLLATE "C" DESC,
+                c.code COLLATE "C" DESC
+            LIMIT $4 OFFSET $5
             "#,
             series.to_string(),
-            limit,
-            offset,
+            difficulty.min,
+            difficulty.max,
+            limit.max(0),
+            offset.max(0),
         )
         .fetch_all(self.db.inner_ref())
         .await
         .map_err(map_sqlx_error)?;
 
-        Ok(contest_codes)
+        Ok(codes)
     }
 
+    #[instrument(name = "ProblemRepository::get_problems_by_contest_codes", skip_all)]
     async fn get_problems_by_contest_codes(
         &self,
         contest_codes: &[String],
+        difficulty: DifficultyRange,
     ) -> Result<Vec<Problem>, RepositoryError> {
         if contest_codes.is_empty() {
             return Ok(vec![]);
@@ -124,12 +169,17 @@ impl ProblemRepository for ProblemRepositoryImpl {
         let problems = sqlx::query_as!(
             Problem,
             r#"
-            SELECT p.id, p.contest_code, p.problem_index, p.title, p.difficulty
-            FROM problems p
-            WHERE p.contest_code = ANY($1)
-            ORDER BY p.contest_code DESC
+            SELECT p.id, a.contest_code, a.problem_index, p.title, p.difficulty
+            FROM problem_appearances a
+            JOIN problems p ON p.id = a.problem_id
+            WHERE a.contest_code = ANY($1)
+              AND ($2::INT IS NULL OR clip_difficulty(p.difficulty) >= $2)
+              AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) <= $3)
+            ORDER BY a.contest_code DESC
             "#,
-            contest_codes
+            contest_codes,
+            difficulty.min,
+            difficulty.max,
         )
         .fetch_all(self.db.inner_ref())
         .await
@@ -138,10 +188,15 @@ impl ProblemRepository for ProblemRepositoryImpl {
         Ok(problems)
     }
 
+    #[instrument(
+        name = "ProblemRepository::search_problems_by_contest_series",
+        skip_all
+    )]
     async fn search_problems_by_contest_series(
         &self,
         series: ContestSeries,
         query: &str,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<Problem>, RepositoryError> {
         let query = query.to_lowercase();
         let problems = sqlx::query_as!(
@@ -154,21 +209,26 @@ impl ProblemRepository for ProblemRepositoryImpl {
                 WHERE s.code = $1
                   AND position($2 in LOWER(c.code)) > 0
             )
-            SELECT p.id, p.contest_code, p.problem_index, p.title, p.difficulty
-            FROM problems p
-            JOIN contests c ON c.code = p.contest_code
+            SELECT p.id, a.contest_code, a.problem_index, p.title, p.difficulty
+            FROM problem_appearances a
+            JOIN problems p ON p.id = a.problem_id
+            JOIN contests c ON c.code = a.contest_code
             JOIN contest_series s ON s.code = c.series_code
             WHERE s.code = $1
               AND (
-                  p.contest_code IN (SELECT code FROM matched_contests)
+                  a.contest_code IN (SELECT code FROM matched_contests)
                   OR position($2 in LOWER(p.id)) > 0
-                  OR position($2 in LOWER(p.problem_index)) > 0
+                  OR position($2 in LOWER(a.problem_index)) > 0
                   OR position($2 in LOWER(p.title)) > 0
               )
-            ORDER BY p.contest_code DESC
+              AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) >= $3)
+              AND ($4::INT IS NULL OR clip_difficulty(p.difficulty) <= $4)
+            ORDER BY a.contest_code DESC
             "#,
             series.to_string(),
             query,
+            difficulty.min,
+            difficulty.max,
         )
         .fetch_all(self.db.inner_ref())
         .await
@@ -177,6 +237,7 @@ impl ProblemRepository for ProblemRepositoryImpl {
         Ok(problems)
     }
 
+    #[instrument(name = "ProblemRepository::get_problem_by_id", skip_all)]
     async fn get_problem_by_id(&self, problem_id: &str) -> Result<Problem, RepositoryError> {
         let problem = sqlx::query_as!(
             Problem,
@@ -195,6 +256,7 @@ impl ProblemRepository for ProblemRepositoryImpl {
         Ok(problem)
     }
 
+    #[instrument(name = "ProblemRepository::get_problems_by_contest", skip_all)]
     async fn get_problems_by_contest(
         &self,
         contest: &str,
@@ -202,11 +264,10 @@ impl ProblemRepository for ProblemRepositoryImpl {
         let problems: Vec<Problem> = sqlx::query_as!(
             Problem,
             r#"
-            SELECT p.id, p.contest_code, p.problem_index, p.title, p.difficulty
-            FROM problems p
-            JOIN contests c ON c.code = p.contest_code
-            JOIN contest_series s ON s.code = c.series_code
-            WHERE p.contest_code = $1
+            SELECT p.id, a.contest_code, a.problem_index, p.title, p.difficulty
+            FROM problem_appearances a
+            JOIN problems p ON p.id = a.problem_id
+            WHERE a.contest_code = $1
             "#,
             contest.to_ascii_lowercase()
         )
@@ -215,6 +276,117 @@ impl ProblemRepository for ProblemRepositoryImpl {
         .map_err(map_sqlx_error)?;
         Ok(problems)
     }
+
+    #[instrument(name = "ProblemRepository::get_all_problem_appearances", skip_all)]
+    async fn get_all_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, RepositoryError> {
+        let appearances = sqlx::query_as!(
+            ProblemAppearance,
+            r#"
+            SELECT contest_code, problem_index, problem_id
+            FROM problem_appearances
+            "#
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(appearances)
+    }
+
+    #[instrument(name = "ProblemRepository::get_problem_id_by_appearance", skip_all)]
+    async fn get_problem_id_by_appearance(
+        &self,
+        contest_code: &str,
+        problem_index: &str,
+    ) -> Result<String, RepositoryError> {
+        // indexは大文字小文字を区別せず、一致するものがあればそれを優先する。同じindexの問題が複数あればidの順で選ぶ
+        sqlx::query_scalar!(
+            r#"
+            SELECT problem_id
+            FROM problem_appearances
+            WHERE contest_code = $1
+              AND LOWER(problem_index) = LOWER($2)
+            ORDER BY problem_index = $2 DESC, problem_id
+            LIMIT 1
+            "#,
+            contest_code.to_ascii_lowercase(),
+            problem_index
+        )
+        .fetch_optional(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(|| RepositoryError::NotFound(format!("{contest_code}/{problem_index}")))
+    }
+
+    #[instrument(name = "ProblemRepository::get_contest_sort_keys", skip_all)]
+    async fn get_contest_sort_keys(
+        &self,
+        contest_codes: &[String],
+    ) -> Result<Vec<ContestSortKey>, RepositoryError> {
+        if contest_codes.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let keys = sqlx::query_as!(
+            ContestSortKey,
+            r#"
+            SELECT code, start_at
+            FROM contests
+            WHERE code = ANY($1)
+            "#,
+            contest_codes
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(keys)
+    }
+
+    #[instrument(name = "ProblemRepository::get_all_contest_metadata", skip_all)]
+    async fn get_all_contest_metadata(&self) -> Result<Vec<ContestMetadata>, RepositoryError> {
+        let contests = sqlx::query_as!(
+            ContestMetadata,
+            r#"
+            SELECT
+                code,
+                title AS "title!",
+                start_at AS "start_at!",
+                duration_second AS "duration_second!",
+                rate_change AS "rate_change!"
+            FROM contests
+            WHERE start_at IS NOT NULL
+            "#
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(contests)
+    }
+
+    #[instrument(name = "ProblemRepository::get_contest_metadata", skip_all)]
+    async fn get_contest_metadata(
+        &self,
+        contest: &str,
+    ) -> Result<Option<ContestMetadata>, RepositoryError> {
+        let contest = sqlx::query_as!(
+            ContestMetadata,
+            r#"
+            SELECT
+                code,
+                title AS "title!",
+                start_at AS "start_at!",
+                duration_second AS "duration_second!",
+                rate_change AS "rate_change!"
+            FROM contests
+            WHERE code = $1
+              AND start_at IS NOT NULL
+            "#,
+            contest.to_ascii_lowercase()
+        )
+        .fetch_optional(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(contest)
+    }
 }
 
 async fn safe_insert_contest(
@@ -252,7 +424,7 @@ async fn upsert_problem(problem: &Problem, db: &PgPool) -> Result<(), Repository
         "#,
         problem.id,
         problem.contest_code,
-        problem.problem_index,
+        problem.problem_index.as_str(),
         problem.title,
         problem.difficulty
     )
@@ -261,3 +433,21 @@ async fn upsert_problem(problem: &Problem, db: &PgPool) -> Result<(), Repository
     .map_err(map_sqlx_error)?;
     Ok(())
 }
+
+async fn upsert_own_appearance(problem: &Problem, db: &PgPool) -> Result<(), RepositoryError> {
+    sqlx::query!(
+        r#"
+        INSERT INTO problem_appearances (contest_code, problem_index, problem_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (contest_code, problem_id) DO UPDATE
+        SET problem_index = EXCLUDED.problem_index
+        "#,
+        problem.contest_code,
+        problem.problem_index.as_str(),
+        problem.id
+    )
+    .execute(db)
+    .await
+    .map_err(map_sqlx_error)?;
+    Ok(())
+}
@@ -2,10 +2,11 @@ use async_trait::async_trait;
 use derive_new::new;
 use domain::{
     error::repository::RepositoryError,
-    model::problem::Problem,
+    model::problem::{ContestMetadata, Problem, ProblemAppearance},
     ports::repository::problem::tx::{ProblemRepositoryTx, ProblemTxManager, ProblemUnitOfWork},
 };
 use sqlx::{Postgres, QueryBuilder, Transaction};
+use tracing::instrument;
 
 use crate::{database::ConnectionPool, error::map_sqlx_error};
 
@@ -16,6 +17,7 @@ pub struct ProblemTransactionManager {
 
 #[async_trait]
 impl ProblemTxManager for ProblemTransactionManager {
+    #[instrument(name = "ProblemTxManager::begin", skip_all)]
     async fn begin(&self) -> Result<Box<dyn ProblemUnitOfWork>, RepositoryError> {
         let tx = self.db.inner_ref().begin().await.map_err(map_sqlx_error)?;
         Ok(Box::new(ProblemUnitOfWorkImpl::new(tx)))
@@ -41,11 +43,13 @@ impl ProblemUnitOfWork for ProblemUnitOfWorkImpl {
         self
     }
 
+    #[instrument(name = "ProblemUnitOfWork::commit", skip_all)]
     async fn commit(mut self: Box<Self>) -> Result<(), RepositoryError> {
         self.tx.commit().await.map_err(map_sqlx_error)?;
         Ok(())
     }
 
+    #[instrument(name = "ProblemUnitOfWork::rollback", skip_all)]
     async fn rollback(mut self: Box<Self>) -> Result<(), RepositoryError> {
         self.tx.rollback().await.map_err(map_sqlx_error)?;
         Ok(())
@@ -54,6 +58,7 @@ impl ProblemUnitOfWork for ProblemUnitOfWorkImpl {
 
 #[async_trait]
 impl ProblemRepositoryTx for ProblemUnitOfWorkImpl {
+    #[instrument(name = "ProblemRepositoryTx::upsert_contest", skip_all)]
     async fn upsert_contest(
         &mut self,
         contest_code: &str,
@@ -75,6 +80,7 @@ impl ProblemRepositoryTx for ProblemUnitOfWorkImpl {
         Ok(())
     }
 
+    #[instrument(name = "ProblemRepositoryTx::upsert_problem", skip_all)]
     async fn upsert_problem(
         &mut self,
         problem_id: &str,
@@ -106,6 +112,7 @@ impl ProblemRepositoryTx for ProblemUnitOfWorkImpl {
         Ok(())
     }
 
+    #[instrument(name = "ProblemRepositoryTx::upsert_contests_bulk", skip_all)]
     async fn upsert_contests_bulk(
         &mut self,
         contests: &[(String, String)],
@@ -130,6 +137,7 @@ impl ProblemRepositoryTx for ProblemUnitOfWorkImpl {
         Ok(())
     }
 
+    #[instrument(name = "ProblemRepositoryTx::upsert_problems_bulk", skip_all)]
     async fn upsert_problems_bulk(&mut self, problems: &[Problem]) -> Result<(), RepositoryError> {
         if problems.is_empty() {
             return Ok(());
@@ -142,7 +150,7 @@ impl ProblemRepositoryTx for ProblemUnitOfWorkImpl {
         query_builder.push_values(problems.iter(), |mut b, problem| {
             b.push_bind(&problem.id)
                 .push_bind(&problem.contest_code)
-                .push_bind(&problem.problem_index)
+                .push_bind(problem.problem_index.as_str())
                 .push_bind(&problem.title)
                 .push_bind(problem.difficulty);
         });
@@ -157,4 +165,88 @@ impl ProblemRepositoryTx for ProblemUnitOfWorkImpl {
             .map_err(map_sqlx_error)?;
         Ok(())
     }
+
+    #[instrument(name = "ProblemRepositoryTx::update_contest_metadata_bulk", skip_all)]
+    async fn update_contest_metadata_bulk(
+        &mut self,
+        contests: &[ContestMetadata],
+    ) -> Result<(), RepositoryError> {
+        if contests.is_empty() {
+            return Ok(());
+        }
+
+        let codes = contests.iter().map(|c| c.code.clone()).collect::<Vec<_>>();
+        let titles = contests.iter().map(|c| c.title.clone()).collect::<Vec<_>>();
+        let start_ats = contests.iter().map(|c| c.start_at).collect::<Vec<_>>();
+        let durations = contests
+            .iter()
+            .map(|c| c.duration_second)
+            .collect::<Vec<_>>();
+        let rate_changes = contests
+            .iter()
+            .map(|c| c.rate_change.clone())
+            .collect::<Vec<_>>();
+        sqlx::query!(
+            r#"
+            UPDATE contests c
+            SET title = m.title,
+                start_at = m.start_at,
+                duration_second = m.duration_second,
+                rate_change = m.rate_change
+            FROM UNNEST($1::text[], $2::text[], $3::timestamptz[], $4::bigint[], $5::text[])
+                AS m(code, title, start_at, duration_second, rate_change)
+            WHERE c.code = m.code
+            "#,
+            &codes,
+            &titles,
+            &start_ats,
+            &durations,
+            &rate_changes
+        )
+        .execute(self.conn())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+
+    #[instrument(
+        name = "ProblemRepositoryTx::upsert_problem_appearances_bulk",
+        skip_all
+    )]
+    async fn upsert_problem_appearances_bulk(
+        &mut self,
+        appearances: &[ProblemAppearance],
+    ) -> Result<(), RepositoryError> {
+        if appearances.is_empty() {
+            return Ok(());
+        }
+
+        let contest_codes = appearances
+            .iter()
+            .map(|a| a.contest_code.clone())
+            .collect::<Vec<_>>();
+        let problem_indexes = appearances
+            .iter()
+            .map(|a| a.problem_index.to_string())
+            .collect::<Vec<_>>();
+        let problem_ids = appearances
+            .iter()
+            .map(|a| a.problem_id.clone())
+            .collect::<Vec<_>>();
+        sqlx::query!(
+            r#"
+            INSERT INTO problem_appearances (contest_code, problem_index, problem_id)
+            SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[])
+            ON CONFLICT (contest_code, problem_id) DO UPDATE
+            SET problem_index = EXCLUDED.problem_index
+            "#,
+            &contest_codes,
+            &problem_indexes,
+            &problem_ids
+        )
+        .execute(self.conn())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(())
+    }
 }
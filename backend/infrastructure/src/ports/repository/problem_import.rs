@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    error::repository::RepositoryError,
+    model::problem_import::{NewProblemImportRun, ProblemImportRun},
+    ports::repository::problem_import::ProblemImportRunRepository,
+};
+use tracing::instrument;
+
+use crate::error::map_sqlx_error;
+use crate::{database::ConnectionPool, model::problem_import::ProblemImportRunRow};
+
+#[derive(new)]
+pub struct ProblemImportRunRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl ProblemImportRunRepository for ProblemImportRunRepositoryImpl {
+    #[instrument(name = "ProblemImportRunRepository::record", skip_all)]
+    async fn record(&self, run: &NewProblemImportRun) -> Result<ProblemImportRun, RepositoryError> {
+        let changes = run.changes;
+        let row = sqlx::query_as!(
+            ProblemImportRunRow,
+            r#"
+            INSERT INTO problem_import_runs
+                (started_at, finished_at, outcome, problem_count, inserted_count, updated_count,
+                 unchanged_count, removed_count, difficulty_fetch_failures,
+                 difficulty_fetch_deferred, error_message)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING id, started_at, finished_at, outcome, problem_count, inserted_count,
+                updated_count, unchanged_count, removed_count, difficulty_fetch_failures,
+                difficulty_fetch_deferred, error_message
+            "#,
+            run.started_at,
+            run.finished_at,
+            run.outcome.to_string(),
+            run.problem_count,
+            changes.map(|c| c.inserted),
+            changes.map(|c| c.updated),
+            changes.map(|c| c.unchanged),
+            changes.map(|c| c.removed),
+            changes.map(|c| c.difficulty_fetch_failures),
+            changes.map(|c| c.difficulty_fetch_deferred),
+            run.error_message,
+        )
+        .fetch_one(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.try_into()
+    }
+
+    #[instrument(name = "ProblemImportRunRepository::latest", skip_all)]
+    async fn latest(&self) -> Result<Option<ProblemImportRun>, RepositoryError> {
+        let row = sqlx::query_as!(
+            ProblemImportRunRow,
+            r#"
+            SELECT id, started_at, finished_at, outcome, problem_count, inserted_count,
+                updated_count, unchanged_count, removed_count, difficulty_fetch_failures,
+                difficulty_fetch_deferred, error_message
+            FROM problem_import_runs
+            ORDER BY finished_at DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        row.map(ProblemImportRun::try_from).transpose()
+    }
+
+    #[instrument(name = "ProblemImportRunRepository::list_recent", skip_all)]
+    async fn list_recent(&self, limit: i64) -> Result<Vec<ProblemImportRun>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            ProblemImportRunRow,
+            r#"
+            SELECT id, started_at, finished_at, outcome, problem_count, inserted_count,
+                updated_count, unchanged_count, removed_count, difficulty_fetch_failures,
+                difficulty_fetch_deferred, error_message
+            FROM problem_import_runs
+            ORDER BY finished_at DESC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        rows.into_iter().map(ProblemImportRun::try_from).collect()
+    }
+}
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::{
+    error::repository::RepositoryError,
+    model::session::{SessionActivity, SessionStatus, TouchedSession, UserSession},
+    ports::repository::session::SessionRepository,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::error::map_sqlx_error;
+use crate::{database::ConnectionPool, model::session::UserSessionRow};
+
+#[derive(new)]
+pub struct SessionRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl SessionRepository for SessionRepositoryImpl {
+    #[instrument(name = "SessionRepository::touch", skip_all)]
+    async fn touch(&self, activity: &SessionActivity) -> Result<TouchedSession, RepositoryError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO user_sessions (user_id, authenticated_at, user_agent, ip_address)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, authenticated_at) DO UPDATE
+            SET last_seen_at = CASE
+                    WHEN user_sessions.revoked_at IS NULL THEN NOW()
+                    ELSE user_sessions.last_seen_at
+                END,
+                user_agent = COALESCE(EXCLUDED.user_agent, user_sessions.user_agent),
+                ip_address = COALESCE(EXCLUDED.ip_address, user_sessions.ip_address)
+            RETURNING id, revoked_at IS NOT NULL AS "revoked!"
+            "#,
+            activity.user_id,
+            activity.authenticated_at,
+            activity.user_agent,
+            activity.ip_address,
+        )
+        .fetch_one(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(TouchedSession {
+            id: row.id,
+            revoked: row.revoked,
+        })
+    }
+
+    #[instrument(name = "SessionRepository::find_status", skip_all)]
+    async fn find_status(
+        &self,
+        user_id: &str,
+        authenticated_at: DateTime<Utc>,
+    ) -> Result<Option<SessionStatus>, RepositoryError> {
+        let status = sqlx::query_as!(
+            SessionStatus,
+            r#"
+            SELECT revoked_at IS NOT NULL AS "revoked!", last_seen_at
+            FROM user_sessions
+            WHERE user_id = $1
+              AND authenticated_at = $2
+            "#,
+            user_id,
+            authenticated_at,
+        )
+        .fetch_optional(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(status)
+    }
+
+    #[instrument(name = "SessionRepository::list_active_by_user", skip_all)]
+    async fn list_active_by_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserSession>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            UserSessionRow,
+            r#"
+            SELECT id, user_id, authenticated_at, user_agent, ip_address, created_at, last_seen_at
+            FROM user_sessions
+            WHERE user_id = $1
+              AND revoked_at IS NULL
+            ORDER BY last_seen_at DESC, id DESC
+            "#,
+            user_id,
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(rows.into_iter().map(UserSession::from).collect())
+    }
+
+    #[instrument(name = "SessionRepository::revoke", skip_all)]
+    async fn revoke(&self, user_id: &str, session_id: Uuid) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE user_sessions
+            SET revoked_at = NOW()
+            WHERE id = $1
+              AND user_id = $2
+              AND revoked_at IS NULL
+            "#,
+            session_id,
+            user_id,
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "session not found: {}",
+                session_id
+            )));
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "SessionRepository::revoke_all_by_user", skip_all)]
+    async fn revoke_all_by_user(&self, user_id: &str) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            UPDATE user_sessions
+            SET revoked_at = NOW()
+            WHERE user_id = $1
+              AND revoked_at IS NULL
+            "#,
+            user_id,
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(())
+    }
+}
@@ -3,10 +3,11 @@ use derive_new::new;
 use domain::error::repository::RepositoryError;
 use domain::model::solution::Solution;
 use domain::ports::repository::solution::tx::{
-    CommentRepositoryTx, CreatedComment, SolutionRespositoryTx, SolutionTxManager, TagRepositoryTx,
-    UnitOfWork, VoteRepositoryTx,
+    CommentRepositoryTx, CreatedComment, ExplanationRequestRepositoryTx, SolutionRespositoryTx,
+    SolutionTxManager, TagRepositoryTx, UnitOfWork, VoteRepositoryTx,
 };
 use sqlx::{Postgres, Transaction};
+use tracing::instrument;
 use uuid::Uuid;
 
 use crate::database::ConnectionPool;
@@ -19,6 +20,7 @@ pub struct SolutionTransactionManager {
 
 #[async_trait]
 impl SolutionTxManager for SolutionTransactionManager {
+    #[instrument(name = "SolutionTxManager::begin", skip_all)]
     async fn begin(&self) -> Result<Box<dyn UnitOfWork>, RepositoryError> {
         let tx = self.db.inner_ref().begin().await.map_err(map_sqlx_error)?;
         Ok(Box::new(SolutionUnitOfWork::new(tx)))
@@ -52,11 +54,16 @@ impl UnitOfWork for SolutionUnitOfWork {
     fn comments(&mut self) -> &mut dyn CommentRepositoryTx {
         self
     }
+    fn explanation_requests(&mut self) -> &mut dyn ExplanationRequestRepositoryTx {
+        self
+    }
 
+    #[instrument(name = "UnitOfWork::commit", skip_all)]
     async fn commit(mut self: Box<Self>) -> Result<(), RepositoryError> {
         self.tx.commit().await.map_err(map_sqlx_error)?;
         Ok(())
     }
+    #[instrument(name = "UnitOfWork::rollback", skip_all)]
     async fn rollback(mut self: Box<Self>) -> Result<(), RepositoryError> {
         self.tx.rollback().await.map_err(map_sqlx_error)?;
         Ok(())
@@ -65,6 +72,7 @@ impl UnitOfWork for SolutionUnitOfWork {
 
 #[async_trait]
 impl SolutionRespositoryTx for SolutionUnitOfWork {
+    #[instrument(name = "SolutionRespositoryTx::create", skip_all)]
     async fn create(&mut self, s: &Solution) -> Result<Uuid, RepositoryError> {
         sqlx::query!(
             r#"INSERT INTO solutions (id, problem_id, user_id, body_md, submit_url, title)
@@ -81,6 +89,7 @@ impl SolutionRespositoryTx for SolutionUnitOfWork {
         .map_err(map_sqlx_error)?;
         Ok(s.id)
     }
+    #[instrument(name = "SolutionRespositoryTx::update", skip_all)]
     async fn update(
         &mut self,
         solution_id: Uuid,
@@ -104,8 +113,9 @@ impl SolutionRespositoryTx for SolutionUnitOfWork {
         .map_err(map_sqlx_error)?;
         Ok(())
     }
+    #[instrument(name = "SolutionRespositoryTx::delete", skip_all)]
     async fn delete(&mut self, solution_id: Uuid) -> Result<(), RepositoryError> {
-        sqlx::query!(
+        let result = sqlx::query!(
             r#"
             DELETE FROM solutions
             WHERE id = $1
@@ -115,8 +125,35 @@ impl SolutionRespositoryTx for SolutionUnitOfWork {
         .execute(self.conn())
         .await
         .map_err(map_sqlx_error)?;
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "solution not found: {solution_id}"
+            )));
+        }
+        Ok(())
+    }
+    #[instrument(name = "SolutionRespositoryTx::set_hidden", skip_all)]
+    async fn set_hidden(&mut self, solution_id: Uuid, hidden: bool) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE solutions
+            SET hidden_at = CASE WHEN $2 THEN COALESCE(hidden_at, NOW()) END
+            WHERE id = $1
+            "#,
+            solution_id,
+            hidden
+        )
+        .execute(self.conn())
+        .await
+        .map_err(map_sqlx_error)?;
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "solution not found: {solution_id}"
+            )));
+        }
         Ok(())
     }
+    #[instrument(name = "SolutionRespositoryTx::replace_tags", skip_all)]
     async fn replace_tags(
         &mut self,
         solution_id: Uuid,
@@ -148,6 +185,7 @@ impl SolutionRespositoryTx for SolutionUnitOfWork {
 
 #[async_trait]
 impl TagRepositoryTx for SolutionUnitOfWork {
+    #[instrument(name = "TagRepositoryTx::upsert", skip_all)]
     async fn upsert(&mut self, names: &[String]) -> Result<Vec<Uuid>, RepositoryError> {
         if names.is_empty() {
             return Ok(vec![]);
@@ -181,8 +219,9 @@ impl TagRepositoryTx for SolutionUnitOfWork {
 
 #[async_trait]
 impl VoteRepositoryTx for SolutionUnitOfWork {
-    async fn like(&mut self, user_id: &str, solution_id: Uuid) -> Result<(), RepositoryError> {
-        sqlx::query!(
+    #[instrument(name = "VoteRepositoryTx::like", skip_all)]
+    async fn like(&mut self, user_id: &str, solution_id: Uuid) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
             r#"
             INSERT INTO solution_votes (user_id, solution_id)
             VALUES ($1, $2)
@@ -195,9 +234,10 @@ impl VoteRepositoryTx for SolutionUnitOfWork {
         .await
         .map_err(map_sqlx_error)?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 
+    #[instrument(name = "VoteRepositoryTx::unlike", skip_all)]
     async fn unlike(&mut self, user_id: &str, solution_id: Uuid) -> Result<(), RepositoryError> {
         sqlx::query!(
             r#"
@@ -217,6 +257,7 @@ impl VoteRepositoryTx for SolutionUnitOfWork {
 
 #[async_trait]
 impl CommentRepositoryTx for SolutionUnitOfWork {
+    #[instrument(name = "CommentRepositoryTx::create_comment", skip_all)]
     async fn create_comment(
         &mut self,
         user_id: &str,
@@ -246,6 +287,7 @@ impl CommentRepositoryTx for SolutionUnitOfWork {
             updated_at: rec.updated_at,
         })
     }
+    #[instrument(name = "CommentRepositoryTx::update_comment", skip_all)]
     async fn update_comment(
         &mut self,
         comment_id: Uuid,
@@ -274,8 +316,9 @@ impl CommentRepositoryTx for SolutionUnitOfWork {
             updated_at: rec.updated_at,
         })
     }
+    #[instrument(name = "CommentRepositoryTx::delete_comment", skip_all)]
     async fn delete_comment(&mut self, comment_id: Uuid) -> Result<(), RepositoryError> {
-        sqlx::query!(
+        let result = sqlx::query!(
             r#"
             DELETE FROM comments
             WHERE id = $1
@@ -285,6 +328,64 @@ impl CommentRepositoryTx for SolutionUnitOfWork {
         .execute(self.conn())
         .await
         .map_err(map_sqlx_error)?;
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "comment not found: {comment_id}"
+            )));
+        }
+        Ok(())
+    }
+    #[instrument(name = "CommentRepositoryTx::set_comment_hidden", skip_all)]
+    async fn set_comment_hidden(
+        &mut self,
+        comment_id: Uuid,
+        hidden: bool,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE comments
+            SET hidden_at = CASE WHEN $2 THEN COALESCE(hidden_at, NOW()) END
+            WHERE id = $1
+            "#,
+            comment_id,
+            hidden
+        )
+        .execute(self.conn())
+        .await
+        .map_err(map_sqlx_error)?;
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "comment not found: {comment_id}"
+            )));
+        }
         Ok(())
     }
 }
+
+#[async_trait]
+impl ExplanationRequestRepositoryTx for SolutionUnitOfWork {
+    #[instrument(
+        name = "ExplanationRequestRepositoryTx::fulfill_explanation_requests",
+        skip_all
+    )]
+    async fn fulfill_explanation_requests(
+        &mut self,
+        problem_id: &str,
+        solution_id: Uuid,
+    ) -> Result<u64, RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE explanation_requests
+            SET fulfilled_solution_id = $2, fulfilled_at = NOW()
+            WHERE problem_id = $1
+              AND fulfilled_at IS NULL
+            "#,
+            problem_id,
+            solution_id
+        )
+        .execute(self.conn())
+        .await
+        .map_err(map_sqlx_error)?;
+        Ok(result.rows_affected())
+    }
+}
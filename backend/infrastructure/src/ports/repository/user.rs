@@ -1,9 +1,12 @@
 use async_trait::async_trait;
 use derive_new::new;
 use domain::{
-    error::repository::RepositoryError, model::user::User, ports::repository::user::UserRepository,
+    error::repository::RepositoryError,
+    model::user::{Role, User},
+    ports::repository::user::UserRepository,
 };
 use sqlx::Row;
+use tracing::instrument;
 
 use crate::error::map_sqlx_error;
 use crate::{database::ConnectionPool, model::user::UserRow};
@@ -15,6 +18,7 @@ pub struct UserRepositoryImpl {
 
 #[async_trait]
 impl UserRepository for UserRepositoryImpl {
+    #[instrument(name = "UserRepository::create_user", skip_all)]
     async fn create_user(&self, user: User) -> Result<(), RepositoryError> {
         let inserted = sqlx::query_scalar!(
             r#"
@@ -39,6 +43,7 @@ impl UserRepository for UserRepositoryImpl {
         }
         Ok(())
     }
+    #[instrument(name = "UserRepository::find_by_uid", skip_all)]
     async fn find_by_uid(&self, uid: &str) -> Result<User, RepositoryError> {
         let user_row = sqlx::query_as!(
             UserRow,
@@ -56,6 +61,31 @@ impl UserRepository for UserRepositoryImpl {
         Ok(user_row.try_into()?)
     }
 
+    #[instrument(name = "UserRepository::update_role", skip_all)]
+    async fn update_role(&self, uid: &str, role: Role) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE users
+            SET role = $2
+            WHERE id = $1
+            "#,
+            uid,
+            role.to_string(),
+        )
+        .execute(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "user not found: {}",
+                uid
+            )));
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "UserRepository::delete_by_uid", skip_all)]
     async fn delete_by_uid(&self, uid: &str) -> Result<(), RepositoryError> {
         let result = sqlx::query!(
             r#"
@@ -77,6 +107,7 @@ impl UserRepository for UserRepositoryImpl {
         Ok(())
     }
 
+    #[instrument(name = "UserRepository::revoke_tokens_by_uid", skip_all)]
     async fn revoke_tokens_by_uid(&self, uid: &str) -> Result<(), RepositoryError> {
         let result = sqlx::query(
             r#"
@@ -99,6 +130,7 @@ impl UserRepository for UserRepositoryImpl {
         Ok(())
     }
 
+    #[instrument(name = "UserRepository::is_token_revoked", skip_all)]
     async fn is_token_revoked(&self, uid: &str, issued_at: i64) -> Result<bool, RepositoryError> {
         let revoked = sqlx::query(
             r#"
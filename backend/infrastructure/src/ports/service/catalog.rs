@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::error::repository::RepositoryError;
+use tracing::instrument;
+use usecase::{model::catalog::CatalogStats, service::catalog::CatalogService};
+
+use crate::database::ConnectionPool;
+use crate::error::map_sqlx_error;
+
+#[derive(new)]
+pub struct CatalogServiceImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait]
+impl CatalogService for CatalogServiceImpl {
+    #[instrument(name = "CatalogService::get_catalog_stats", skip_all)]
+    async fn get_catalog_stats(&self) -> Result<CatalogStats, RepositoryError> {
+        let rec = sqlx::query!(
+            r#"
+                SELECT
+                    (SELECT COUNT(*) FROM contests) AS "contests!",
+                    (SELECT COUNT(*) FROM problems) AS "problems!",
+                    (SELECT COUNT(*) FROM problems WHERE difficulty IS NULL) AS "problems_without_difficulty!",
+                    (SELECT COUNT(*) FROM users) AS "users!",
+                    (SELECT COUNT(*) FROM users WHERE role = 'admin') AS "admins!",
+                    (SELECT COUNT(*) FROM solutions) AS "solutions!",
+                    (SELECT COUNT(*) FROM solutions WHERE hidden_at IS NOT NULL) AS "hidden_solutions!",
+                    (SELECT COUNT(*) FROM comments) AS "comments!",
+                    (SELECT COUNT(*) FROM comments WHERE hidden_at IS NOT NULL) AS "hidden_comments!",
+                    (SELECT COUNT(*) FROM solution_votes) AS "votes!"
+            "#
+        )
+        .fetch_one(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(CatalogStats {
+            contests: rec.contests,
+            problems: rec.problems,
+            problems_without_difficulty: rec.problems_without_difficulty,
+            users: rec.users,
+            admins: rec.admins,
+            solutions: rec.solutions,
+            hidden_solutions: rec.hidden_solutions,
+            comments: rec.comments,
+            hidden_comments: rec.hidden_comments,
+            votes: rec.votes,
+        })
+    }
+}
@@ -1,7 +1,10 @@
+use std::cmp::Reverse;
+
 use async_trait::async_trait;
 use derive_new::new;
 use domain::error::repository::RepositoryError;
-use domain::model::problem::ContestSeries;
+use domain::model::problem::{ContestSeries, ContestSortKey};
+use tracing::instrument;
 use usecase::{model::contests::ContestListItem, service::contest::ContestService};
 
 use crate::error::map_sqlx_error;
@@ -14,6 +17,7 @@ pub struct ContestServiceImpl {
 
 #[async_trait]
 impl ContestService for ContestServiceImpl {
+    #[instrument(name = "ContestService::get_contents_by_series", skip_all)]
     async fn get_contents_by_series(
         &self,
         series: ContestSeries,
@@ -21,21 +25,33 @@ impl ContestService for ContestServiceImpl {
         let contests = sqlx::query_as!(
             ContestListItemViewRaw,
             r#"
-                SELECT c.id, c.code, c.series_code, c.created_at, c.updated_at
+                SELECT
+                    c.id, c.code, c.series_code,
+                    c.title, c.start_at, c.duration_second, c.rate_change,
+                    c.created_at, c.updated_at
                 FROM contests c
                 WHERE c.series_code = $1
                   AND EXISTS (
                     SELECT 1
-                    FROM problems p
-                    WHERE p.contest_code = c.code
+                    FROM problem_appearances a
+                    WHERE a.contest_code = c.code
                   )
-                ORDER BY c.code DESC
             "#,
             series.to_string()
         )
         .fetch_all(self.db.inner_ref())
         .await
         .map_err(map_sqlx_error)?;
-        Ok(contests.into_iter().map(ContestListItem::from).collect())
+        let mut contests = contests
+            .into_iter()
+            .map(ContestListItem::from)
+            .collect::<Vec<_>>();
+        contests.sort_by_cached_key(|contest| {
+            Reverse(ContestSortKey {
+                start_at: contest.start_at,
+                code: contest.code.clone(),
+            })
+        });
+        Ok(contests)
     }
 }
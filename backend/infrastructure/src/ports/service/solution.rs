@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use derive_new::new;
-use domain::error::repository::RepositoryError;
+use domain::{error::repository::RepositoryError, model::problem::DifficultyRange};
+use tracing::instrument;
 use usecase::{
     model::solution::{
-        SolutionComment, SolutionDetails, SolutionListItem, SolutionListSort, UserSolutionListItem,
+        ProblemSolutionStats, SolutionComment, SolutionDetails, SolutionListItem, SolutionListSort,
+        TagCount, UserSolutionListItem,
     },
     service::solution::SolutionService,
 };
@@ -24,9 +26,11 @@ pub struct SolutionServiceImpl {
 
 #[async_trait]
 impl SolutionService for SolutionServiceImpl {
+    #[instrument(name = "SolutionService::get_latest_solutions", skip_all)]
     async fn get_latest_solutions(
         &self,
         limit: Option<i32>,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<SolutionListItem>, RepositoryError> {
         let solutions = sqlx::query_as!(
             SolutionListItemViewRaw,
@@ -36,12 +40,18 @@ impl SolutionService for SolutionServiceImpl {
                        s.created_at, s.updated_at
                 FROM solutions s
                 JOIN users u on s.user_id = u.id
+                JOIN problems p ON s.problem_id = p.id
                 LEFT JOIN solution_votes sv ON sv.solution_id = s.id
+                WHERE s.hidden_at IS NULL
+                  AND ($2::INT IS NULL OR clip_difficulty(p.difficulty) >= $2)
+                  AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) <= $3)
                 GROUP BY s.id, s.title, s.problem_id, s.user_id, u.user_name, s.created_at, s.updated_at
                 ORDER BY s.created_at DESC
                 LIMIT COALESCE($1, 2147483647)
             "#,
-            limit
+            limit,
+            difficulty.min,
+            difficulty.max,
         )
         .fetch_all(self.db.inner_ref())
         .await
@@ -50,6 +60,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(solutions.into_iter().map(SolutionListItem::from).collect())
     }
 
+    #[instrument(name = "SolutionService::get_solutions_by_problem_id", skip_all)]
     async fn get_solutions_by_problem_id(
         &self,
         problem_id: String,
@@ -68,7 +79,7 @@ impl SolutionService for SolutionServiceImpl {
                         FROM solutions s
                         JOIN users u on s.user_id = u.id
                         LEFT JOIN solution_votes sv ON sv.solution_id = s.id
-                        WHERE s.problem_id = $1
+                        WHERE s.problem_id = $1 AND s.hidden_at IS NULL
                         GROUP BY s.id, s.title, s.problem_id, s.user_id, u.user_name, s.created_at, s.updated_at
                         ORDER BY s.created_at DESC
                         LIMIT COALESCE($2, 2147483647)
@@ -90,7 +101,7 @@ impl SolutionService for SolutionServiceImpl {
                         FROM solutions s
                         JOIN users u on s.user_id = u.id
                         LEFT JOIN solution_votes sv ON sv.solution_id = s.id
-                        WHERE s.problem_id = $1
+                        WHERE s.problem_id = $1 AND s.hidden_at IS NULL
                         GROUP BY s.id, s.title, s.problem_id, s.user_id, u.user_name, s.created_at, s.updated_at
                         ORDER BY "votes_count!" DESC, s.created_at DESC
                         LIMIT COALESCE($2, 2147483647)
@@ -106,6 +117,7 @@ impl SolutionService for SolutionServiceImpl {
 
         Ok(solutions.into_iter().map(SolutionListItem::from).collect())
     }
+    #[instrument(name = "SolutionService::get_solution_by_solution_id", skip_all)]
     async fn get_solution_by_solution_id(
         &self,
         solution_id: Uuid,
@@ -134,7 +146,7 @@ impl SolutionService for SolutionServiceImpl {
                 JOIN problems p on s.problem_id = p.id
                 LEFT JOIN solution_tags st ON st.solution_id = s.id
                 LEFT JOIN tags t ON t.id = st.tag_id
-                WHERE s.id = $1
+                WHERE s.id = $1 AND s.hidden_at IS NULL
                 GROUP BY
                     s.id,
                     s.title,
@@ -157,10 +169,12 @@ impl SolutionService for SolutionServiceImpl {
         Ok(solution)
     }
 
+    #[instrument(name = "SolutionService::get_solutions_by_user_name", skip_all)]
     async fn get_solutions_by_user_name(
         &self,
         user_name: String,
         sort: SolutionListSort,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<UserSolutionListItem>, RepositoryError> {
         let user_name_ref = user_name.as_str();
         let rows = match sort {
@@ -175,11 +189,15 @@ impl SolutionService for SolutionServiceImpl {
                         JOIN users u ON s.user_id = u.id
                         JOIN problems p ON s.problem_id = p.id
                         LEFT JOIN solution_votes sv ON sv.solution_id = s.id
-                        WHERE u.user_name = $1
+                        WHERE u.user_name = $1 AND s.hidden_at IS NULL
+                          AND ($2::INT IS NULL OR clip_difficulty(p.difficulty) >= $2)
+                          AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) <= $3)
                         GROUP BY s.id, s.title, s.problem_id, p.title, s.user_id, u.user_name, s.created_at, s.updated_at
                         ORDER BY s.created_at DESC
                     "#,
-                    user_name_ref
+                    user_name_ref,
+                    difficulty.min,
+                    difficulty.max,
                 )
                 .fetch_all(self.db.inner_ref())
                 .await
@@ -196,11 +214,15 @@ impl SolutionService for SolutionServiceImpl {
                         JOIN users u ON s.user_id = u.id
                         JOIN problems p ON s.problem_id = p.id
                         LEFT JOIN solution_votes sv ON sv.solution_id = s.id
-                        WHERE u.user_name = $1
+                        WHERE u.user_name = $1 AND s.hidden_at IS NULL
+                          AND ($2::INT IS NULL OR clip_difficulty(p.difficulty) >= $2)
+                          AND ($3::INT IS NULL OR clip_difficulty(p.difficulty) <= $3)
                         GROUP BY s.id, s.title, s.problem_id, p.title, s.user_id, u.user_name, s.created_at, s.updated_at
                         ORDER BY "votes_count!" DESC, s.created_at DESC
                     "#,
-                    user_name_ref
+                    user_name_ref,
+                    difficulty.min,
+                    difficulty.max,
                 )
                 .fetch_all(self.db.inner_ref())
                 .await
@@ -211,6 +233,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rows.into_iter().map(UserSolutionListItem::from).collect())
     }
 
+    #[instrument(name = "SolutionService::user_name_exists", skip_all)]
     async fn user_name_exists(&self, user_name: &str) -> Result<bool, RepositoryError> {
         let rec = sqlx::query!(
             r#"
@@ -229,6 +252,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.exists)
     }
 
+    #[instrument(name = "SolutionService::get_solution_votes_count", skip_all)]
     async fn get_solution_votes_count(&self, solution_id: Uuid) -> Result<i64, RepositoryError> {
         let rec = sqlx::query!(
             r#"
@@ -244,6 +268,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.count)
     }
 
+    #[instrument(name = "SolutionService::has_user_voted_solution", skip_all)]
     async fn has_user_voted_solution(
         &self,
         user_id: String,
@@ -267,13 +292,14 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.exists)
     }
 
+    #[instrument(name = "SolutionService::solution_exists", skip_all)]
     async fn solution_exists(&self, solution_id: Uuid) -> Result<bool, RepositoryError> {
         let rec = sqlx::query!(
             r#"
                 SELECT EXISTS(
                     SELECT 1
                     FROM solutions
-                    WHERE id = $1
+                    WHERE id = $1 AND hidden_at IS NULL
                 ) AS "exists!"
             "#,
             solution_id
@@ -285,6 +311,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.exists)
     }
 
+    #[instrument(name = "SolutionService::get_comments_by_solution_id", skip_all)]
     async fn get_comments_by_solution_id(
         &self,
         solution_id: Uuid,
@@ -295,7 +322,7 @@ impl SolutionService for SolutionServiceImpl {
                 SELECT c.id, c.user_id, u.user_name, c.solution_id, c.body_md, c.created_at, c.updated_at
                 FROM comments c
                 JOIN users u ON c.user_id = u.id
-                WHERE c.solution_id = $1
+                WHERE c.solution_id = $1 AND c.hidden_at IS NULL
                 ORDER BY c.created_at ASC
             "#,
             solution_id
@@ -307,6 +334,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(comments.into_iter().map(SolutionComment::from).collect())
     }
 
+    #[instrument(name = "SolutionService::get_solution_user_id", skip_all)]
     async fn get_solution_user_id(&self, solution_id: Uuid) -> Result<String, RepositoryError> {
         let rec = sqlx::query!(
             r#"
@@ -323,13 +351,14 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.user_id)
     }
 
+    #[instrument(name = "SolutionService::comment_exists", skip_all)]
     async fn comment_exists(&self, comment_id: Uuid) -> Result<bool, RepositoryError> {
         let rec = sqlx::query!(
             r#"
                 SELECT EXISTS(
                     SELECT 1
                     FROM comments
-                    WHERE id = $1
+                    WHERE id = $1 AND hidden_at IS NULL
                 ) AS "exists!"
             "#,
             comment_id
@@ -341,6 +370,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.exists)
     }
 
+    #[instrument(name = "SolutionService::get_comment_user_id", skip_all)]
     async fn get_comment_user_id(&self, comment_id: Uuid) -> Result<String, RepositoryError> {
         let rec = sqlx::query!(
             r#"
@@ -357,6 +387,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.user_id)
     }
 
+    #[instrument(name = "SolutionService::get_user_name_by_id", skip_all)]
     async fn get_user_name_by_id(&self, user_id: &str) -> Result<String, RepositoryError> {
         let rec = sqlx::query!(
             r#"
@@ -373,6 +404,7 @@ impl SolutionService for SolutionServiceImpl {
         Ok(rec.user_name)
     }
 
+    #[instrument(name = "SolutionService::problem_exists", skip_all)]
     async fn problem_exists(&self, problem_id: &str) -> Result<bool, RepositoryError> {
         let rec = sqlx::query!(
             r#"
@@ -390,4 +422,75 @@ impl SolutionService for SolutionServiceImpl {
 
         Ok(rec.exists)
     }
+
+    #[instrument(name = "SolutionService::get_solution_stats_by_problem_ids", skip_all)]
+    async fn get_solution_stats_by_problem_ids(
+        &self,
+        problem_ids: &[String],
+    ) -> Result<Vec<ProblemSolutionStats>, RepositoryError> {
+        if problem_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let stats = sqlx::query_as!(
+            ProblemSolutionStats,
+            r#"
+                SELECT
+                    s.problem_id,
+                    COUNT(*) AS "solution_count!",
+                    (ARRAY_AGG(s.id ORDER BY v.votes_count DESC, s.created_at DESC))[1]
+                        AS top_voted_solution_id,
+                    SUM(v.votes_count)::BIGINT AS "total_votes!",
+                    MAX(GREATEST(s.updated_at, v.last_voted_at, c.last_commented_at))
+                        AS latest_activity_at
+                FROM solutions s
+                CROSS JOIN LATERAL (
+                    SELECT COUNT(*) AS votes_count, MAX(sv.created_at) AS last_voted_at
+                    FROM solution_votes sv
+                    WHERE sv.solution_id = s.id
+                ) v
+                CROSS JOIN LATERAL (
+                    SELECT MAX(c.updated_at) AS last_commented_at
+                    FROM comments c
+                    WHERE c.solution_id = s.id AND c.hidden_at IS NULL
+                ) c
+                WHERE s.problem_id = ANY($1) AND s.hidden_at IS NULL
+                GROUP BY s.problem_id
+            "#,
+            problem_ids
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(stats)
+    }
+
+    #[instrument(name = "SolutionService::get_top_tags_by_problem_id", skip_all)]
+    async fn get_top_tags_by_problem_id(
+        &self,
+        problem_id: &str,
+        limit: i64,
+    ) -> Result<Vec<TagCount>, RepositoryError> {
+        let tags = sqlx::query_as!(
+            TagCount,
+            r#"
+                SELECT t.name, COUNT(*) AS "solution_count!"
+                FROM solutions s
+                JOIN solution_tags st ON st.solution_id = s.id
+                JOIN tags t ON t.id = st.tag_id
+                WHERE s.problem_id = $1 AND s.hidden_at IS NULL
+                GROUP BY t.name
+                ORDER BY COUNT(*) DESC, t.name
+                LIMIT $2
+            "#,
+            problem_id,
+            limit
+        )
+        .fetch_all(self.db.inner_ref())
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(tags)
+    }
 }
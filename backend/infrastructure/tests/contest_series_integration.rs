@@ -0,0 +1,88 @@
+use anyhow::Result;
+use domain::{
+    model::problem::{
+        ContestReclassification, ContestSeries, ContestSeriesCatalog, ContestSeriesDefinition,
+        Problem,
+    },
+    ports::repository::{contest_series::ContestSeriesRepository, problem::ProblemRepository},
+};
+use infrastructure::{
+    database::ConnectionPool,
+    ports::repository::{
+        contest_series::ContestSeriesRepositoryImpl, problem::ProblemRepositoryImpl,
+    },
+};
+use sqlx::PgPool;
+
+// 取り込みと同じく、マイグレーションで入るシリーズで分類する
+async fn series_catalog(pool: &PgPool) -> Result<ContestSeriesCatalog> {
+    let repo = ContestSeriesRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    Ok(ContestSeriesCatalog::new(repo.list().await?)?)
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn migrated_definitions_classify_like_the_former_prefixes(pool: PgPool) -> Result<()> {
+    let repo = ContestSeriesRepositoryImpl::new(ConnectionPool::new(pool));
+    let catalog = ContestSeriesCatalog::new(repo.list().await?)?;
+
+    assert_eq!(catalog.definitions().len(), 6);
+    assert_eq!(catalog.classify("abc400"), ContestSeries::ABC);
+    assert_eq!(catalog.classify("awc0001"), ContestSeries::AWC);
+    assert_eq!(catalog.classify("typical90"), ContestSeries::OTHER);
+    assert_eq!(catalog.parse("other")?, ContestSeries::OTHER);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn create_and_reclassify_moves_contests_to_the_new_series(pool: PgPool) -> Result<()> {
+    let conn = ConnectionPool::new(pool.clone());
+    ProblemRepositoryImpl::new(conn.clone())
+        .create_records(
+            vec![Problem {
+                id: "past202412_a".into(),
+                contest_code: "past202412".into(),
+                problem_index: "A".into(),
+                title: "A - Example".into(),
+                difficulty: None,
+            }],
+            &series_catalog(&pool).await?,
+        )
+        .await?;
+    let repo = ContestSeriesRepositoryImpl::new(conn);
+    assert_eq!(
+        repo.list_contest_assignments().await?,
+        vec![("past202412".to_string(), ContestSeries::OTHER)]
+    );
+
+    let past = ContestSeriesDefinition {
+        code: ContestSeries::new("PAST"),
+        display_name: "PAST".into(),
+        code_pattern: Some(r"^past\d+".into()),
+        sort_order: 60,
+    };
+    repo.create_and_reclassify(
+        &past,
+        &[ContestReclassification {
+            contest_code: "past202412".into(),
+            from: ContestSeries::OTHER,
+            to: past.code.clone(),
+        }],
+    )
+    .await?;
+
+    assert!(repo.list().await?.contains(&past));
+    assert_eq!(
+        repo.list_contest_assignments().await?,
+        vec![("past202412".to_string(), past.code.clone())]
+    );
+
+    let err = repo
+        .create_and_reclassify(&past, &[])
+        .await
+        .expect_err("duplicate series should fail");
+    assert!(matches!(
+        err,
+        domain::error::repository::RepositoryError::UniqueViolation(_)
+    ));
+    Ok(())
+}
@@ -0,0 +1,333 @@
+use anyhow::Result;
+use domain::{
+    model::{
+        explanation_request::WantedProblemFilter,
+        problem::{
+            ContestSeries, ContestSeriesCatalog, DifficultyBand, Problem, ProblemAppearance,
+        },
+        solution::Solution,
+        user::{Role, User},
+    },
+    ports::repository::{
+        contest_series::ContestSeriesRepository,
+        explanation_request::ExplanationRequestRepository,
+        problem::{ProblemRepository, tx::ProblemTxManager},
+        solution::tx::SolutionTxManager,
+        user::UserRepository,
+    },
+};
+use infrastructure::{
+    database::ConnectionPool,
+    ports::repository::{
+        contest_series::ContestSeriesRepositoryImpl,
+        explanation_request::ExplanationRequestRepositoryImpl,
+        problem::{ProblemRepositoryImpl, tx::ProblemTransactionManager},
+        solution::tx::SolutionTransactionManager,
+        user::UserRepositoryImpl,
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+// 取り込みと同じく、マイグレーションで入るシリーズで分類する
+async fn series_catalog(pool: &PgPool) -> Result<ContestSeriesCatalog> {
+    let repo = ContestSeriesRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    Ok(ContestSeriesCatalog::new(repo.list().await?)?)
+}
+
+async fn seed(pool: &PgPool) -> Result<ConnectionPool> {
+    for role in ["admin", "user"] {
+        sqlx::query!(
+            r#"
+            INSERT INTO roles (name)
+            VALUES ($1) ON CONFLICT DO NOTHING
+            "#,
+            role
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    let conn = ConnectionPool::new(pool.clone());
+    let problem = |id: &str, contest_code: &str, difficulty: Option<i32>| Problem {
+        id: id.into(),
+        contest_code: contest_code.into(),
+        problem_index: id.rsplit('_').next().unwrap().into(),
+        title: format!("{id} title"),
+        difficulty,
+    };
+    ProblemRepositoryImpl::new(conn.clone())
+        .create_records(
+            vec![
+                problem("arc100_a", "arc100", Some(900)),
+                problem("arc100_b", "arc100", Some(1500)),
+                problem("arc100_c", "arc100", None),
+                problem("abc300_a", "abc300", Some(100)),
+            ],
+            &series_catalog(pool).await?,
+        )
+        .await?;
+
+    let user_repo = UserRepositoryImpl::new(conn.clone());
+    for id in ["alice", "bob", "carol"] {
+        user_repo
+            .create_user(User {
+                id: id.to_string(),
+                user_name: id.to_string(),
+                role: Role::default(),
+            })
+            .await?;
+    }
+    Ok(conn)
+}
+
+fn filter(band: Option<DifficultyBand>) -> WantedProblemFilter {
+    WantedProblemFilter {
+        series: ContestSeries::ARC,
+        band,
+        limit: 50,
+        offset: 0,
+    }
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn wanted_problems_are_ranked_by_requests_per_series_and_band(pool: PgPool) -> Result<()> {
+    let conn = seed(&pool).await?;
+    let repo = ExplanationRequestRepositoryImpl::new(conn);
+
+    for (user_id, problem_id) in [
+        ("alice", "arc100_a"),
+        ("alice", "arc100_b"),
+        ("bob", "arc100_b"),
+        ("carol", "arc100_c"),
+        ("alice", "abc300_a"),
+    ] {
+        assert!(repo.create(user_id, problem_id).await?);
+    }
+    // 同じ人の2件目は数えない
+    assert!(!repo.create("bob", "arc100_b").await?);
+
+    let wanted = repo.list_wanted(&filter(None)).await?;
+    let mut ranked = wanted
+        .iter()
+        .map(|w| (w.problem.id.as_str(), w.request_count))
+        .collect::<Vec<_>>();
+    // 同数の順は最終リクエスト時刻によるため、先頭だけ確かめる
+    ranked[1..].sort();
+    assert_eq!(
+        ranked,
+        vec![("arc100_b", 2), ("arc100_a", 1), ("arc100_c", 1)]
+    );
+
+    let green = repo
+        .list_wanted(&filter(Some(DifficultyBand::Green)))
+        .await?;
+    assert_eq!(
+        green
+            .iter()
+            .map(|w| w.problem.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["arc100_a"]
+    );
+    // difficultyのない問題は色帯で絞ると出ない
+    let gray = repo
+        .list_wanted(&filter(Some(DifficultyBand::Gray)))
+        .await?;
+    assert!(gray.is_empty());
+
+    repo.delete("bob", "arc100_b").await?;
+    let wanted = repo.list_wanted(&filter(None)).await?;
+    assert_eq!(wanted[0].request_count, 1);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn first_solution_fulfills_requests_and_leaves_the_board(pool: PgPool) -> Result<()> {
+    let conn = seed(&pool).await?;
+    let repo = ExplanationRequestRepositoryImpl::new(conn.clone());
+    repo.create("alice", "arc100_a").await?;
+    repo.create("bob", "arc100_a").await?;
+    repo.create("alice", "arc100_b").await?;
+
+    let tx_mng = SolutionTransactionManager::new(conn.clone());
+    let mut solution_ids = vec![];
+    for title in ["first", "second"] {
+        let mut uow = tx_mng.begin().await?;
+        let solution_id = uow
+            .solutions()
+            .create(&Solution {
+                id: Uuid::now_v7(),
+                title: title.to_string(),
+                problem_id: "arc100_a".to_string(),
+                user_id: "carol".to_string(),
+                body_md: "body".to_string(),
+                submit_url: String::new(),
+            })
+            .await?;
+        let fulfilled = uow
+            .explanation_requests()
+            .fulfill_explanation_requests("arc100_a", solution_id)
+            .await?;
+        uow.commit().await?;
+        solution_ids.push((solution_id, fulfilled));
+    }
+    assert_eq!(solution_ids[0].1, 2);
+    assert_eq!(solution_ids[1].1, 0);
+
+    let mine = repo.list_by_user("alice").await?;
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine[0].problem_id, "arc100_a");
+    assert_eq!(mine[0].fulfilled_solution_id, Some(solution_ids[0].0));
+    assert!(mine[0].fulfilled_at.is_some());
+    assert_eq!(mine[1].problem_id, "arc100_b");
+    assert!(mine[1].fulfilled_at.is_none());
+
+    let wanted = repo.list_wanted(&filter(None)).await?;
+    assert_eq!(
+        wanted
+            .iter()
+            .map(|w| w.problem.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["arc100_b"]
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn re_request_after_the_solution_is_hidden_reopens_the_request(pool: PgPool) -> Result<()> {
+    let conn = seed(&pool).await?;
+    let repo = ExplanationRequestRepositoryImpl::new(conn.clone());
+    repo.create("alice", "arc100_a").await?;
+    repo.create("bob", "arc100_a").await?;
+
+    let tx_mng = SolutionTransactionManager::new(conn.clone());
+    let create_solution = |title: &'static str| {
+        let tx_mng = &tx_mng;
+        async move {
+            let mut uow = tx_mng.begin().await?;
+            let solution_id = uow
+                .solutions()
+                .create(&Solution {
+                    id: Uuid::now_v7(),
+                    title: title.to_string(),
+                    problem_id: "arc100_a".to_string(),
+                    user_id: "carol".to_string(),
+                    body_md: "body".to_string(),
+                    submit_url: String::new(),
+                })
+                .await?;
+            let fulfilled = uow
+                .explanation_requests()
+                .fulfill_explanation_requests("arc100_a", solution_id)
+                .await?;
+            uow.commit().await?;
+            anyhow::Ok((solution_id, fulfilled))
+        }
+    };
+    let (first_id, fulfilled) = create_solution("first").await?;
+    assert_eq!(fulfilled, 2);
+
+    let mut uow = tx_mng.begin().await?;
+    uow.solutions().set_hidden(first_id, true).await?;
+    uow.commit().await?;
+
+    // 達成済みのリクエストは解説が非表示になっても数えない
+    assert!(repo.list_wanted(&filter(None)).await?.is_empty());
+
+    assert!(repo.create("alice", "arc100_a").await?);
+    let wanted = repo.list_wanted(&filter(None)).await?;
+    assert_eq!(wanted.len(), 1);
+    assert_eq!(wanted[0].problem.id, "arc100_a");
+    assert_eq!(wanted[0].request_count, 1);
+    assert!(repo.list_by_user("alice").await?[0].fulfilled_at.is_none());
+    // 未達成のリクエストをし直しても何も変わらない
+    assert!(!repo.create("alice", "arc100_a").await?);
+
+    // し直した人だけが次の解説で達成される
+    let (second_id, fulfilled) = create_solution("second").await?;
+    assert_eq!(fulfilled, 1);
+    assert_eq!(
+        repo.list_by_user("alice").await?[0].fulfilled_solution_id,
+        Some(second_id)
+    );
+    assert_eq!(
+        repo.list_by_user("bob").await?[0].fulfilled_solution_id,
+        Some(first_id)
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn problems_are_wanted_in_every_series_they_appear_in(pool: PgPool) -> Result<()> {
+    let conn = seed(&pool).await?;
+    let repo = ExplanationRequestRepositoryImpl::new(conn.clone());
+    // ABC/ARCの同時開催で、arc100_aがABCの2つのコンテストにも出題された
+    let txm = ProblemTransactionManager::new(conn.clone());
+    let mut uow = txm.begin().await?;
+    uow.problems()
+        .upsert_contests_bulk(&[("abc299".into(), "ABC".into())])
+        .await?;
+    uow.problems()
+        .upsert_problem_appearances_bulk(&[
+            ProblemAppearance {
+                contest_code: "abc299".into(),
+                problem_index: "G".into(),
+                problem_id: "arc100_a".into(),
+            },
+            ProblemAppearance {
+                contest_code: "abc300".into(),
+                problem_index: "F".into(),
+                problem_id: "arc100_a".into(),
+            },
+        ])
+        .await?;
+    uow.commit().await?;
+
+    repo.create("alice", "arc100_a").await?;
+    repo.create("bob", "arc100_a").await?;
+    repo.create("alice", "abc300_a").await?;
+
+    let abc = |band| WantedProblemFilter {
+        series: ContestSeries::ABC,
+        ..filter(band)
+    };
+    let wanted = repo.list_wanted(&abc(None)).await?;
+    let listed = wanted
+        .iter()
+        .map(|w| {
+            (
+                w.problem.id.as_str(),
+                w.problem.contest_code.as_str(),
+                w.problem.problem_index.as_str(),
+                w.request_count,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        listed,
+        vec![
+            ("arc100_a", "abc299", "G", 2),
+            ("abc300_a", "abc300", "a", 1)
+        ]
+    );
+
+    let arc = repo.list_wanted(&filter(None)).await?;
+    assert_eq!(arc[0].problem.contest_code, "arc100");
+    assert_eq!(arc[0].request_count, 2);
+
+    // difficulty 100は補正後の189でgrayに入る
+    let gray = repo.list_wanted(&abc(Some(DifficultyBand::Gray))).await?;
+    assert_eq!(
+        gray.iter()
+            .map(|w| w.problem.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["abc300_a"]
+    );
+    let green = repo.list_wanted(&abc(Some(DifficultyBand::Green))).await?;
+    assert_eq!(green[0].problem.id, "arc100_a");
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use domain::ports::external::auth::{AuthError, AuthenticatorPort};
+use infrastructure::ports::external::local_jwt::{LocalJwtAuthenticator, LocalJwtIssuer};
+use shared::config::{
+    LOCAL_JWT_DEFAULT_AUDIENCE, LOCAL_JWT_DEFAULT_ISSUER, LocalJwtConfig, LocalJwtKey,
+};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/local_jwt");
+
+fn config(key: LocalJwtKey) -> LocalJwtConfig {
+    LocalJwtConfig {
+        issuer: LOCAL_JWT_DEFAULT_ISSUER.into(),
+        audience: LOCAL_JWT_DEFAULT_AUDIENCE.into(),
+        key,
+    }
+}
+
+fn fixture(name: &str) -> String {
+    format!("{FIXTURES}/{name}")
+}
+
+#[tokio::test]
+async fn hs256_minted_token_is_verified() -> Result<()> {
+    let config = config(LocalJwtKey::Secret("secret".into()));
+    let issuer = LocalJwtIssuer::from_config(&config, None, None)?;
+    let authenticator = LocalJwtAuthenticator::from_config(&config)?;
+
+    let token = issuer.mint("uid1", Some("a@example.com"), Duration::from_secs(60))?;
+    let principal = authenticator.verify_id_token(&token).await?;
+
+    assert_eq!(principal.uid, "uid1");
+    assert_eq!(principal.email.as_deref(), Some("a@example.com"));
+    assert!(principal.issued_at > 0);
+    assert!(!principal.is_personal_token());
+    Ok(())
+}
+
+#[tokio::test]
+async fn hs256_token_with_other_secret_is_rejected() -> Result<()> {
+    let issuer =
+        LocalJwtIssuer::from_config(&config(LocalJwtKey::Secret("other".into())), None, None)?;
+    let authenticator =
+        LocalJwtAuthenticator::from_config(&config(LocalJwtKey::Secret("secret".into())))?;
+
+    let token = issuer.mint("uid1", None, Duration::from_secs(60))?;
+    let err = authenticator.verify_id_token(&token).await.err();
+
+    assert!(matches!(err, Some(AuthError::Unauthorized)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn token_for_other_audience_is_rejected() -> Result<()> {
+    let mut minting = config(LocalJwtKey::Secret("secret".into()));
+    minting.audience = "someone-else".into();
+    let issuer = LocalJwtIssuer::from_config(&minting, None, None)?;
+    let authenticator =
+        LocalJwtAuthenticator::from_config(&config(LocalJwtKey::Secret("secret".into())))?;
+
+    let token = issuer.mint("uid1", None, Duration::from_secs(60))?;
+    let err = authenticator.verify_id_token(&token).await.err();
+
+    assert!(matches!(err, Some(AuthError::Unauthorized)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn rs256_token_is_verified_with_public_key_pem() -> Result<()> {
+    let config = config(LocalJwtKey::RsaPublicKeyPemPath(fixture("public.pem")));
+    let issuer = LocalJwtIssuer::from_config(&config, Some(&fixture("private.pem")), None)?;
+    let authenticator = LocalJwtAuthenticator::from_config(&config)?;
+
+    let token = issuer.mint("uid1", None, Duration::from_secs(60))?;
+    let principal = authenticator.verify_id_token(&token).await?;
+
+    assert_eq!(principal.uid, "uid1");
+    assert!(principal.email.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn rs256_token_is_verified_with_jwks_file() -> Result<()> {
+    let config = config(LocalJwtKey::JwksPath(fixture("jwks.json")));
+    let issuer = LocalJwtIssuer::from_config(
+        &config,
+        Some(&fixture("private.pem")),
+        Some("local-test".into()),
+    )?;
+    let authenticator = LocalJwtAuthenticator::from_config(&config)?;
+
+    let token = issuer.mint("uid1", None, Duration::from_secs(60))?;
+    assert_eq!(authenticator.verify_id_token(&token).await?.uid, "uid1");
+
+    let unknown_kid = LocalJwtIssuer::from_config(
+        &config,
+        Some(&fixture("private.pem")),
+        Some("unknown".into()),
+    )?
+    .mint("uid1", None, Duration::from_secs(60))?;
+    let err = authenticator.verify_id_token(&unknown_kid).await.err();
+    assert!(matches!(err, Some(AuthError::Unauthorized)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn hs256_token_is_rejected_by_rs256_authenticator() -> Result<()> {
+    let issuer =
+        LocalJwtIssuer::from_config(&config(LocalJwtKey::Secret("secret".into())), None, None)?;
+    let authenticator = LocalJwtAuthenticator::from_config(&config(
+        LocalJwtKey::RsaPublicKeyPemPath(fixture("public.pem")),
+    ))?;
+
+    let token = issuer.mint("uid1", None, Duration::from_secs(60))?;
+    let err = authenticator.verify_id_token(&token).await.err();
+
+    assert!(matches!(err, Some(AuthError::Unauthorized)));
+    Ok(())
+}
+
+#[test]
+fn missing_key_file_fails_to_build() {
+    let res =
+        LocalJwtAuthenticator::from_config(&config(LocalJwtKey::JwksPath(fixture("missing.json"))));
+    assert!(res.is_err());
+}
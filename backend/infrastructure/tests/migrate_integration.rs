@@ -0,0 +1,87 @@
+use anyhow::Result;
+use infrastructure::database::{
+    MIGRATOR,
+    migrate::{self, MigrationState},
+};
+use sqlx::PgPool;
+
+fn versions() -> Vec<i64> {
+    MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| m.version)
+        .collect()
+}
+
+#[sqlx::test(migrations = false)]
+async fn up_applies_pending_and_down_reverts_newer_versions(pool: PgPool) -> Result<()> {
+    let mut conn = pool.acquire().await?.detach();
+    let versions = versions();
+
+    let status = migrate::status(&mut conn).await?;
+    assert_eq!(status.len(), versions.len());
+    assert!(status.iter().all(|e| e.state == MigrationState::Pending));
+
+    let applied = migrate::up(&mut conn).await?;
+    assert_eq!(
+        applied.iter().map(|e| e.version).collect::<Vec<_>>(),
+        versions
+    );
+    assert!(migrate::pending(&mut conn).await?.is_empty());
+    // 二度目は何もしない
+    assert!(migrate::up(&mut conn).await?.is_empty());
+
+    let target = versions[1];
+    let planned = migrate::revertible(&mut conn, target).await?;
+    let mut expected = versions[2..].to_vec();
+    expected.reverse();
+    assert_eq!(
+        planned.iter().map(|e| e.version).collect::<Vec<_>>(),
+        expected
+    );
+    // dry-runでは何も変わらない
+    assert!(migrate::pending(&mut conn).await?.is_empty());
+
+    let reverted = migrate::down_to(&mut conn, target).await?;
+    assert_eq!(reverted.len(), planned.len());
+    let status = migrate::status(&mut conn).await?;
+    for entry in status {
+        let expected = if entry.version > target {
+            MigrationState::Pending
+        } else {
+            MigrationState::Applied
+        };
+        assert_eq!(entry.state, expected, "version {}", entry.version);
+    }
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn status_flags_modified_and_unknown_versions(pool: PgPool) -> Result<()> {
+    let mut conn = pool.acquire().await?.detach();
+    migrate::up(&mut conn).await?;
+    let first = versions()[0];
+    sqlx::query("UPDATE _sqlx_migrations SET checksum = '\\x00' WHERE version = $1")
+        .bind(first)
+        .execute(&pool)
+        .await?;
+    sqlx::query(
+        "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) VALUES (1, 'gone', true, '\\x00', 0)",
+    )
+    .execute(&pool)
+    .await?;
+
+    let status = migrate::status(&mut conn).await?;
+    assert_eq!(status[0].version, 1);
+    assert_eq!(status[0].state, MigrationState::Unknown);
+    assert_eq!(status[1].version, first);
+    assert_eq!(status[1].state, MigrationState::Modified);
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn down_rejects_unknown_target_version(pool: PgPool) -> Result<()> {
+    let mut conn = pool.acquire().await?.detach();
+    assert!(migrate::revertible(&mut conn, 42).await.is_err());
+    Ok(())
+}
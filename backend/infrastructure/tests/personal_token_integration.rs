@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use domain::{
+    error::repository::RepositoryError,
+    model::{
+        personal_token::{NewPersonalToken, TokenScope},
+        user::{Role, User},
+    },
+    ports::{
+        external::{
+            auth::{AuthError, AuthenticatorPort, Credential},
+            personal_token::PersonalTokenGeneratorPort,
+        },
+        repository::{personal_token::PersonalTokenRepository, user::UserRepository},
+    },
+};
+use infrastructure::{
+    database::ConnectionPool,
+    ports::{
+        external::personal_token::{PersonalTokenAuthenticator, RandomPersonalTokenGenerator},
+        repository::{personal_token::PersonalTokenRepositoryImpl, user::UserRepositoryImpl},
+    },
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn seed_user(pool: &PgPool, id: &str, name: &str) -> Result<()> {
+    let repo = UserRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    repo.create_user(User {
+        id: id.into(),
+        role: Role::default(),
+        user_name: name.into(),
+    })
+    .await?;
+    Ok(())
+}
+
+fn make_token(user_id: &str, name: &str, hash: &str) -> NewPersonalToken {
+    NewPersonalToken {
+        id: Uuid::now_v7(),
+        user_id: user_id.into(),
+        name: name.into(),
+        scopes: vec![TokenScope::Read, TokenScope::WriteComments],
+        token_hash: hash.into(),
+        token_prefix: "aspat_abcdef".into(),
+    }
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn create_list_and_revoke_personal_tokens(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    seed_user(&pool, "uid2", "bob").await?;
+    let repo = PersonalTokenRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    let first = repo.create(&make_token("uid1", "cli", "hash1")).await?;
+    assert_eq!(
+        first.scopes,
+        vec![TokenScope::Read, TokenScope::WriteComments]
+    );
+    assert!(first.last_used_at.is_none());
+    repo.create(&make_token("uid1", "ci", "hash2")).await?;
+    repo.create(&make_token("uid2", "other", "hash3")).await?;
+
+    let tokens = repo.list_by_user("uid1").await?;
+    assert_eq!(tokens.len(), 2);
+    assert!(tokens.iter().all(|t| t.user_id == "uid1"));
+
+    let err = repo
+        .revoke("uid2", first.id)
+        .await
+        .expect_err("other user's token cannot be revoked");
+    assert!(matches!(err, RepositoryError::NotFound(_)));
+
+    repo.revoke("uid1", first.id).await?;
+    let tokens = repo.list_by_user("uid1").await?;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].name, "ci");
+
+    assert!(repo.find_active_by_hash("hash1").await?.is_none());
+    let err = repo
+        .revoke("uid1", first.id)
+        .await
+        .expect_err("already revoked");
+    assert!(matches!(err, RepositoryError::NotFound(_)));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn personal_token_hash_must_be_unique(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    let repo = PersonalTokenRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    repo.create(&make_token("uid1", "cli", "same")).await?;
+    let err = repo
+        .create(&make_token("uid1", "cli2", "same"))
+        .await
+        .expect_err("hash should be unique");
+    assert!(matches!(err, RepositoryError::UniqueViolation(_)));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn personal_token_authenticator_verifies_and_records_last_used(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    let repo = Arc::new(PersonalTokenRepositoryImpl::new(ConnectionPool::new(
+        pool.clone(),
+    )));
+    let generated = RandomPersonalTokenGenerator::new().generate();
+    let created = repo
+        .create(&make_token("uid1", "cli", &generated.hash))
+        .await?;
+
+    let authenticator = PersonalTokenAuthenticator::new(repo.to_owned());
+    let principal = authenticator.verify_id_token(&generated.plain).await?;
+    assert_eq!(principal.uid, "uid1");
+    assert!(principal.email.is_none());
+    assert!(principal.has_scope(TokenScope::WriteComments));
+    assert!(!principal.has_scope(TokenScope::WriteSolutions));
+    assert!(matches!(
+        principal.credential,
+        Credential::PersonalToken { token_id, .. } if token_id == created.id
+    ));
+
+    let tokens = repo.list_by_user("uid1").await?;
+    assert!(tokens[0].last_used_at.is_some());
+
+    let err = authenticator
+        .verify_id_token("aspat_unknown")
+        .await
+        .err()
+        .expect("unknown token");
+    assert!(matches!(err, AuthError::Unauthorized));
+
+    repo.revoke("uid1", created.id).await?;
+    let err = authenticator
+        .verify_id_token(&generated.plain)
+        .await
+        .err()
+        .expect("revoked token");
+    assert!(matches!(err, AuthError::Unauthorized));
+
+    Ok(())
+}
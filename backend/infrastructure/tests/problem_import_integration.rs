@@ -0,0 +1,101 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use domain::{
+    model::problem_import::{ImportOutcome, NewProblemImportRun, ProblemImportChanges},
+    ports::repository::{
+        health::HealthCheckRepository, problem_import::ProblemImportRunRepository,
+    },
+};
+use infrastructure::{
+    database::ConnectionPool,
+    ports::repository::{
+        health::HealthCheckRepositoryImpl, problem_import::ProblemImportRunRepositoryImpl,
+    },
+};
+use sqlx::PgPool;
+
+#[sqlx::test(migrations = "./migrations")]
+async fn latest_returns_most_recently_finished_run(pool: PgPool) -> Result<()> {
+    let repo = ProblemImportRunRepositoryImpl::new(ConnectionPool::new(pool));
+    assert!(repo.latest().await?.is_none());
+
+    let now = Utc::now();
+    repo.record(&NewProblemImportRun {
+        started_at: now - Duration::hours(25),
+        finished_at: now - Duration::hours(24),
+        outcome: ImportOutcome::Success,
+        problem_count: Some(100),
+        changes: None,
+        error_message: None,
+    })
+    .await?;
+    let recorded = repo
+        .record(&NewProblemImportRun {
+            started_at: now - Duration::minutes(2),
+            finished_at: now - Duration::minutes(1),
+            outcome: ImportOutcome::Failure,
+            problem_count: None,
+            changes: None,
+            error_message: Some("external unavailable".into()),
+        })
+        .await?;
+
+    let latest = repo.latest().await?.expect("should exist");
+    assert_eq!(latest.id, recorded.id);
+    assert_eq!(latest.outcome, ImportOutcome::Failure);
+    assert_eq!(latest.problem_count, None);
+    assert_eq!(
+        latest.error_message.as_deref(),
+        Some("external unavailable")
+    );
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn list_recent_returns_runs_newest_first_with_changes(pool: PgPool) -> Result<()> {
+    let repo = ProblemImportRunRepositoryImpl::new(ConnectionPool::new(pool));
+    let now = Utc::now();
+    let changes = ProblemImportChanges {
+        inserted: 3,
+        updated: 1,
+        unchanged: 96,
+        removed: 2,
+        difficulty_fetch_failures: 4,
+        difficulty_fetch_deferred: 5,
+    };
+    for hours_ago in [48, 24, 1] {
+        repo.record(&NewProblemImportRun {
+            started_at: now - Duration::hours(hours_ago) - Duration::minutes(1),
+            finished_at: now - Duration::hours(hours_ago),
+            outcome: ImportOutcome::Success,
+            problem_count: Some(100),
+            changes: Some(changes),
+            error_message: None,
+        })
+        .await?;
+    }
+
+    let runs = repo.list_recent(2).await?;
+    assert_eq!(runs.len(), 2);
+    assert!(runs[0].finished_at > runs[1].finished_at);
+    assert_eq!(runs[0].changes, Some(changes));
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn migration_status_has_no_pending_versions_after_migrate(pool: PgPool) -> Result<()> {
+    let repo = HealthCheckRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    let status = repo.migration_status().await?;
+    assert!(status.pending_versions.is_empty());
+    assert_eq!(status.applied_version, status.expected_version);
+
+    sqlx::query("DELETE FROM _sqlx_migrations WHERE version = $1")
+        .bind(status.expected_version.unwrap())
+        .execute(&pool)
+        .await?;
+    let status = repo.migration_status().await?;
+    assert!(status.is_behind());
+    assert_eq!(status.pending_versions.len(), 1);
+    Ok(())
+}
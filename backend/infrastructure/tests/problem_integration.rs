@@ -1,27 +1,34 @@
 use anyhow::Result;
-use domain::model::problem::{ContestSeries, Problem};
-use domain::ports::repository::problem::ProblemRepository;
-use infrastructure::{database::ConnectionPool, ports::repository::problem::ProblemRepositoryImpl};
+use domain::error::repository::RepositoryError;
+use domain::model::problem::{
+    ContestMetadata, ContestSeries, ContestSeriesCatalog, DifficultyRange, Problem,
+    ProblemAppearance, natural_cmp,
+};
+use domain::ports::repository::{
+    contest_series::ContestSeriesRepository,
+    problem::{ProblemRepository, tx::ProblemTxManager},
+};
+use infrastructure::{
+    database::ConnectionPool,
+    ports::{
+        repository::{
+            contest_series::ContestSeriesRepositoryImpl,
+            problem::{ProblemRepositoryImpl, tx::ProblemTransactionManager},
+        },
+        service::contests::ContestServiceImpl,
+    },
+};
 use sqlx::PgPool;
+use usecase::service::contest::ContestService;
 
-#[cfg(test)]
-pub async fn seed_contest_series(pool: &PgPool) -> Result<()> {
-    for code in ["ABC", "ARC", "AGC", "AHC", "AWC", "OTHER"] {
-        sqlx::query!(
-            r#"INSERT INTO contest_series (code)
-               VALUES ($1) ON CONFLICT (code) DO NOTHING"#,
-            code
-        )
-        .execute(pool)
-        .await?;
-    }
-    Ok(())
+// 取り込みと同じく、マイグレーションで入るシリーズで分類する
+async fn series_catalog(pool: &PgPool) -> Result<ContestSeriesCatalog> {
+    let repo = ContestSeriesRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    Ok(ContestSeriesCatalog::new(repo.list().await?)?)
 }
 
 #[sqlx::test(migrations = "./migrations")]
 async fn create_records(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
-
     let conn = ConnectionPool::new(pool.clone());
     let repo = ProblemRepositoryImpl::new(conn);
     let p1 = Problem {
@@ -39,7 +46,8 @@ async fn create_records(pool: PgPool) -> Result<()> {
         difficulty: None,
     };
 
-    repo.create_records(vec![p1, p2]).await?;
+    repo.create_records(vec![p1, p2], &series_catalog(&pool).await?)
+        .await?;
 
     let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM contests")
         .fetch_one(&pool)
@@ -57,8 +65,6 @@ async fn create_records(pool: PgPool) -> Result<()> {
 
 #[sqlx::test(migrations = "./migrations")]
 async fn upsert_records(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
-
     let conn = ConnectionPool::new(pool.clone());
     let repo = ProblemRepositoryImpl::new(conn);
     let p1 = Problem {
@@ -68,7 +74,8 @@ async fn upsert_records(pool: PgPool) -> Result<()> {
         title: "A - Example".into(),
         difficulty: None,
     };
-    repo.create_records(vec![p1.to_owned()]).await?;
+    repo.create_records(vec![p1.to_owned()], &series_catalog(&pool).await?)
+        .await?;
     let (title,): (String,) = sqlx::query_as("SELECT title FROM problems WHERE id = 'abc300_a'")
         .fetch_one(&pool)
         .await?;
@@ -78,7 +85,8 @@ async fn upsert_records(pool: PgPool) -> Result<()> {
         title: "A - Example modified".into(),
         ..p1
     };
-    repo.create_records(vec![updated]).await?;
+    repo.create_records(vec![updated], &series_catalog(&pool).await?)
+        .await?;
     let (title,): (String,) = sqlx::query_as("SELECT title FROM problems WHERE id = 'abc300_a'")
         .fetch_one(&pool)
         .await?;
@@ -88,8 +96,6 @@ async fn upsert_records(pool: PgPool) -> Result<()> {
 
 #[sqlx::test(migrations = "./migrations")]
 async fn get_problems_by_contest_series(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
-
     let conn = ConnectionPool::new(pool.clone());
     let repo = ProblemRepositoryImpl::new(conn);
     let p1 = Problem {
@@ -121,7 +127,8 @@ async fn get_problems_by_contest_series(pool: PgPool) -> Result<()> {
         difficulty: None,
     };
 
-    repo.create_records(vec![p1, p2, p3, p4]).await?;
+    repo.create_records(vec![p1, p2, p3, p4], &series_catalog(&pool).await?)
+        .await?;
 
     let abcs = repo
         .get_problems_by_contest_series(ContestSeries::ABC)
@@ -143,8 +150,6 @@ async fn get_problems_by_contest_series(pool: PgPool) -> Result<()> {
 
 #[sqlx::test(migrations = "./migrations")]
 async fn create_records_with_unknown_series_uses_other(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
-
     let conn = ConnectionPool::new(pool.clone());
     let repo = ProblemRepositoryImpl::new(conn);
     let p1 = Problem {
@@ -155,7 +160,8 @@ async fn create_records_with_unknown_series_uses_other(pool: PgPool) -> Result<(
         difficulty: None,
     };
 
-    repo.create_records(vec![p1]).await?;
+    repo.create_records(vec![p1], &series_catalog(&pool).await?)
+        .await?;
 
     let (series_code,): (String,) =
         sqlx::query_as("SELECT series_code FROM contests WHERE code = 'tessoku-book'")
@@ -171,3 +177,267 @@ async fn create_records_with_unknown_series_uses_other(pool: PgPool) -> Result<(
 
     Ok(())
 }
+
+#[sqlx::test(migrations = "./migrations")]
+async fn contest_metadata_is_stored_only_for_existing_contests(pool: PgPool) -> Result<()> {
+    let conn = ConnectionPool::new(pool.clone());
+    let repo = ProblemRepositoryImpl::new(conn.clone());
+    repo.create_records(
+        vec![Problem {
+            id: "abc234_a".into(),
+            contest_code: "abc234".into(),
+            problem_index: "A".into(),
+            title: "Weird Function".into(),
+            difficulty: None,
+        }],
+        &series_catalog(&pool).await?,
+    )
+    .await?;
+    assert!(repo.get_contest_metadata("abc234").await?.is_none());
+
+    let abc234 = ContestMetadata {
+        code: "abc234".into(),
+        title: "AtCoder Beginner Contest 234".into(),
+        start_at: "2022-01-01T12:00:00Z".parse()?,
+        duration_second: 6000,
+        rate_change: "~ 1999".into(),
+    };
+    let txm = ProblemTransactionManager::new(conn.clone());
+    let mut uow = txm.begin().await?;
+    uow.problems()
+        .update_contest_metadata_bulk(&[
+            abc234.clone(),
+            ContestMetadata {
+                code: "adt_easy_20250430_3".into(),
+                ..abc234.clone()
+            },
+        ])
+        .await?;
+    uow.commit().await?;
+
+    assert_eq!(
+        repo.get_contest_metadata("ABC234").await?,
+        Some(abc234.clone())
+    );
+    assert_eq!(repo.get_all_contest_metadata().await?, vec![abc234]);
+
+    let contests = ContestServiceImpl::new(conn)
+        .get_contents_by_series(ContestSeries::ABC)
+        .await?;
+    assert_eq!(contests.len(), 1);
+    assert_eq!(
+        contests[0].title.as_deref(),
+        Some("AtCoder Beginner Contest 234")
+    );
+    assert_eq!(contests[0].duration_second, Some(6000));
+    assert_eq!(contests[0].rate_change.as_deref(), Some("~ 1999"));
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn contest_codes_are_ordered_by_start_time_then_number(pool: PgPool) -> Result<()> {
+    let conn = ConnectionPool::new(pool.clone());
+    let repo = ProblemRepositoryImpl::new(conn.clone());
+    let problems = ["abc99", "abc400", "abc100", "abc401"]
+        .into_iter()
+        .map(|code| Problem {
+            id: format!("{code}_a"),
+            contest_code: code.into(),
+            problem_index: "A".into(),
+            title: "A - Example".into(),
+            difficulty: None,
+        })
+        .collect();
+    repo.create_records(problems, &series_catalog(&pool).await?)
+        .await?;
+    // abc401だけ開始時刻が分かっている
+    let txm = ProblemTransactionManager::new(conn.clone());
+    let mut uow = txm.begin().await?;
+    uow.problems()
+        .update_contest_metadata_bulk(&[ContestMetadata {
+            code: "abc401".into(),
+            title: "AtCoder Beginner Contest 401".into(),
+            start_at: "2025-04-12T12:00:00Z".parse()?,
+            duration_second: 6000,
+            rate_change: "~ 1999".into(),
+        }])
+        .await?;
+    uow.commit().await?;
+
+    let codes = repo
+        .get_contest_codes_by_series(ContestSeries::ABC, DifficultyRange::default(), 10, 0)
+        .await?;
+    assert_eq!(codes, vec!["abc401", "abc400", "abc100", "abc99"]);
+    let page = repo
+        .get_contest_codes_by_series(ContestSeries::ABC, DifficultyRange::default(), 2, 1)
+        .await?;
+    assert_eq!(page, vec!["abc400", "abc100"]);
+
+    let contests = ContestServiceImpl::new(conn)
+        .get_contents_by_series(ContestSeries::ABC)
+        .await?;
+    let codes = contests.iter().map(|c| c.code.as_str()).collect::<Vec<_>>();
+    assert_eq!(codes, vec!["abc401", "abc400", "abc100", "abc99"]);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn natural_sort_key_orders_codes_like_natural_cmp(pool: PgPool) -> Result<()> {
+    let codes = [
+        "abc99",
+        "abc400",
+        "ABC100",
+        "abc100",
+        "abc001",
+        "abc1",
+        "arc100",
+        "abc200",
+        "typical90",
+        "typical90_b",
+        "adt_easy_20250430_3",
+        "adt_easy_20250430_10",
+        "tessoku-book",
+        "",
+    ]
+    .map(String::from);
+    let mut expected = codes.to_vec();
+    expected.sort_by(|a, b| natural_cmp(a, b));
+
+    let sorted: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT code
+        FROM UNNEST($1::text[]) AS t(code)
+        ORDER BY natural_sort_key(code) COLLATE "C", code COLLATE "C"
+        "#,
+    )
+    .bind(&codes[..])
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(sorted, expected);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn problems_are_listed_by_every_contest_they_appear_in(pool: PgPool) -> Result<()> {
+    let conn = ConnectionPool::new(pool.clone());
+    let repo = ProblemRepositoryImpl::new(conn.clone());
+    repo.create_records(
+        vec![Problem {
+            id: "abc395_a".into(),
+            contest_code: "abc395".into(),
+            problem_index: "A".into(),
+            title: "Strictly Increasing?".into(),
+            difficulty: Some(20),
+        }],
+        &series_catalog(&pool).await?,
+    )
+    .await?;
+
+    let adt = ProblemAppearance {
+        contest_code: "adt_easy_20250430_3".into(),
+        problem_index: "B".into(),
+        problem_id: "abc395_a".into(),
+    };
+    let txm = ProblemTransactionManager::new(conn.clone());
+    let mut uow = txm.begin().await?;
+    uow.problems()
+        .upsert_contests_bulk(&[(adt.contest_code.clone(), "OTHER".into())])
+        .await?;
+    uow.problems()
+        .upsert_problem_appearances_bulk(std::slice::from_ref(&adt))
+        .await?;
+    uow.commit().await?;
+
+    let problems = repo.get_problems_by_contest("adt_easy_20250430_3").await?;
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].id, "abc395_a");
+    assert_eq!(problems[0].contest_code, "adt_easy_20250430_3");
+    assert_eq!(problems[0].problem_index.as_str(), "B");
+    assert_eq!(problems[0].difficulty, Some(20));
+
+    let problems = repo.get_problems_by_contest("abc395").await?;
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].problem_index.as_str(), "A");
+
+    let mut appearances = repo.get_all_problem_appearances().await?;
+    appearances.sort_by(|a, b| a.contest_code.cmp(&b.contest_code));
+    assert_eq!(appearances.len(), 2);
+    assert_eq!(appearances[1], adt);
+
+    assert_eq!(
+        repo.get_problem_id_by_appearance("ADT_EASY_20250430_3", "b")
+            .await?,
+        "abc395_a"
+    );
+    let err = repo
+        .get_problem_id_by_appearance("adt_easy_20250430_3", "C")
+        .await
+        .expect_err("unknown appearance");
+    assert!(matches!(err, RepositoryError::NotFound(_)));
+
+    let contests = ContestServiceImpl::new(conn)
+        .get_contents_by_series(ContestSeries::OTHER)
+        .await?;
+    assert_eq!(contests.len(), 1);
+    assert_eq!(contests[0].code, "adt_easy_20250430_3");
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn problems_sharing_a_contest_index_are_all_listed(pool: PgPool) -> Result<()> {
+    let conn = ConnectionPool::new(pool.clone());
+    let repo = ProblemRepositoryImpl::new(conn.clone());
+    // 取り込み元で同じコンテストの同じindexに2問あることがある
+    let problems = ["abc050_a", "abc050_a_2"]
+        .into_iter()
+        .map(|id| Problem {
+            id: id.into(),
+            contest_code: "abc050".into(),
+            problem_index: "A".into(),
+            title: "A - Example".into(),
+            difficulty: None,
+        })
+        .collect();
+    repo.create_records(problems, &series_catalog(&pool).await?)
+        .await?;
+
+    let ids = |problems: Vec<Problem>| {
+        let mut ids = problems.into_iter().map(|p| p.id).collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+    let expected = vec!["abc050_a".to_string(), "abc050_a_2".to_string()];
+    assert_eq!(
+        ids(repo
+            .get_problems_by_contest_series(ContestSeries::ABC)
+            .await?),
+        expected
+    );
+    let codes = repo
+        .get_contest_codes_by_series(ContestSeries::ABC, DifficultyRange::default(), 10, 0)
+        .await?;
+    assert_eq!(codes, vec!["abc050"]);
+    assert_eq!(
+        ids(repo
+            .get_problems_by_contest_codes(&codes, DifficultyRange::default())
+            .await?),
+        expected
+    );
+    assert_eq!(
+        ids(repo
+            .search_problems_by_contest_series(
+                ContestSeries::ABC,
+                "abc050",
+                DifficultyRange::default()
+            )
+            .await?),
+        expected
+    );
+    assert_eq!(ids(repo.get_problems_by_contest("abc050").await?), expected);
+    assert_eq!(repo.get_all_problem_appearances().await?.len(), 2);
+    assert_eq!(
+        repo.get_problem_id_by_appearance("abc050", "A").await?,
+        "abc050_a"
+    );
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use domain::{
+    error::repository::RepositoryError,
+    model::{
+        session::SessionActivity,
+        user::{Role, User},
+    },
+    ports::repository::{session::SessionRepository, user::UserRepository},
+};
+use infrastructure::{
+    database::ConnectionPool,
+    ports::repository::{session::SessionRepositoryImpl, user::UserRepositoryImpl},
+};
+use sqlx::PgPool;
+
+async fn seed_user(pool: &PgPool, id: &str, name: &str) -> Result<()> {
+    let repo = UserRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    repo.create_user(User {
+        id: id.into(),
+        role: Role::default(),
+        user_name: name.into(),
+    })
+    .await?;
+    Ok(())
+}
+
+fn activity(user_id: &str, authenticated_at: i64, user_agent: &str) -> SessionActivity {
+    SessionActivity {
+        user_id: user_id.into(),
+        authenticated_at: DateTime::<Utc>::from_timestamp(authenticated_at, 0).unwrap(),
+        user_agent: Some(user_agent.into()),
+        ip_address: Some("192.0.2.1".into()),
+    }
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn touch_creates_one_session_per_sign_in(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    let repo = SessionRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    let first = repo
+        .touch(&activity("uid1", 1_700_000_000, "laptop"))
+        .await?;
+    let again = repo
+        .touch(&activity("uid1", 1_700_000_000, "laptop"))
+        .await?;
+    let other = repo
+        .touch(&activity("uid1", 1_700_000_100, "lab pc"))
+        .await?;
+
+    assert_eq!(first.id, again.id);
+    assert_ne!(first.id, other.id);
+    assert!(!first.revoked);
+
+    let sessions = repo.list_active_by_user("uid1").await?;
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].user_agent.as_deref(), Some("lab pc"));
+    assert_eq!(sessions[0].ip_address.as_deref(), Some("192.0.2.1"));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn revoked_session_stays_revoked_on_touch(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    seed_user(&pool, "uid2", "bob").await?;
+    let repo = SessionRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    let lab = repo
+        .touch(&activity("uid1", 1_700_000_000, "lab pc"))
+        .await?;
+    repo.touch(&activity("uid1", 1_700_000_100, "laptop"))
+        .await?;
+
+    let err = repo
+        .revoke("uid2", lab.id)
+        .await
+        .expect_err("other user's session cannot be revoked");
+    assert!(matches!(err, RepositoryError::NotFound(_)));
+
+    repo.revoke("uid1", lab.id).await?;
+    let touched = repo
+        .touch(&activity("uid1", 1_700_000_000, "lab pc"))
+        .await?;
+    assert!(touched.revoked);
+
+    let sessions = repo.list_active_by_user("uid1").await?;
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].user_agent.as_deref(), Some("laptop"));
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn revoke_all_revokes_every_session_of_user(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    seed_user(&pool, "uid2", "bob").await?;
+    let repo = SessionRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    repo.touch(&activity("uid1", 1_700_000_000, "lab pc"))
+        .await?;
+    repo.touch(&activity("uid1", 1_700_000_100, "laptop"))
+        .await?;
+    repo.touch(&activity("uid2", 1_700_000_000, "phone"))
+        .await?;
+
+    repo.revoke_all_by_user("uid1").await?;
+
+    assert!(repo.list_active_by_user("uid1").await?.is_empty());
+    assert_eq!(repo.list_active_by_user("uid2").await?.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn find_status_reads_revocation_and_last_seen(pool: PgPool) -> Result<()> {
+    seed_user(&pool, "uid1", "alice").await?;
+    let repo = SessionRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    let authenticated_at = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+
+    assert!(repo.find_status("uid1", authenticated_at).await?.is_none());
+
+    let touched = repo
+        .touch(&activity("uid1", 1_700_000_000, "laptop"))
+        .await?;
+    let status = repo
+        .find_status("uid1", authenticated_at)
+        .await?
+        .expect("touched session");
+    assert!(!status.revoked);
+    let sessions = repo.list_active_by_user("uid1").await?;
+    assert_eq!(status.last_seen_at, sessions[0].last_seen_at);
+
+    repo.revoke("uid1", touched.id).await?;
+    let status = repo
+        .find_status("uid1", authenticated_at)
+        .await?
+        .expect("revoked session");
+    assert!(status.revoked);
+
+    Ok(())
+}
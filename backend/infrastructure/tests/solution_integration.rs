@@ -4,7 +4,10 @@ use domain::ports::repository::problem::ProblemRepository;
 use domain::ports::repository::user::UserRepository;
 use domain::{
     error::repository::RepositoryError,
-    model::{problem::Problem, solution::Solution},
+    model::{
+        problem::{ContestSeriesCatalog, DifficultyRange, Problem, clip_difficulty},
+        solution::Solution,
+    },
     ports::repository::solution::tx::SolutionTxManager,
 };
 use infrastructure::ports::repository::user::UserRepositoryImpl;
@@ -21,22 +24,12 @@ use usecase::{model::solution::SolutionListSort, service::solution::SolutionServ
 use uuid::Uuid;
 
 #[cfg(test)]
-async fn seed_contest_series(pool: &PgPool) -> Result<()> {
-    for code in ["ABC", "ARC", "AGC", "AHC", "AWC", "OTHER"] {
-        sqlx::query!(
-            r#"INSERT INTO contest_series (code)
-               VALUES ($1) ON CONFLICT (code) DO NOTHING"#,
-            code
-        )
-        .execute(pool)
-        .await?;
-    }
-    Ok(())
-}
-
-#[cfg(test)]
+// 解説のテストではシリーズを使わない
 async fn seed_problem(problems_repo: &ProblemRepositoryImpl, problem: Problem) {
-    problems_repo.create_records(vec![problem]).await.unwrap()
+    problems_repo
+        .create_records(vec![problem], &ContestSeriesCatalog::new(vec![]).unwrap())
+        .await
+        .unwrap()
 }
 
 pub async fn seed_roles(pool: &PgPool) -> Result<()> {
@@ -56,7 +49,6 @@ pub async fn seed_roles(pool: &PgPool) -> Result<()> {
 
 #[sqlx::test(migrations = "./migrations")]
 async fn create_solution_records(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
     seed_roles(&pool).await?;
 
     let conn = ConnectionPool::new(pool.clone());
@@ -116,7 +108,6 @@ async fn create_solution_records(pool: PgPool) -> Result<()> {
 
 #[sqlx::test(migrations = "./migrations")]
 async fn create_solution_transaction_error(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
     seed_roles(&pool).await?;
 
     let conn = ConnectionPool::new(pool.clone());
@@ -217,7 +208,6 @@ async fn insert_vote(pool: &PgPool, user_id: &str, solution_id: Uuid) -> Result<
 
 #[sqlx::test(migrations = "./migrations")]
 async fn get_solutions_by_problem_id_sorts_latest_and_votes(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
     seed_roles(&pool).await?;
 
     let conn = ConnectionPool::new(pool.clone());
@@ -286,7 +276,6 @@ async fn get_solutions_by_problem_id_sorts_latest_and_votes(pool: PgPool) -> Res
 async fn get_solutions_by_user_name_votes_returns_problem_title_and_vote_count(
     pool: PgPool,
 ) -> Result<()> {
-    seed_contest_series(&pool).await?;
     seed_roles(&pool).await?;
 
     let conn = ConnectionPool::new(pool.clone());
@@ -339,7 +328,11 @@ async fn get_solutions_by_user_name_votes_returns_problem_title_and_vote_count(
 
     let service = SolutionServiceImpl::new(conn);
     let rows = service
-        .get_solutions_by_user_name("alice".to_string(), SolutionListSort::Votes)
+        .get_solutions_by_user_name(
+            "alice".to_string(),
+            SolutionListSort::Votes,
+            DifficultyRange::default(),
+        )
         .await?;
 
     assert_eq!(rows.len(), 2);
@@ -357,7 +350,6 @@ async fn get_solutions_by_user_name_votes_returns_problem_title_and_vote_count(
 async fn get_solutions_by_problem_id_votes_tie_breaks_by_created_at_desc(
     pool: PgPool,
 ) -> Result<()> {
-    seed_contest_series(&pool).await?;
     seed_roles(&pool).await?;
 
     let conn = ConnectionPool::new(pool.clone());
@@ -444,7 +436,6 @@ async fn create_solution_with_missing_problem_returns_fk_violation(pool: PgPool)
 
 #[sqlx::test(migrations = "./migrations")]
 async fn deleting_user_cascades_solutions_comments_and_votes(pool: PgPool) -> Result<()> {
-    seed_contest_series(&pool).await?;
     seed_roles(&pool).await?;
 
     let conn = ConnectionPool::new(pool.clone());
@@ -529,3 +520,281 @@ async fn deleting_user_cascades_solutions_comments_and_votes(pool: PgPool) -> Re
 
     Ok(())
 }
+
+#[sqlx::test(migrations = "./migrations")]
+async fn hidden_solution_is_excluded_until_unhidden(pool: PgPool) -> Result<()> {
+    seed_roles(&pool).await?;
+
+    let conn = ConnectionPool::new(pool.clone());
+    let problems_repo = ProblemRepositoryImpl::new(conn.to_owned());
+    seed_problem(
+        &problems_repo,
+        Problem {
+            id: "abc303_a".into(),
+            contest_code: "abc303".into(),
+            problem_index: "a".into(),
+            title: "A - Example".into(),
+            difficulty: None,
+        },
+    )
+    .await;
+
+    let user_repo = UserRepositoryImpl::new(conn.to_owned());
+    user_repo
+        .create_user(User {
+            id: "author".to_string(),
+            user_name: "author".to_string(),
+            role: Role::default(),
+        })
+        .await?;
+    let solution_id = create_solution_only(conn.to_owned(), "abc303_a", "author", "title").await?;
+
+    let tx_mng = SolutionTransactionManager::new(conn.to_owned());
+    let mut uow = tx_mng.begin().await?;
+    uow.solutions().set_hidden(solution_id, true).await?;
+    uow.commit().await?;
+
+    let service = SolutionServiceImpl::new(conn.to_owned());
+    let listed = service
+        .get_solutions_by_problem_id("abc303_a".to_string(), SolutionListSort::Latest, None)
+        .await?;
+    assert!(listed.is_empty());
+    assert!(!service.solution_exists(solution_id).await?);
+
+    let mut uow = tx_mng.begin().await?;
+    uow.solutions().set_hidden(solution_id, false).await?;
+    uow.commit().await?;
+
+    assert!(service.solution_exists(solution_id).await?);
+
+    let mut uow = tx_mng.begin().await?;
+    let err = uow
+        .solutions()
+        .set_hidden(Uuid::now_v7(), true)
+        .await
+        .expect_err("should error");
+    assert!(matches!(err, RepositoryError::NotFound(_)));
+    uow.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn solution_stats_are_grouped_by_problem_and_skip_hidden(pool: PgPool) -> Result<()> {
+    seed_roles(&pool).await?;
+
+    let conn = ConnectionPool::new(pool.clone());
+    let problems_repo = ProblemRepositoryImpl::new(conn.to_owned());
+    for (id, contest_code) in [("abc304_a", "abc304"), ("abc305_a", "abc305")] {
+        seed_problem(
+            &problems_repo,
+            Problem {
+                id: id.into(),
+                contest_code: contest_code.into(),
+                problem_index: "a".into(),
+                title: "A - Example".into(),
+                difficulty: None,
+            },
+        )
+        .await;
+    }
+
+    let user_repo = UserRepositoryImpl::new(conn.to_owned());
+    for id in ["author1", "author2", "voter1", "voter2"] {
+        user_repo
+            .create_user(User {
+                id: id.to_string(),
+                user_name: id.to_string(),
+                role: Role::default(),
+            })
+            .await?;
+    }
+
+    let first_id = create_solution_only(conn.to_owned(), "abc304_a", "author1", "first").await?;
+    let second_id = create_solution_only(conn.to_owned(), "abc304_a", "author2", "second").await?;
+    let hidden_id = create_solution_only(conn.to_owned(), "abc304_a", "author2", "hidden").await?;
+
+    insert_vote(&pool, "voter1", second_id).await?;
+    insert_vote(&pool, "voter2", second_id).await?;
+    insert_vote(&pool, "voter1", first_id).await?;
+    insert_vote(&pool, "voter1", hidden_id).await?;
+    insert_vote(&pool, "voter2", hidden_id).await?;
+
+    let tx_mng = SolutionTransactionManager::new(conn.to_owned());
+    let mut uow = tx_mng.begin().await?;
+    uow.solutions().set_hidden(hidden_id, true).await?;
+    uow.commit().await?;
+
+    sleep(Duration::from_millis(20)).await;
+    sqlx::query!(
+        r#"
+        INSERT INTO comments (user_id, solution_id, body_md)
+        VALUES ($1, $2, $3)
+        "#,
+        "voter1",
+        first_id,
+        "comment body"
+    )
+    .execute(&pool)
+    .await?;
+    let commented_at = sqlx::query_scalar!("SELECT MAX(updated_at) FROM comments")
+        .fetch_one(&pool)
+        .await?;
+
+    let service = SolutionServiceImpl::new(conn);
+    let stats = service
+        .get_solution_stats_by_problem_ids(&["abc304_a".to_string(), "abc305_a".to_string()])
+        .await?;
+
+    // 解説のない問題は返さない
+    assert_eq!(stats.len(), 1);
+    let stat = &stats[0];
+    assert_eq!(stat.problem_id, "abc304_a");
+    assert_eq!(stat.solution_count, 2);
+    assert_eq!(stat.total_votes, 3);
+    assert_eq!(stat.top_voted_solution_id, Some(second_id));
+    assert_eq!(stat.latest_activity_at, commented_at);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn top_tags_are_counted_over_visible_solutions(pool: PgPool) -> Result<()> {
+    seed_roles(&pool).await?;
+
+    let conn = ConnectionPool::new(pool.clone());
+    let problems_repo = ProblemRepositoryImpl::new(conn.to_owned());
+    seed_problem(
+        &problems_repo,
+        Problem {
+            id: "abc306_a".into(),
+            contest_code: "abc306".into(),
+            problem_index: "a".into(),
+            title: "A - Example".into(),
+            difficulty: None,
+        },
+    )
+    .await;
+
+    let user_repo = UserRepositoryImpl::new(conn.to_owned());
+    user_repo
+        .create_user(User {
+            id: "author1".to_string(),
+            user_name: "author1".to_string(),
+            role: Role::default(),
+        })
+        .await?;
+
+    let tx_mng = SolutionTransactionManager::new(conn.to_owned());
+    for (title, tags, hidden) in [
+        ("first", vec!["greedy", "dp"], false),
+        ("second", vec!["dp"], false),
+        ("third", vec!["bfs", "dp"], false),
+        ("hidden", vec!["bfs", "graph"], true),
+    ] {
+        let id = create_solution_only(conn.to_owned(), "abc306_a", "author1", title).await?;
+        let names: Vec<String> = tags.into_iter().map(String::from).collect();
+        let mut uow = tx_mng.begin().await?;
+        let tag_ids = uow.tags().upsert(&names).await?;
+        uow.solutions().replace_tags(id, &tag_ids).await?;
+        uow.solutions().set_hidden(id, hidden).await?;
+        uow.commit().await?;
+    }
+
+    let service = SolutionServiceImpl::new(conn);
+    let tags = service.get_top_tags_by_problem_id("abc306_a", 2).await?;
+
+    // 件数の多い順、同数なら名前順。非表示の解説は数えない
+    let tags: Vec<(String, i64)> = tags
+        .into_iter()
+        .map(|t| (t.name, t.solution_count))
+        .collect();
+    assert_eq!(tags, vec![("dp".to_string(), 3), ("bfs".to_string(), 1)]);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "./migrations")]
+async fn latest_solutions_are_filtered_by_clipped_difficulty(pool: PgPool) -> Result<()> {
+    seed_roles(&pool).await?;
+
+    // SQLの補正がドメインの補正と一致すること
+    for difficulty in [-3000, -1000, -101, -100, 0, 1, 250, 399, 400, 1234, 4000] {
+        let clipped = sqlx::query_scalar!("SELECT clip_difficulty($1)", difficulty)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(clipped, Some(clip_difficulty(difficulty)), "{difficulty}");
+    }
+
+    let conn = ConnectionPool::new(pool.clone());
+    let problems_repo = ProblemRepositoryImpl::new(conn.to_owned());
+    for (id, difficulty) in [
+        ("abc306_a", Some(-100)),
+        ("abc306_b", Some(-1000)),
+        ("abc306_c", Some(1200)),
+        ("abc306_d", None),
+    ] {
+        seed_problem(
+            &problems_repo,
+            Problem {
+                id: id.into(),
+                contest_code: "abc306".into(),
+                problem_index: id.rsplit('_').next().unwrap().into(),
+                title: "Example".into(),
+                difficulty,
+            },
+        )
+        .await;
+    }
+    let user_repo = UserRepositoryImpl::new(conn.to_owned());
+    user_repo
+        .create_user(User {
+            id: "author".to_string(),
+            user_name: "author".to_string(),
+            role: Role::default(),
+        })
+        .await?;
+    for problem_id in ["abc306_a", "abc306_b", "abc306_c", "abc306_d"] {
+        create_solution_only(conn.to_owned(), problem_id, "author", problem_id).await?;
+    }
+
+    let service = SolutionServiceImpl::new(conn);
+    let problem_ids = |range: DifficultyRange| {
+        let service = &service;
+        async move {
+            let mut latest = service
+                .get_latest_solutions(None, range)
+                .await?
+                .into_iter()
+                .map(|s| s.problem_id)
+                .collect::<Vec<_>>();
+            latest.sort();
+            let mut by_user = service
+                .get_solutions_by_user_name("author".to_string(), SolutionListSort::Latest, range)
+                .await?
+                .into_iter()
+                .map(|s| s.problem_id)
+                .collect::<Vec<_>>();
+            by_user.sort();
+            assert_eq!(latest, by_user);
+            anyhow::Ok(latest)
+        }
+    };
+
+    assert_eq!(problem_ids(DifficultyRange::default()).await?.len(), 4);
+    // -100は115、-1000は12に補正される
+    assert_eq!(
+        problem_ids(DifficultyRange::new(Some(100), Some(399))).await?,
+        vec!["abc306_a"]
+    );
+    assert_eq!(
+        problem_ids(DifficultyRange::new(None, Some(399))).await?,
+        vec!["abc306_a", "abc306_b"]
+    );
+    assert_eq!(
+        problem_ids(DifficultyRange::new(Some(1200), None)).await?,
+        vec!["abc306_c"]
+    );
+
+    Ok(())
+}
@@ -215,3 +215,23 @@ async fn missing_user_is_treated_as_revoked(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test(migrations = "./migrations")]
+async fn update_role_changes_role_and_missing_user_is_not_found(pool: PgPool) -> Result<()> {
+    seed_roles(&pool).await?;
+
+    let conn = ConnectionPool::new(pool.clone());
+    let repo = UserRepositoryImpl::new(conn);
+    repo.create_user(make_user("id1", "bob1")).await?;
+
+    repo.update_role("id1", Role::Admin).await?;
+    assert_eq!(repo.find_by_uid("id1").await?.role, Role::Admin);
+
+    let err = repo
+        .update_role("id999", Role::Admin)
+        .await
+        .expect_err("should error");
+    assert!(matches!(err, RepositoryError::NotFound(_)));
+
+    Ok(())
+}
@@ -1,6 +1,11 @@
 use shared::error::http::HttpError;
 use usecase::model::{
-    contests::ContestError, problem::ProblemError, solution::SolutionError, user::UserError,
+    contests::ContestError,
+    explanation_request::ExplanationRequestError,
+    personal_token::PersonalTokenError,
+    problem::{ProblemError, resolve_url::ResolveProblemUrlError},
+    solution::SolutionError,
+    user::UserError,
 };
 
 pub trait ToHttpError {
@@ -20,6 +25,19 @@ impl ToHttpError for ProblemError {
     }
 }
 
+impl ToHttpError for ResolveProblemUrlError {
+    fn to_http_error(self) -> HttpError {
+        match self {
+            ResolveProblemUrlError::BadRequest(reason) => HttpError::BadRequest(reason),
+            ResolveProblemUrlError::NotImported { .. } => HttpError::NotFound(self.to_string()),
+            ResolveProblemUrlError::DBError(reason) => {
+                tracing::error!(domain = "problem", error.message = %reason, "internal error");
+                HttpError::Internal(reason)
+            }
+        }
+    }
+}
+
 impl ToHttpError for SolutionError {
     fn to_http_error(self) -> HttpError {
         match self {
@@ -49,11 +67,39 @@ impl ToHttpError for UserError {
     }
 }
 
+impl ToHttpError for PersonalTokenError {
+    fn to_http_error(self) -> HttpError {
+        match self {
+            PersonalTokenError::BadRequest(reason) => HttpError::BadRequest(reason),
+            PersonalTokenError::NotFound(reason) => HttpError::NotFound(reason),
+            PersonalTokenError::DBError(reason) => {
+                tracing::error!(domain = "personal_token", error.message = %reason, "internal error");
+                HttpError::Internal(reason)
+            }
+        }
+    }
+}
+
+impl ToHttpError for ExplanationRequestError {
+    fn to_http_error(self) -> HttpError {
+        match self {
+            ExplanationRequestError::BadRequest(reason) => HttpError::BadRequest(reason),
+            ExplanationRequestError::NotFound(reason) => HttpError::NotFound(reason),
+            ExplanationRequestError::Conflict(reason) => HttpError::Conflict(reason),
+            ExplanationRequestError::DBError(reason) => {
+                tracing::error!(domain = "explanation_request", error.message = %reason, "internal error");
+                HttpError::Internal(reason)
+            }
+        }
+    }
+}
+
 impl ToHttpError for ContestError {
     fn to_http_error(self) -> HttpError {
         match self {
             ContestError::BadRequest(reason) => HttpError::BadRequest(reason),
             ContestError::NotFound(reason) => HttpError::NotFound(reason),
+            ContestError::Conflict(reason) => HttpError::Conflict(reason),
             ContestError::DBError(reason) => {
                 tracing::error!(domain = "contest", error.message = %reason, "internal error");
                 HttpError::Internal(reason)
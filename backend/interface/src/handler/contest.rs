@@ -1,11 +1,50 @@
+use std::time::Duration;
+
 use axum::extract::{Path, State};
 use domain::model::problem::ContestSeries;
 use registry::Registry;
 use shared::{error::http::HttpError, response::ApiResponse};
-use usecase::contest::get_by_series::GetContestsBySeriesUseCase;
+use usecase::contest::{
+    add_series::AddContestSeriesUsecase, get_by_series::GetContestsBySeriesUseCase,
+    load_series::LoadContestSeriesUsecase,
+};
+use usecase::model::contests::ContestError;
 
 use crate::error::ToHttpError;
-use crate::model::contests::ContestResponse;
+use crate::http::{AdminUser, ApiJson};
+use crate::model::contests::{
+    ContestResponse,
+    add_series::{AddContestSeriesRequest, AddContestSeriesResponse},
+};
+
+// contest_seriesを読み込み、Registryの一覧を差し替える
+pub async fn load_contest_series(reg: &Registry) -> Result<(), ContestError> {
+    let catalog = LoadContestSeriesUsecase::new(reg.contest_series_repository())
+        .run()
+        .await?;
+    reg.set_contest_series(catalog);
+    Ok(())
+}
+
+// 未知のシリーズで読み込み直す間隔の下限。認証なしのリクエストで毎回DBを読ませない
+const CONTEST_SERIES_RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+
+// 未知のシリーズは他のインスタンスで追加された可能性があるため、読み込み直してから判定する
+pub(crate) async fn resolve_contest_series(
+    reg: &Registry,
+    series: &str,
+) -> Result<ContestSeries, HttpError> {
+    let parsed = reg.contest_series().parse(series);
+    if parsed.is_ok() || !reg.claim_contest_series_reload(CONTEST_SERIES_RELOAD_INTERVAL) {
+        return parsed.map_err(|e| HttpError::BadRequest(e.msg()));
+    }
+    load_contest_series(reg)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    reg.contest_series()
+        .parse(series)
+        .map_err(|e| HttpError::BadRequest(e.msg()))
+}
 
 pub async fn get_contests_by_series_handler(
     State(reg): State<Registry>,
@@ -14,9 +53,24 @@ pub async fn get_contests_by_series_handler(
     let service = reg.contest_service();
     let usecase = GetContestsBySeriesUseCase::new(service);
 
-    let series = ContestSeries::try_from(series).map_err(|e| HttpError::BadRequest(e.msg()))?;
+    let series = resolve_contest_series(&reg, &series).await?;
     let contests = usecase.run(series).await.map_err(|e| e.to_http_error())?;
     let resp: Vec<ContestResponse> = contests.into_iter().map(ContestResponse::from).collect();
 
     Ok(ApiResponse::ok(resp))
 }
+
+pub async fn add_contest_series_handler(
+    State(reg): State<Registry>,
+    _admin: AdminUser,
+    ApiJson(req): ApiJson<AddContestSeriesRequest>,
+) -> Result<ApiResponse<AddContestSeriesResponse>, HttpError> {
+    let usecase = AddContestSeriesUsecase::new(reg.contest_series_repository());
+    let output = usecase
+        .run(req.into())
+        .await
+        .map_err(|e| e.to_http_error())?;
+    reg.set_contest_series(output.catalog.clone());
+
+    Ok(ApiResponse::ok(output.into()))
+}
@@ -0,0 +1,100 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use domain::model::{personal_token::TokenScope, problem::DifficultyBand};
+use registry::Registry;
+use shared::{error::http::HttpError, response::ApiResponse};
+use usecase::explanation_request::{
+    list_mine::ListMyExplanationRequestsUsecase, list_wanted::ListWantedProblemsUsecase,
+    request::RequestExplanationUsecase, withdraw::WithdrawExplanationRequestUsecase,
+};
+
+use crate::{
+    error::ToHttpError,
+    handler::contest::resolve_contest_series,
+    http::{ApiQuery, AuthUser, require_scope},
+    model::explanation_request::{
+        list_my_explanation_requests::MyExplanationRequestResponse,
+        list_wanted_problems::{ListWantedProblemsRequestParams, WantedProblemResponse},
+        request_explanation::ExplanationRequestStatusResponse,
+    },
+};
+
+pub async fn request_explanation_handler(
+    State(registry): State<Registry>,
+    Path(problem_id): Path<String>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<ExplanationRequestStatusResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
+    let uc = RequestExplanationUsecase::new(
+        registry.explanation_request_repository(),
+        registry.solution_service(),
+    );
+    uc.run(user.uid, &problem_id)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    Ok(Json(ApiResponse::ok(
+        ExplanationRequestStatusResponse::requested(problem_id),
+    )))
+}
+
+pub async fn withdraw_explanation_request_handler(
+    State(registry): State<Registry>,
+    Path(problem_id): Path<String>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<ExplanationRequestStatusResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
+    let uc = WithdrawExplanationRequestUsecase::new(registry.explanation_request_repository());
+    uc.run(user.uid, &problem_id)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    Ok(Json(ApiResponse::ok(
+        ExplanationRequestStatusResponse::withdrawn(problem_id),
+    )))
+}
+
+pub async fn list_my_explanation_requests_handler(
+    State(registry): State<Registry>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<Vec<MyExplanationRequestResponse>>>, HttpError> {
+    require_scope(&user, TokenScope::Read)?;
+    let uc = ListMyExplanationRequestsUsecase::new(registry.explanation_request_repository());
+    let requests = uc.run(user.uid).await.map_err(|e| e.to_http_error())?;
+    let ret: Vec<_> = requests
+        .into_iter()
+        .map(MyExplanationRequestResponse::from)
+        .collect();
+    Ok(Json(ApiResponse::ok(ret)))
+}
+
+pub async fn list_wanted_problems_handler(
+    State(registry): State<Registry>,
+    Path(series): Path<String>,
+    ApiQuery(query): ApiQuery<ListWantedProblemsRequestParams>,
+) -> Result<Json<ApiResponse<Vec<WantedProblemResponse>>>, HttpError> {
+    let series = resolve_contest_series(&registry, &series).await?;
+    let band = query
+        .band
+        .as_deref()
+        .map(|band| {
+            band.parse::<DifficultyBand>().map_err(|_| {
+                HttpError::BadRequest(
+                    "band must be one of: gray, brown, green, cyan, blue, yellow, orange, red"
+                        .to_string(),
+                )
+            })
+        })
+        .transpose()?;
+
+    let uc = ListWantedProblemsUsecase::new(registry.explanation_request_repository());
+    let problems = uc
+        .run(series, band, query.limit, query.offset)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    let ret: Vec<_> = problems
+        .into_iter()
+        .map(WantedProblemResponse::from)
+        .collect();
+    Ok(Json(ApiResponse::ok(ret)))
+}
@@ -1,5 +1,10 @@
 use axum::{extract::State, http::StatusCode};
 use registry::Registry;
+use shared::response::ApiResponse;
+use tracing::warn;
+use usecase::health::readiness::CheckReadinessUsecase;
+
+use crate::model::health::{LivenessResponse, ReadinessResponse};
 
 pub async fn health_check(State(_registry): State<Registry>) -> StatusCode {
     StatusCode::OK
@@ -12,3 +17,37 @@ pub async fn health_check_db(State(registry): State<Registry>) -> StatusCode {
         StatusCode::INTERNAL_SERVER_ERROR
     }
 }
+
+pub async fn liveness_handler() -> ApiResponse<LivenessResponse> {
+    ApiResponse::ok(LivenessResponse { status: "ok" })
+}
+
+pub async fn readiness_handler(State(registry): State<Registry>) -> ApiResponse<ReadinessResponse> {
+    let usecase = CheckReadinessUsecase::new(
+        registry.health_check_repository(),
+        registry.problem_import_run_repository(),
+        registry.auth_port(),
+    );
+    let report = usecase.run().await;
+    if let Err(e) = &report.migrations {
+        warn!(error = ?e, "failed to read migration status");
+    }
+    if let Err(e) = &report.last_problem_import {
+        warn!(error = ?e, "failed to read problem import runs");
+    }
+
+    let mut response = ReadinessResponse::from(report);
+    if registry.is_shutting_down() {
+        response.ready = false;
+        response.shutting_down = true;
+    }
+    if response.ready {
+        return ApiResponse::ok(response);
+    }
+    ApiResponse::err_with_data(
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Service Unavailable",
+        "NOT_READY",
+        response,
+    )
+}
@@ -0,0 +1,21 @@
+use axum::{
+    Extension,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use metrics_exporter_prometheus::PrometheusHandle;
+use registry::Registry;
+
+pub async fn metrics_handler(
+    State(registry): State<Registry>,
+    Extension(handle): Extension<PrometheusHandle>,
+) -> Response {
+    // プール状態はスクレイプ時点の値を記録する
+    registry.record_pool_metrics();
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        handle.render(),
+    )
+        .into_response()
+}
@@ -1,5 +1,8 @@
 pub mod contest;
+pub mod explanation_request;
 pub mod health;
+pub mod metrics;
+pub mod personal_token;
 pub mod problem;
 pub mod solution;
 pub mod user;
@@ -0,0 +1,67 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use registry::Registry;
+use shared::{error::http::HttpError, response::ApiResponse};
+use usecase::personal_token::{
+    create::CreatePersonalTokenUsecase, list::ListPersonalTokensUsecase,
+    revoke::RevokePersonalTokenUsecase,
+};
+use uuid::Uuid;
+
+use crate::{
+    error::ToHttpError,
+    http::{ApiJson, AuthUser, require_id_token},
+    model::personal_token::{
+        create_personal_token::{
+            CreatePersonalTokenRequest, CreatePersonalTokenResponse, from_req_for_input,
+        },
+        list_personal_tokens::PersonalTokenResponse,
+        revoke_personal_token::RevokePersonalTokenResponse,
+    },
+};
+
+pub async fn create_personal_token_handler(
+    State(registry): State<Registry>,
+    AuthUser(user): AuthUser,
+    ApiJson(req): ApiJson<CreatePersonalTokenRequest>,
+) -> Result<Json<ApiResponse<CreatePersonalTokenResponse>>, HttpError> {
+    require_id_token(&user)?;
+    let uc = CreatePersonalTokenUsecase::new(
+        registry.id_provider_port(),
+        registry.personal_token_generator(),
+        registry.personal_token_repository(),
+    );
+    let input = from_req_for_input(user.uid, req);
+    let created = uc.run(input).await.map_err(|e| e.to_http_error())?;
+    Ok(Json(ApiResponse::ok(created.into())))
+}
+
+pub async fn list_personal_tokens_handler(
+    State(registry): State<Registry>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<Vec<PersonalTokenResponse>>>, HttpError> {
+    require_id_token(&user)?;
+    let uc = ListPersonalTokensUsecase::new(registry.personal_token_repository());
+    let tokens = uc.run(user.uid).await.map_err(|e| e.to_http_error())?;
+    let ret: Vec<_> = tokens
+        .into_iter()
+        .map(PersonalTokenResponse::from)
+        .collect();
+    Ok(Json(ApiResponse::ok(ret)))
+}
+
+pub async fn revoke_personal_token_handler(
+    State(registry): State<Registry>,
+    Path(token_id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<RevokePersonalTokenResponse>>, HttpError> {
+    require_id_token(&user)?;
+    let uc = RevokePersonalTokenUsecase::new(registry.personal_token_repository());
+    let revoked = uc
+        .run(user.uid, token_id)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    Ok(Json(ApiResponse::ok(revoked.into())))
+}
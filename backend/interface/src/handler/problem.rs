@@ -2,39 +2,70 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
 };
-use domain::model::problem::ContestSeries;
+use domain::model::problem::DifficultyRange;
 use registry::Registry;
 use shared::{error::http::HttpError, response::ApiResponse};
 use tracing::error;
+use usecase::model::problem::create::{ImportProblemsSettings, ImportProblemsUsecaseError};
 use usecase::problem::{
     create::ImportProblemsUsecase, get_by_contest::GetProblemsByContestUsecase,
     get_by_id::GetProblemByIdUsecase,
     get_contest_group_by_contest_series::GetContestGroupByContestSeriesUsecase,
+    get_summary::GetProblemSummaryUsecase, list_import_runs::ListProblemImportRunsUsecase,
+    resolve_url::ResolveProblemUrlUsecase,
 };
 
 use crate::error::ToHttpError;
-use crate::http::ApiQuery;
+use crate::handler::contest::resolve_contest_series;
+use crate::http::{AdminUser, ApiQuery};
 use crate::model::problem::{
     ProblemResponse,
+    get_by_contest::{ContestProblemResponse, contest_problem_responses},
     get_contest_group_by_contest_series::{
         ContestGroupPageResponse, ContestProblemGroupResponse,
         GetContestGroupByContestSeriesRequestParams,
     },
+    get_summary::ProblemSummaryResponse,
+    import_dry_run::ImportDryRunReportResponse,
+    list_import_runs::{ListProblemImportRunsRequestParams, ProblemImportRunResponse},
+    resolve_url::{ResolveProblemUrlRejection, ResolveProblemUrlRequestParams},
 };
 
 const MAX_CONTEST_GROUP_QUERY_LENGTH: usize = 100;
-const MAX_CONTEST_GROUP_OFFSET: usize = 5_000;
 
-pub async fn import_problem(reg: &Registry) -> StatusCode {
-    let atcoder_problems_port = reg.atcoder_problems_port();
-    let problem_repository = reg.problem_repository();
-    let problem_tx_manager = reg.problem_tx_manager();
-    let usecase = ImportProblemsUsecase::new(
-        atcoder_problems_port,
-        problem_repository,
-        problem_tx_manager,
-    );
+pub(crate) fn difficulty_range(
+    min_difficulty: Option<i32>,
+    max_difficulty: Option<i32>,
+) -> Result<DifficultyRange, HttpError> {
+    if let (Some(min), Some(max)) = (min_difficulty, max_difficulty)
+        && min > max
+    {
+        return Err(HttpError::BadRequest(
+            "minDifficulty must be at most maxDifficulty".to_string(),
+        ));
+    }
+    Ok(DifficultyRange::new(min_difficulty, max_difficulty))
+}
+
+// 定期ジョブと管理コマンドで同じ設定を使う
+pub fn import_problems_usecase(reg: &Registry) -> ImportProblemsUsecase {
+    let limits = reg.limits();
+    ImportProblemsUsecase::new(
+        reg.atcoder_problems_port(),
+        reg.problem_repository(),
+        reg.problem_tx_manager(),
+        reg.problem_import_run_repository(),
+        reg.contest_series_repository(),
+        ImportProblemsSettings {
+            chunk_size: limits.problem_upsert_chunk_size,
+            difficulty_fetch_concurrency: limits.difficulty_fetch_concurrency,
+            difficulty_fetch_budget: limits.difficulty_fetch_budget,
+        },
+    )
+}
 
+pub async fn import_problem(reg: &Registry) -> StatusCode {
+    let usecase = import_problems_usecase(reg);
     match usecase.run().await {
         Ok(_) => StatusCode::OK,
         Err(e) => {
@@ -44,10 +75,17 @@ pub async fn import_problem(reg: &Registry) -> StatusCode {
     }
 }
 
+pub async fn import_problem_dry_run(
+    reg: &Registry,
+) -> Result<ImportDryRunReportResponse, ImportProblemsUsecaseError> {
+    let report = import_problems_usecase(reg).dry_run().await?;
+    Ok(report.into())
+}
+
 pub async fn get_problems_by_contest_handler(
     State(reg): State<Registry>,
     Path(contest_code): Path<String>,
-) -> Result<ApiResponse<Vec<ProblemResponse>>, HttpError> {
+) -> Result<ApiResponse<Vec<ContestProblemResponse>>, HttpError> {
     let contest = contest_code.trim();
     if contest.is_empty() {
         return Err(HttpError::BadRequest(
@@ -58,9 +96,8 @@ pub async fn get_problems_by_contest_handler(
     let problems_repository = reg.problem_repository();
     let usecase = GetProblemsByContestUsecase::new(problems_repository);
     let problems = usecase.run(contest).await.map_err(|e| e.to_http_error())?;
-    let resp: Vec<ProblemResponse> = problems.into_iter().map(ProblemResponse::from).collect();
 
-    Ok(ApiResponse::ok(resp))
+    Ok(ApiResponse::ok(contest_problem_responses(problems)))
 }
 
 pub async fn get_problem_by_id_handler(
@@ -84,6 +121,29 @@ pub async fn get_problem_by_id_handler(
     Ok(ApiResponse::ok(ProblemResponse::from(problem)))
 }
 
+pub async fn resolve_problem_url_handler(
+    State(reg): State<Registry>,
+    ApiQuery(query): ApiQuery<ResolveProblemUrlRequestParams>,
+) -> Result<ApiResponse<ProblemResponse>, ResolveProblemUrlRejection> {
+    let usecase = ResolveProblemUrlUsecase::new(reg.problem_repository());
+    let problem = usecase.run(&query.url).await?;
+
+    Ok(ApiResponse::ok(ProblemResponse::from(problem)))
+}
+
+pub async fn get_problem_summary_handler(
+    State(reg): State<Registry>,
+    Path(problem_id): Path<String>,
+) -> Result<ApiResponse<ProblemSummaryResponse>, HttpError> {
+    let usecase = GetProblemSummaryUsecase::new(reg.problem_repository(), reg.solution_service());
+    let summary = usecase
+        .run(&problem_id)
+        .await
+        .map_err(|e| e.to_http_error())?;
+
+    Ok(ApiResponse::ok(ProblemSummaryResponse::from(summary)))
+}
+
 pub async fn get_contest_group_by_contest_series_handler(
     State(reg): State<Registry>,
     Path(series): Path<String>,
@@ -91,7 +151,7 @@ pub async fn get_contest_group_by_contest_series_handler(
 ) -> Result<ApiResponse<ContestGroupPageResponse>, HttpError> {
     let problems_repository = reg.problem_repository();
     let usecase = GetContestGroupByContestSeriesUsecase::new(problems_repository);
-    let series = ContestSeries::try_from(series).map_err(|e| HttpError::BadRequest(e.msg()))?;
+    let series = resolve_contest_series(&reg, &series).await?;
     if query
         .q
         .as_ref()
@@ -101,17 +161,16 @@ pub async fn get_contest_group_by_contest_series_handler(
             "q must be at most {MAX_CONTEST_GROUP_QUERY_LENGTH} characters"
         )));
     }
-    if query
-        .offset
-        .is_some_and(|offset| offset > MAX_CONTEST_GROUP_OFFSET)
-    {
+    let difficulty = difficulty_range(query.min_difficulty, query.max_difficulty)?;
+    let max_offset = reg.limits().max_contest_group_offset;
+    if query.offset.is_some_and(|offset| offset > max_offset) {
         return Err(HttpError::BadRequest(format!(
-            "offset must be at most {MAX_CONTEST_GROUP_OFFSET}"
+            "offset must be at most {max_offset}"
         )));
     }
 
     let page = usecase
-        .run(series, query.q, query.limit, query.offset)
+        .run(series, query.q, difficulty, query.limit, query.offset)
         .await
         .map_err(|e| e.to_http_error())?;
     let groups = page
@@ -119,7 +178,7 @@ pub async fn get_contest_group_by_contest_series_handler(
         .0
         .into_iter()
         .map(|(contest_id, problems)| ContestProblemGroupResponse {
-            contest_id: contest_id.0,
+            contest_id: contest_id.0.code,
             problems: problems
                 .into_iter()
                 .map(ProblemResponse::from)
@@ -133,3 +192,49 @@ pub async fn get_contest_group_by_contest_series_handler(
         total_contest_count: page.total_contest_count,
     }))
 }
+
+pub async fn list_problem_import_runs_handler(
+    State(reg): State<Registry>,
+    _admin: AdminUser,
+    ApiQuery(query): ApiQuery<ListProblemImportRunsRequestParams>,
+) -> Result<ApiResponse<Vec<ProblemImportRunResponse>>, HttpError> {
+    let usecase = ListProblemImportRunsUsecase::new(reg.problem_import_run_repository());
+    let runs = usecase
+        .run(query.limit)
+        .await
+        .map_err(|e| e.to_http_error())?;
+
+    Ok(ApiResponse::ok(
+        runs.into_iter()
+            .map(ProblemImportRunResponse::from)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::model::problem::DifficultyRange;
+    use shared::error::http::HttpError;
+
+    use super::difficulty_range;
+
+    #[test]
+    fn difficulty_range_accepts_open_and_ordered_bounds() {
+        assert_eq!(
+            difficulty_range(None, None).expect("valid"),
+            DifficultyRange::default()
+        );
+        assert_eq!(
+            difficulty_range(Some(800), Some(800)).expect("valid"),
+            DifficultyRange::new(Some(800), Some(800))
+        );
+    }
+
+    #[test]
+    fn difficulty_range_rejects_reversed_bounds() {
+        assert!(matches!(
+            difficulty_range(Some(1600), Some(1200)),
+            Err(HttpError::BadRequest(message)) if message == "minDifficulty must be at most maxDifficulty"
+        ));
+    }
+}
@@ -2,24 +2,28 @@ use axum::{
     Json,
     extract::{Path, State},
 };
+use domain::model::personal_token::TokenScope;
 use registry::Registry;
 use shared::{error::http::HttpError, response::ApiResponse};
 use usecase::model::solution::create_comment::CreateCommentInput;
 use usecase::solution::{
     create::CreateSolutionUsecase, create_comment::CreateCommentUsecase,
     delete::DeleteSolutionUsecase, delete_comment::DeleteCommentUsecase,
+    get_by_problem_appearance::GetSolutionsByProblemAppearanceUsecase,
     get_by_problem_id::GetSolutionsByProblemIdUsecase,
     get_by_solution_id::GetSolutionBySolutionIdUsecase,
     get_by_user_name::GetSolutionsByUserNameUsecase,
     get_comments_by_solution_id::GetCommentsBySolutionIdUsecase,
     get_latest::GetLatestSolutionsUsecase, get_my_vote_status::GetMyVoteStatusUsecase,
+    get_stats_by_problem_ids::GetSolutionStatsByProblemIdsUsecase,
     get_votes_count::GetSolutionVotesCountUsecase, unvote::UnvoteSolutionUsecase,
     update::UpdateSolutionUsecase, update_comment::UpdateCommentUsecase, vote::VoteSolutionUsecase,
 };
 
 use crate::{
     error::ToHttpError,
-    http::{ApiJson, ApiQuery, AuthUser},
+    handler::problem::difficulty_range,
+    http::{ApiJson, ApiQuery, AuthUser, require_scope},
     model::solution::{
         create_comment::{CreateCommentRequest, CreateCommentResponse},
         create_solution::{CreateSolutionRequest, CreateSolutionResponse, from_req_for_input},
@@ -29,6 +33,7 @@ use crate::{
         get_latest_solutions::{GetLatestSolutionsRequest, GetLatestSolutionsResponse},
         get_my_vote_status::GetMyVoteStatusResponse,
         get_solution_by_solution_id::GetSolutionBySolutionIdResponse,
+        get_solution_stats::{GetSolutionStatsRequest, GetSolutionStatsResponse},
         get_solution_votes_count::GetSolutionVotesCountResponse,
         get_solutions_by_problem_id::{
             GetSolutionsByProblemIdRequest, GetSolutionsByProblemIdResponse,
@@ -71,6 +76,7 @@ pub async fn create_solution_handler(
     AuthUser(user): AuthUser,
     ApiJson(req): ApiJson<CreateSolutionRequest>,
 ) -> Result<Json<ApiResponse<CreateSolutionResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
     let user_id = user.uid;
     let repo = CreateSolutionUsecase::new(
         registry.id_provider_port(),
@@ -89,6 +95,7 @@ pub async fn update_solution_handler(
     AuthUser(user): AuthUser,
     ApiJson(req): ApiJson<UpdateSolutionRequest>,
 ) -> Result<Json<ApiResponse<UpdateSolutionResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
     let uc =
         UpdateSolutionUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     let input = from_req_for_update_solution(user.uid, solution_id, req);
@@ -101,6 +108,7 @@ pub async fn delete_solution_handler(
     Path(solution_id): Path<Uuid>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<DeleteSolutionResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
     let uc =
         DeleteSolutionUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     let deleted_id = uc
@@ -121,15 +129,7 @@ pub async fn get_solutions_by_problems_id_handler(
             "problem_id cannot be empty".to_string(),
         ));
     }
-    let sort = match req.sort_by.as_deref() {
-        None | Some("latest") => usecase::model::solution::SolutionListSort::Latest,
-        Some("votes") => usecase::model::solution::SolutionListSort::Votes,
-        Some(_) => {
-            return Err(HttpError::BadRequest(
-                "sort_by must be one of: latest, votes".to_string(),
-            ));
-        }
-    };
+    let sort = problem_solutions_sort(&req)?;
     let limit = validate_limit(req.limit)?;
     let solutions = uc
         .run(problem_id.to_string(), sort, limit)
@@ -142,6 +142,58 @@ pub async fn get_solutions_by_problems_id_handler(
     Ok(Json(ApiResponse::ok(ret)))
 }
 
+// ADTなど他のコンテストでの出題からも元の問題の解説一覧を返す
+pub async fn get_solutions_by_problem_appearance_handler(
+    State(registry): State<Registry>,
+    Path((contest_code, problem_index)): Path<(String, String)>,
+    ApiQuery(req): ApiQuery<GetSolutionsByProblemIdRequest>,
+) -> Result<Json<ApiResponse<Vec<GetSolutionsByProblemIdResponse>>>, HttpError> {
+    let uc = GetSolutionsByProblemAppearanceUsecase::new(
+        registry.problem_repository(),
+        registry.solution_service(),
+    );
+    let sort = problem_solutions_sort(&req)?;
+    let limit = validate_limit(req.limit)?;
+    let solutions = uc
+        .run(&contest_code, &problem_index, sort, limit)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    let ret: Vec<_> = solutions
+        .into_iter()
+        .map(GetSolutionsByProblemIdResponse::from)
+        .collect();
+    Ok(Json(ApiResponse::ok(ret)))
+}
+
+// 問題一覧に解説数などを出すため、複数の問題の集計をまとめて返す
+pub async fn get_solution_stats_handler(
+    State(registry): State<Registry>,
+    ApiJson(req): ApiJson<GetSolutionStatsRequest>,
+) -> Result<Json<ApiResponse<Vec<GetSolutionStatsResponse>>>, HttpError> {
+    let uc = GetSolutionStatsByProblemIdsUsecase::new(registry.solution_service());
+    let stats = uc
+        .run(req.problem_ids)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    let ret: Vec<_> = stats
+        .into_iter()
+        .map(GetSolutionStatsResponse::from)
+        .collect();
+    Ok(Json(ApiResponse::ok(ret)))
+}
+
+fn problem_solutions_sort(
+    req: &GetSolutionsByProblemIdRequest,
+) -> Result<usecase::model::solution::SolutionListSort, HttpError> {
+    match req.sort_by.as_deref() {
+        None | Some("latest") => Ok(usecase::model::solution::SolutionListSort::Latest),
+        Some("votes") => Ok(usecase::model::solution::SolutionListSort::Votes),
+        Some(_) => Err(HttpError::BadRequest(
+            "sort_by must be one of: latest, votes".to_string(),
+        )),
+    }
+}
+
 pub async fn get_latest_solutions_handler(
     State(registry): State<Registry>,
     ApiQuery(req): ApiQuery<GetLatestSolutionsRequest>,
@@ -150,7 +202,11 @@ pub async fn get_latest_solutions_handler(
 
     let uc = GetLatestSolutionsUsecase::new(registry.solution_service());
     let limit = validate_limit(req.limit)?;
-    let solutions = uc.run(limit).await.map_err(|e| e.to_http_error())?;
+    let difficulty = difficulty_range(req.min_difficulty, req.max_difficulty)?;
+    let solutions = uc
+        .run(limit, difficulty)
+        .await
+        .map_err(|e| e.to_http_error())?;
     let ret: Vec<_> = solutions
         .into_iter()
         .map(GetLatestSolutionsResponse::from)
@@ -176,9 +232,10 @@ pub async fn get_solutions_by_user_name_handler(
             "sort_by must be one of: latest, votes".to_string(),
         ));
     }
+    let difficulty = difficulty_range(req.min_difficulty, req.max_difficulty)?;
 
     let solutions = uc
-        .run(user_name.to_string(), req.list_sort())
+        .run(user_name.to_string(), req.list_sort(), difficulty)
         .await
         .map_err(|e| e.to_http_error())?;
     let ret: Vec<_> = solutions
@@ -202,6 +259,7 @@ pub async fn vote_solution_handler(
     Path(solution_id): Path<Uuid>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<VoteSolutionResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
     let uc = VoteSolutionUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     uc.run(user.uid, solution_id)
         .await
@@ -216,6 +274,7 @@ pub async fn unvote_solution_handler(
     Path(solution_id): Path<Uuid>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<UnvoteSolutionResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteSolutions)?;
     let uc =
         UnvoteSolutionUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     uc.run(user.uid, solution_id)
@@ -243,6 +302,7 @@ pub async fn get_my_vote_status_handler(
     Path(solution_id): Path<Uuid>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<GetMyVoteStatusResponse>>, HttpError> {
+    require_scope(&user, TokenScope::Read)?;
     let uc = GetMyVoteStatusUsecase::new(registry.solution_service());
     let liked = uc
         .run(user.uid, solution_id)
@@ -260,6 +320,7 @@ pub async fn create_comment_handler(
     AuthUser(user): AuthUser,
     ApiJson(req): ApiJson<CreateCommentRequest>,
 ) -> Result<Json<ApiResponse<CreateCommentResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteComments)?;
     let uc = CreateCommentUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     let input = CreateCommentInput {
         user_id: user.uid,
@@ -276,6 +337,7 @@ pub async fn update_comment_handler(
     AuthUser(user): AuthUser,
     ApiJson(req): ApiJson<UpdateCommentRequest>,
 ) -> Result<Json<ApiResponse<UpdateCommentResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteComments)?;
     let uc = UpdateCommentUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     let input = from_req_for_update_comment(user.uid, comment_id, req);
     let updated = uc.run(input).await.map_err(|e| e.to_http_error())?;
@@ -287,6 +349,7 @@ pub async fn delete_comment_handler(
     Path(comment_id): Path<Uuid>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<DeleteCommentResponse>>, HttpError> {
+    require_scope(&user, TokenScope::WriteComments)?;
     let uc = DeleteCommentUsecase::new(registry.solution_tx_manager(), registry.solution_service());
     let deleted_id = uc
         .run(user.uid, comment_id)
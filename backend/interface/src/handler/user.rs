@@ -1,19 +1,27 @@
-use axum::{Json, extract::State};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use domain::model::personal_token::TokenScope;
 use registry::Registry;
 use shared::{error::http::HttpError, response::ApiResponse};
 use usecase::user::{
     create_user::CreateUserUsecase, delete_me::DeleteMeUsecase, get_me::GetMeUsecase,
+    list_sessions::ListSessionsUsecase, revoke_session::RevokeSessionUsecase,
     revoke_tokens::RevokeTokensUsecase,
 };
+use uuid::Uuid;
 
 use crate::{
     error::ToHttpError,
-    http::{ApiJson, AuthUser, VerifiedUser},
+    http::{ApiJson, AuthUser, VerifiedUser, require_id_token, require_scope},
     model::user::create_user::{
         CreateUserRequest, CreateUserResponse, try_from_create_user_request_for_create_user_input,
     },
     model::user::delete_me::DeleteMeResponse,
     model::user::get_me::GetMeResponse,
+    model::user::list_sessions::SessionResponse,
+    model::user::revoke_session::RevokeSessionResponse,
     model::user::revoke_tokens::RevokeTokensResponse,
 };
 
@@ -38,6 +46,7 @@ pub async fn get_me_handler(
     State(registry): State<Registry>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<GetMeResponse>>, HttpError> {
+    require_scope(&user, TokenScope::Read)?;
     let uc = GetMeUsecase::new(registry.user_repository());
     let me = uc.run(user.uid).await.map_err(|e| e.to_http_error())?;
     Ok(Json(ApiResponse::ok(me.into())))
@@ -47,6 +56,7 @@ pub async fn delete_me_handler(
     State(registry): State<Registry>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<DeleteMeResponse>>, HttpError> {
+    require_id_token(&user)?;
     let uc = DeleteMeUsecase::new(registry.user_repository());
     let deleted = uc.run(user.uid).await.map_err(|e| e.to_http_error())?;
     Ok(Json(ApiResponse::ok(deleted.into())))
@@ -56,7 +66,36 @@ pub async fn revoke_tokens_handler(
     State(registry): State<Registry>,
     AuthUser(user): AuthUser,
 ) -> Result<Json<ApiResponse<RevokeTokensResponse>>, HttpError> {
-    let uc = RevokeTokensUsecase::new(registry.user_repository());
+    require_id_token(&user)?;
+    let uc = RevokeTokensUsecase::new(registry.user_repository(), registry.session_repository());
     let revoked = uc.run(user.uid).await.map_err(|e| e.to_http_error())?;
     Ok(Json(ApiResponse::ok(revoked.into())))
 }
+
+pub async fn list_sessions_handler(
+    State(registry): State<Registry>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<Vec<SessionResponse>>>, HttpError> {
+    require_id_token(&user)?;
+    let uc = ListSessionsUsecase::new(registry.session_repository());
+    let sessions = uc
+        .run(user.uid, user.authenticated_at)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    let ret: Vec<_> = sessions.into_iter().map(SessionResponse::from).collect();
+    Ok(Json(ApiResponse::ok(ret)))
+}
+
+pub async fn revoke_session_handler(
+    State(registry): State<Registry>,
+    Path(session_id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<Json<ApiResponse<RevokeSessionResponse>>, HttpError> {
+    require_id_token(&user)?;
+    let uc = RevokeSessionUsecase::new(registry.session_repository());
+    let revoked = uc
+        .run(user.uid, session_id)
+        .await
+        .map_err(|e| e.to_http_error())?;
+    Ok(Json(ApiResponse::ok(revoked.into())))
+}
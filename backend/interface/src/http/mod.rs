@@ -1,14 +1,22 @@
+use std::net::SocketAddr;
+
 use axum::{
-    extract::{FromRequest, FromRequestParts, Json, Query, Request},
+    extract::{ConnectInfo, FromRequest, FromRequestParts, Json, Query, Request},
     http::{StatusCode, header, request::Parts},
     response::IntoResponse,
 };
+use chrono::{DateTime, TimeDelta, Utc};
 use domain::{
-    model::user::Role,
+    model::{
+        personal_token::{PERSONAL_TOKEN_PREFIX, TokenScope},
+        session::SessionActivity,
+        user::Role,
+    },
     ports::external::auth::{AuthError, Principal},
 };
 use serde::de::DeserializeOwned;
 use shared::{error::http::HttpError, response::ApiResponse};
+use tracing::warn;
 
 use registry::Registry;
 
@@ -57,7 +65,14 @@ impl FromRequestParts<Registry> for AuthUser {
             .strip_prefix("Bearer ")
             .ok_or(AuthRejection::Unauthorized)?;
 
-        match state.auth_port().verify_id_token(token).await {
+        // Personal Access TokenはprefixでFirebase ID tokenと見分ける
+        let authenticator = if token.starts_with(PERSONAL_TOKEN_PREFIX) {
+            state.personal_token_auth_port()
+        } else {
+            state.auth_port()
+        };
+
+        match authenticator.verify_id_token(token).await {
             Ok(p) => {
                 let revoked = state
                     .user_repository()
@@ -67,6 +82,9 @@ impl FromRequestParts<Registry> for AuthUser {
                 if revoked {
                     return Err(AuthRejection::Unauthorized);
                 }
+                if !p.is_personal_token() {
+                    track_session(parts, state, &p).await?;
+                }
                 Ok(AuthUser(p))
             }
             Err(AuthError::Unauthorized) => Err(AuthRejection::Unauthorized),
@@ -75,6 +93,88 @@ impl FromRequestParts<Registry> for AuthUser {
     }
 }
 
+// 最終アクセスの書き込みはこの間隔より空いた時だけ行う
+const SESSION_TOUCH_INTERVAL_MINUTES: i64 = 5;
+
+// 端末ごとに失効できるよう、サインイン単位でアクセス元を記録する。
+// 失効の確認は読み取りだけで済ませ、記録に失敗しても要求は通す
+async fn track_session(
+    parts: &Parts,
+    state: &Registry,
+    principal: &Principal,
+) -> Result<(), AuthRejection> {
+    let authenticated_at = DateTime::from_timestamp(principal.authenticated_at, 0)
+        .ok_or(AuthRejection::Unauthorized)?;
+    let sessions = state.session_repository();
+    let status = sessions
+        .find_status(&principal.uid, authenticated_at)
+        .await
+        .map_err(|_| AuthRejection::Unavailable)?;
+    match status {
+        Some(status) if status.revoked => return Err(AuthRejection::Unauthorized),
+        Some(status)
+            if !status.needs_touch(
+                Utc::now(),
+                TimeDelta::minutes(SESSION_TOUCH_INTERVAL_MINUTES),
+            ) =>
+        {
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let activity = SessionActivity {
+        user_id: principal.uid.to_owned(),
+        authenticated_at,
+        user_agent: user_agent(parts),
+        ip_address: client_ip(parts, state.trusted_proxy_hops()),
+    };
+    match sessions.touch(&activity).await {
+        Ok(touched) if touched.revoked => Err(AuthRejection::Unauthorized),
+        Ok(_) => Ok(()),
+        Err(error) => {
+            warn!(error = ?error, "failed to record session activity");
+            Ok(())
+        }
+    }
+}
+
+const MAX_USER_AGENT_LEN: usize = 512;
+
+fn user_agent(parts: &Parts) -> Option<String> {
+    parts
+        .headers
+        .get(header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.chars().take(MAX_USER_AGENT_LEN).collect())
+}
+
+// X-Forwarded-Forはクライアントが自由に書けるため、信用するプロキシが付け足した末尾の分だけを見る。
+// プロキシがn段なら右からn番目がクライアント
+fn client_ip(parts: &Parts, trusted_proxy_hops: usize) -> Option<String> {
+    let forwarded = trusted_proxy_hops
+        .checked_sub(1)
+        .and_then(|skip| {
+            parts
+                .headers
+                .get_all("x-forwarded-for")
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .map(str::trim)
+                .rev()
+                .nth(skip)
+                .map(str::to_string)
+        })
+        .filter(|v| !v.is_empty());
+    forwarded.or_else(|| {
+        parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+    })
+}
+
 impl FromRequestParts<Registry> for VerifiedUser {
     type Rejection = AuthRejection;
     async fn from_request_parts(
@@ -105,6 +205,9 @@ impl FromRequestParts<Registry> for AdminUser {
         state: &Registry,
     ) -> Result<Self, Self::Rejection> {
         let AuthUser(principal) = AuthUser::from_request_parts(parts, state).await?;
+        if principal.is_personal_token() {
+            return Err(AuthRejection::Forbidden);
+        }
         let user = state
             .user_repository()
             .find_by_uid(principal.uid.as_str())
@@ -120,6 +223,26 @@ impl FromRequestParts<Registry> for AdminUser {
     }
 }
 
+pub fn require_scope(principal: &Principal, scope: TokenScope) -> Result<(), HttpError> {
+    if principal.has_scope(scope) {
+        return Ok(());
+    }
+    Err(HttpError::Forbidden(format!(
+        "personal token lacks scope: {}",
+        scope
+    )))
+}
+
+// トークン管理やアカウント操作はブラウザからのID tokenに限る
+pub fn require_id_token(principal: &Principal) -> Result<(), HttpError> {
+    if principal.is_personal_token() {
+        return Err(HttpError::Forbidden(
+            "personal token cannot be used for this operation".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl<S, T> FromRequest<S> for ApiJson<T>
 where
     S: Send + Sync,
@@ -149,3 +272,66 @@ where
             .map_err(|e| HttpError::BadRequest(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use axum::{
+        extract::ConnectInfo,
+        http::{Request, header, request::Parts},
+    };
+
+    use super::{client_ip, user_agent};
+
+    fn parts_with_forwarded_for(forwarded_for: &str) -> Parts {
+        let (mut parts, _) = Request::get("/")
+            .header("x-forwarded-for", forwarded_for)
+            .body(())
+            .expect("request")
+            .into_parts();
+        parts
+            .extensions
+            .insert(ConnectInfo("10.0.0.2:443".parse::<SocketAddr>().unwrap()));
+        parts
+    }
+
+    #[test]
+    fn client_ip_ignores_forwarded_for_without_trusted_proxies() {
+        let parts = parts_with_forwarded_for("203.0.113.7");
+        assert_eq!(client_ip(&parts, 0).as_deref(), Some("10.0.0.2"));
+    }
+
+    #[test]
+    fn client_ip_takes_the_hop_added_by_the_outermost_trusted_proxy() {
+        // クライアントが先頭に偽のアドレスを入れても、プロキシが付け足した分は偽れない
+        let parts = parts_with_forwarded_for("198.51.100.1, 203.0.113.7, 10.0.0.1");
+        assert_eq!(client_ip(&parts, 1).as_deref(), Some("10.0.0.1"));
+        assert_eq!(client_ip(&parts, 2).as_deref(), Some("203.0.113.7"));
+
+        // ヘッダーが短ければ接続元を使う
+        assert_eq!(client_ip(&parts, 4).as_deref(), Some("10.0.0.2"));
+    }
+
+    #[test]
+    fn client_ip_falls_back_to_peer_address() {
+        let (mut parts, _) = Request::get("/").body(()).expect("request").into_parts();
+        assert_eq!(client_ip(&parts, 1), None);
+
+        parts
+            .extensions
+            .insert(ConnectInfo("10.0.0.2:443".parse::<SocketAddr>().unwrap()));
+        assert_eq!(client_ip(&parts, 1).as_deref(), Some("10.0.0.2"));
+    }
+
+    #[test]
+    fn user_agent_is_truncated() {
+        let (parts, _) = Request::get("/")
+            .header(header::USER_AGENT, "a".repeat(1000))
+            .body(())
+            .expect("request")
+            .into_parts();
+
+        assert_eq!(user_agent(&parts).map(|v| v.len()), Some(512));
+    }
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use usecase::model::contests::add_series::{AddContestSeriesInput, AddContestSeriesOutput};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddContestSeriesRequest {
+    pub code: String,
+    pub display_name: String,
+    pub code_pattern: String,
+    pub sort_order: i32,
+}
+
+impl From<AddContestSeriesRequest> for AddContestSeriesInput {
+    fn from(value: AddContestSeriesRequest) -> Self {
+        Self {
+            code: value.code,
+            display_name: value.display_name,
+            code_pattern: value.code_pattern,
+            sort_order: value.sort_order,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestSeriesResponse {
+    pub code: String,
+    pub display_name: String,
+    pub code_pattern: Option<String>,
+    pub sort_order: i32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestReclassificationResponse {
+    pub contest_code: String,
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddContestSeriesResponse {
+    pub series: ContestSeriesResponse,
+    pub reclassified: Vec<ContestReclassificationResponse>,
+}
+
+impl From<AddContestSeriesOutput> for AddContestSeriesResponse {
+    fn from(value: AddContestSeriesOutput) -> Self {
+        Self {
+            series: ContestSeriesResponse {
+                code: value.definition.code.into(),
+                display_name: value.definition.display_name,
+                code_pattern: value.definition.code_pattern,
+                sort_order: value.definition.sort_order,
+            },
+            reclassified: value
+                .reclassified
+                .into_iter()
+                .map(|r| ContestReclassificationResponse {
+                    contest_code: r.contest_code,
+                    from: r.from.into(),
+                    to: r.to.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::model::problem::{
+        ContestReclassification, ContestSeries, ContestSeriesCatalog, ContestSeriesDefinition,
+    };
+    use usecase::model::contests::add_series::AddContestSeriesOutput;
+
+    use super::{AddContestSeriesRequest, AddContestSeriesResponse};
+
+    #[test]
+    fn request_is_camel_case() {
+        let req: AddContestSeriesRequest = serde_json::from_value(serde_json::json!({
+            "code": "PAST",
+            "displayName": "PAST",
+            "codePattern": "^past\\d+",
+            "sortOrder": 60,
+        }))
+        .unwrap();
+        assert_eq!(req.code_pattern, "^past\\d+");
+        assert_eq!(req.sort_order, 60);
+    }
+
+    #[test]
+    fn response_lists_reclassified_contests() {
+        let definition = ContestSeriesDefinition {
+            code: ContestSeries::new("PAST"),
+            display_name: "PAST".into(),
+            code_pattern: Some("^past\\d+".into()),
+            sort_order: 60,
+        };
+        let resp = AddContestSeriesResponse::from(AddContestSeriesOutput {
+            catalog: ContestSeriesCatalog::new(vec![definition.clone()]).unwrap(),
+            definition,
+            reclassified: vec![ContestReclassification {
+                contest_code: "past202412".into(),
+                from: ContestSeries::OTHER,
+                to: ContestSeries::new("PAST"),
+            }],
+        });
+
+        assert_eq!(
+            serde_json::to_value(&resp).unwrap(),
+            serde_json::json!({
+                "series": {
+                    "code": "PAST",
+                    "displayName": "PAST",
+                    "codePattern": "^past\\d+",
+                    "sortOrder": 60,
+                },
+                "reclassified": [
+                    { "contestCode": "past202412", "from": "OTHER", "to": "PAST" },
+                ],
+            })
+        );
+    }
+}
@@ -1,11 +1,19 @@
+use chrono::{DateTime, Utc};
+use domain::model::problem::ContestMetadata;
 use serde::Serialize;
 use usecase::dto::contests::ContestListItemView;
 
+pub mod add_series;
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContestResponse {
     pub code: String,
     pub series_code: String,
+    pub title: Option<String>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub duration_second: Option<i64>,
+    pub rate_change: Option<String>,
 }
 
 impl From<ContestListItemView> for ContestResponse {
@@ -13,6 +21,32 @@ impl From<ContestListItemView> for ContestResponse {
         Self {
             code: value.code,
             series_code: value.series_code,
+            title: value.title,
+            start_at: value.start_at,
+            duration_second: value.duration_second,
+            rate_change: value.rate_change,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestMetadataResponse {
+    pub code: String,
+    pub title: String,
+    pub start_at: DateTime<Utc>,
+    pub duration_second: i64,
+    pub rate_change: String,
+}
+
+impl From<ContestMetadata> for ContestMetadataResponse {
+    fn from(value: ContestMetadata) -> Self {
+        Self {
+            code: value.code,
+            title: value.title,
+            start_at: value.start_at,
+            duration_second: value.duration_second,
+            rate_change: value.rate_change,
         }
     }
 }
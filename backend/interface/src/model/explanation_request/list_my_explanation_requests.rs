@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use domain::model::explanation_request::ExplanationRequest;
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyExplanationRequestResponse {
+    pub problem_id: String,
+    pub fulfilled_solution_id: Option<Uuid>,
+    pub fulfilled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ExplanationRequest> for MyExplanationRequestResponse {
+    fn from(value: ExplanationRequest) -> Self {
+        Self {
+            problem_id: value.problem_id,
+            fulfilled_solution_id: value.fulfilled_solution_id,
+            fulfilled_at: value.fulfilled_at,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use domain::model::explanation_request::WantedProblem;
+use serde::{Deserialize, Serialize};
+
+use crate::model::problem::ProblemResponse;
+
+#[derive(Deserialize)]
+pub struct ListWantedProblemsRequestParams {
+    pub band: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WantedProblemResponse {
+    pub problem: ProblemResponse,
+    pub request_count: i64,
+    pub last_requested_at: DateTime<Utc>,
+}
+
+impl From<WantedProblem> for WantedProblemResponse {
+    fn from(value: WantedProblem) -> Self {
+        Self {
+            problem: value.problem.into(),
+            request_count: value.request_count,
+            last_requested_at: value.last_requested_at,
+        }
+    }
+}
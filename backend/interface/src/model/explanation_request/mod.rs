@@ -0,0 +1,3 @@
+pub mod list_my_explanation_requests;
+pub mod list_wanted_problems;
+pub mod request_explanation;
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplanationRequestStatusResponse {
+    pub problem_id: String,
+    pub requested: bool,
+}
+
+impl ExplanationRequestStatusResponse {
+    pub fn requested(problem_id: String) -> Self {
+        Self {
+            problem_id,
+            requested: true,
+        }
+    }
+
+    pub fn withdrawn(problem_id: String) -> Self {
+        Self {
+            problem_id,
+            requested: false,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    model::{health::MigrationStatus, problem_import::ProblemImportRun},
+    ports::external::auth::KeySetStatus,
+};
+use serde::Serialize;
+use usecase::model::health::{DatabaseCheck, KeySetCheck, ReadinessReport};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LivenessResponse {
+    pub status: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    pub shutting_down: bool,
+    pub database: DatabaseCheckResponse,
+    pub migrations: MigrationCheckResponse,
+    pub jwks: JwksCheckResponse,
+    pub last_problem_import: ProblemImportCheckResponse,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseCheckResponse {
+    pub ok: bool,
+    pub latency_ms: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationCheckResponse {
+    pub ok: bool,
+    pub applied_version: Option<i64>,
+    pub expected_version: Option<i64>,
+    pub pending_versions: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwksCheckResponse {
+    pub fresh: bool,
+    pub state: &'static str,
+    pub fetched_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemImportCheckResponse {
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub outcome: Option<String>,
+    pub problem_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<ReadinessReport> for ReadinessResponse {
+    fn from(value: ReadinessReport) -> Self {
+        Self {
+            ready: value.is_ready(),
+            shutting_down: false,
+            database: value.database.into(),
+            migrations: match value.migrations {
+                Ok(status) => status.into(),
+                Err(_) => MigrationCheckResponse {
+                    ok: false,
+                    applied_version: None,
+                    expected_version: None,
+                    pending_versions: vec![],
+                    error: Some("failed to read migration status".into()),
+                },
+            },
+            jwks: value.jwks.into(),
+            last_problem_import: match value.last_problem_import {
+                Ok(run) => run.into(),
+                Err(_) => ProblemImportCheckResponse {
+                    started_at: None,
+                    finished_at: None,
+                    outcome: None,
+                    problem_count: None,
+                    error: Some("failed to read problem import runs".into()),
+                },
+            },
+        }
+    }
+}
+
+impl From<DatabaseCheck> for DatabaseCheckResponse {
+    fn from(value: DatabaseCheck) -> Self {
+        Self {
+            ok: value.reachable,
+            latency_ms: value.latency.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+impl From<MigrationStatus> for MigrationCheckResponse {
+    fn from(value: MigrationStatus) -> Self {
+        Self {
+            ok: !value.is_behind(),
+            applied_version: value.applied_version,
+            expected_version: value.expected_version,
+            pending_versions: value.pending_versions,
+            error: None,
+        }
+    }
+}
+
+impl From<KeySetCheck> for JwksCheckResponse {
+    fn from(value: KeySetCheck) -> Self {
+        let (state, fetched_at, expires_at) = match value.status {
+            KeySetStatus::NotApplicable => ("not_applicable", None, None),
+            KeySetStatus::NotLoaded => ("not_loaded", None, None),
+            KeySetStatus::Loaded {
+                fetched_at,
+                expires_at,
+            } => ("loaded", Some(fetched_at), Some(expires_at)),
+        };
+        Self {
+            fresh: value.fresh,
+            state,
+            fetched_at,
+            expires_at,
+        }
+    }
+}
+
+impl From<Option<ProblemImportRun>> for ProblemImportCheckResponse {
+    fn from(value: Option<ProblemImportRun>) -> Self {
+        Self {
+            started_at: value.as_ref().map(|run| run.started_at),
+            finished_at: value.as_ref().map(|run| run.finished_at),
+            outcome: value.as_ref().map(|run| run.outcome.to_string()),
+            problem_count: value.and_then(|run| run.problem_count),
+            error: None,
+        }
+    }
+}
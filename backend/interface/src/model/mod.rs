@@ -1,4 +1,7 @@
 pub mod contests;
+pub mod explanation_request;
+pub mod health;
+pub mod personal_token;
 pub mod problem;
 pub mod solution;
 pub mod user;
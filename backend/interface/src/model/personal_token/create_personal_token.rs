@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use usecase::model::personal_token::create::{CreatePersonalTokenInput, CreatePersonalTokenOutput};
+use uuid::Uuid;
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePersonalTokenRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+pub fn from_req_for_input(
+    user_id: String,
+    req: CreatePersonalTokenRequest,
+) -> CreatePersonalTokenInput {
+    CreatePersonalTokenInput {
+        user_id,
+        name: req.name,
+        scopes: req.scopes,
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePersonalTokenResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CreatePersonalTokenOutput> for CreatePersonalTokenResponse {
+    fn from(value: CreatePersonalTokenOutput) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            scopes: value.scopes,
+            token: value.token,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{CreatePersonalTokenRequest, from_req_for_input};
+
+    #[test]
+    fn create_personal_token_request_deserializes_camel_case() {
+        let req: CreatePersonalTokenRequest = serde_json::from_value(json!({
+            "name": "cli",
+            "scopes": ["read", "write:comments"]
+        }))
+        .expect("request");
+
+        let input = from_req_for_input("uid".into(), req);
+        assert_eq!(input.user_id, "uid");
+        assert_eq!(input.name, "cli");
+        assert_eq!(input.scopes, vec!["read", "write:comments"]);
+    }
+}
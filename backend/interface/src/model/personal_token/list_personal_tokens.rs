@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::model::personal_token::list::PersonalTokenListItem;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalTokenResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub token_prefix: String,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PersonalTokenListItem> for PersonalTokenResponse {
+    fn from(value: PersonalTokenListItem) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            scopes: value.scopes,
+            token_prefix: value.token_prefix,
+            last_used_at: value.last_used_at,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+use usecase::model::personal_token::revoke::RevokePersonalTokenOutput;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokePersonalTokenResponse {
+    pub id: Uuid,
+}
+
+impl From<RevokePersonalTokenOutput> for RevokePersonalTokenResponse {
+    fn from(value: RevokePersonalTokenOutput) -> Self {
+        Self { id: value.id }
+    }
+}
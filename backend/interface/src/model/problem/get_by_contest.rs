@@ -0,0 +1,72 @@
+use serde::Serialize;
+use usecase::model::problem::get_by_contest::ContestProblems;
+
+use crate::model::{contests::ContestMetadataResponse, problem::ProblemResponse};
+
+// 配列の形は保ったまま、各問題にコンテスト情報を添える
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestProblemResponse {
+    #[serde(flatten)]
+    pub problem: ProblemResponse,
+    pub contest: Option<ContestMetadataResponse>,
+}
+
+pub fn contest_problem_responses(value: ContestProblems) -> Vec<ContestProblemResponse> {
+    value
+        .problems
+        .into_iter()
+        .map(|problem| ContestProblemResponse {
+            problem: problem.into(),
+            contest: value.contest.clone().map(ContestMetadataResponse::from),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::model::problem::{ContestMetadata, Problem};
+    use usecase::model::problem::get_by_contest::ContestProblems;
+
+    use super::contest_problem_responses;
+
+    #[test]
+    fn each_problem_carries_contest_metadata() {
+        let resp = contest_problem_responses(ContestProblems {
+            contest: Some(ContestMetadata {
+                code: "abc234".into(),
+                title: "AtCoder Beginner Contest 234".into(),
+                start_at: "2022-01-01T12:00:00Z".parse().unwrap(),
+                duration_second: 6000,
+                rate_change: "~ 1999".into(),
+            }),
+            problems: vec![Problem {
+                id: "abc234_a".into(),
+                contest_code: "abc234".into(),
+                problem_index: "A".into(),
+                title: "Weird Function".into(),
+                difficulty: Some(20),
+            }],
+        });
+
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "id": "abc234_a",
+                "contestCode": "abc234",
+                "problemIndex": "A",
+                "title": "Weird Function",
+                "difficulty": 20,
+                "difficultyBand": "gray",
+                "contest": {
+                    "code": "abc234",
+                    "title": "AtCoder Beginner Contest 234",
+                    "startAt": "2022-01-01T12:00:00Z",
+                    "durationSecond": 6000,
+                    "rateChange": "~ 1999",
+                },
+            }])
+        );
+    }
+}
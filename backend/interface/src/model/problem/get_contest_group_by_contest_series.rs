@@ -3,8 +3,11 @@ use serde::{Deserialize, Serialize};
 use crate::model::problem::ProblemResponse;
 
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetContestGroupByContestSeriesRequestParams {
     pub q: Option<String>,
+    pub min_difficulty: Option<i32>,
+    pub max_difficulty: Option<i32>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 }
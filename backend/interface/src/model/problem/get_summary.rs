@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::model::{
+    problem::get_summary::{ProblemSummary, TopSolutionPreview},
+    solution::TagCount,
+};
+use uuid::Uuid;
+
+use crate::model::{contests::ContestMetadataResponse, problem::ProblemResponse};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemSummaryResponse {
+    pub problem: ProblemResponse,
+    pub contest: Option<ContestMetadataResponse>,
+    pub solution_count: i64,
+    pub total_votes: i64,
+    pub top_tags: Vec<TagCountResponse>,
+    pub top_solution: Option<TopSolutionPreviewResponse>,
+    pub previous_problem: Option<ProblemResponse>,
+    pub next_problem: Option<ProblemResponse>,
+}
+
+impl From<ProblemSummary> for ProblemSummaryResponse {
+    fn from(value: ProblemSummary) -> Self {
+        Self {
+            problem: value.problem.into(),
+            contest: value.contest.map(ContestMetadataResponse::from),
+            solution_count: value.solution_count,
+            total_votes: value.total_votes,
+            top_tags: value.top_tags.into_iter().map(Into::into).collect(),
+            top_solution: value.top_solution.map(Into::into),
+            previous_problem: value.previous_problem.map(Into::into),
+            next_problem: value.next_problem.map(Into::into),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCountResponse {
+    pub name: String,
+    pub solution_count: i64,
+}
+
+impl From<TagCount> for TagCountResponse {
+    fn from(value: TagCount) -> Self {
+        Self {
+            name: value.name,
+            solution_count: value.solution_count,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopSolutionPreviewResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub user_name: String,
+    pub votes_count: i64,
+    pub tags: Vec<String>,
+    pub body_preview: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TopSolutionPreview> for TopSolutionPreviewResponse {
+    fn from(value: TopSolutionPreview) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            user_name: value.user_name,
+            votes_count: value.votes_count,
+            tags: value.tags,
+            body_preview: value.body_preview,
+            created_at: value.created_at,
+        }
+    }
+}
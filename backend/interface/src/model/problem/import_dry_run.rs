@@ -0,0 +1,120 @@
+use serde::Serialize;
+use usecase::model::problem::create::{
+    DuplicateProblemIndex, ImportDryRunReport, ProblemUpdate, UnknownSeriesContest,
+};
+
+use crate::model::problem::ProblemResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDryRunSummaryResponse {
+    pub fetched: usize,
+    pub inserts: usize,
+    pub updates: usize,
+    pub unchanged: usize,
+    pub removed: usize,
+    pub duplicate_indexes: usize,
+    pub unknown_series: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemUpdateResponse {
+    pub before: ProblemResponse,
+    pub after: ProblemResponse,
+}
+
+impl From<ProblemUpdate> for ProblemUpdateResponse {
+    fn from(value: ProblemUpdate) -> Self {
+        Self {
+            before: value.before.into(),
+            after: value.after.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateProblemIndexResponse {
+    pub contest_code: String,
+    pub problem_index: String,
+    pub problem_ids: Vec<String>,
+}
+
+impl From<DuplicateProblemIndex> for DuplicateProblemIndexResponse {
+    fn from(value: DuplicateProblemIndex) -> Self {
+        Self {
+            contest_code: value.contest_code,
+            problem_index: value.problem_index,
+            problem_ids: value.problem_ids,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownSeriesContestResponse {
+    pub contest_code: String,
+    pub problem_ids: Vec<String>,
+}
+
+impl From<UnknownSeriesContest> for UnknownSeriesContestResponse {
+    fn from(value: UnknownSeriesContest) -> Self {
+        Self {
+            contest_code: value.contest_code,
+            problem_ids: value.problem_ids,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDryRunReportResponse {
+    pub summary: ImportDryRunSummaryResponse,
+    pub inserts: Vec<ProblemResponse>,
+    pub updates: Vec<ProblemUpdateResponse>,
+    pub removed: Vec<ProblemResponse>,
+    pub duplicate_indexes: Vec<DuplicateProblemIndexResponse>,
+    pub unknown_series: Vec<UnknownSeriesContestResponse>,
+}
+
+impl From<ImportDryRunReport> for ImportDryRunReportResponse {
+    fn from(value: ImportDryRunReport) -> Self {
+        Self {
+            summary: ImportDryRunSummaryResponse {
+                fetched: value.fetched,
+                inserts: value.inserts.len(),
+                updates: value.updates.len(),
+                unchanged: value.unchanged,
+                removed: value.removed.len(),
+                duplicate_indexes: value.duplicate_indexes.len(),
+                unknown_series: value.unknown_series.len(),
+            },
+            inserts: value
+                .inserts
+                .into_iter()
+                .map(ProblemResponse::from)
+                .collect(),
+            updates: value
+                .updates
+                .into_iter()
+                .map(ProblemUpdateResponse::from)
+                .collect(),
+            removed: value
+                .removed
+                .into_iter()
+                .map(ProblemResponse::from)
+                .collect(),
+            duplicate_indexes: value
+                .duplicate_indexes
+                .into_iter()
+                .map(DuplicateProblemIndexResponse::from)
+                .collect(),
+            unknown_series: value
+                .unknown_series
+                .into_iter()
+                .map(UnknownSeriesContestResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use domain::model::problem_import::{ProblemImportChanges, ProblemImportRun};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct ListProblemImportRunsRequestParams {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemImportChangesResponse {
+    pub inserted: i32,
+    pub updated: i32,
+    pub unchanged: i32,
+    pub removed: i32,
+    pub difficulty_fetch_failures: i32,
+    pub difficulty_fetch_deferred: i32,
+}
+
+impl From<ProblemImportChanges> for ProblemImportChangesResponse {
+    fn from(value: ProblemImportChanges) -> Self {
+        Self {
+            inserted: value.inserted,
+            updated: value.updated,
+            unchanged: value.unchanged,
+            removed: value.removed,
+            difficulty_fetch_failures: value.difficulty_fetch_failures,
+            difficulty_fetch_deferred: value.difficulty_fetch_deferred,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemImportRunResponse {
+    pub id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: String,
+    pub problem_count: Option<i32>,
+    pub changes: Option<ProblemImportChangesResponse>,
+    pub error_message: Option<String>,
+}
+
+impl From<ProblemImportRun> for ProblemImportRunResponse {
+    fn from(value: ProblemImportRun) -> Self {
+        Self {
+            id: value.id,
+            started_at: value.started_at,
+            finished_at: value.finished_at,
+            outcome: value.outcome.to_string(),
+            problem_count: value.problem_count,
+            changes: value.changes.map(ProblemImportChangesResponse::from),
+            error_message: value.error_message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::model::problem_import::{ImportOutcome, ProblemImportChanges, ProblemImportRun};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::ProblemImportRunResponse;
+
+    #[test]
+    fn import_run_serializes_changes_in_camel_case() {
+        let at = Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap();
+        let run = ProblemImportRun {
+            id: Uuid::nil(),
+            started_at: at,
+            finished_at: at,
+            outcome: ImportOutcome::Success,
+            problem_count: Some(10),
+            changes: Some(ProblemImportChanges {
+                inserted: 1,
+                updated: 2,
+                unchanged: 7,
+                removed: 1,
+                difficulty_fetch_failures: 0,
+                difficulty_fetch_deferred: 3,
+            }),
+            error_message: None,
+        };
+
+        let value = serde_json::to_value(ProblemImportRunResponse::from(run)).unwrap();
+        assert_eq!(value["outcome"], "success");
+        assert_eq!(value["problemCount"], 10);
+        assert_eq!(
+            value["changes"],
+            json!({
+                "inserted": 1,
+                "updated": 2,
+                "unchanged": 7,
+                "removed": 1,
+                "difficultyFetchFailures": 0,
+                "difficultyFetchDeferred": 3
+            })
+        );
+    }
+
+    #[test]
+    fn failed_import_run_has_no_changes() {
+        let at = Utc.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap();
+        let run = ProblemImportRun {
+            id: Uuid::nil(),
+            started_at: at,
+            finished_at: at,
+            outcome: ImportOutcome::Failure,
+            problem_count: None,
+            changes: None,
+            error_message: Some("external unavailable".into()),
+        };
+
+        let value = serde_json::to_value(ProblemImportRunResponse::from(run)).unwrap();
+        assert_eq!(value["outcome"], "failure");
+        assert!(value["changes"].is_null());
+    }
+}
@@ -1,7 +1,12 @@
-use domain::model::problem::Problem;
+use domain::model::problem::{DifficultyBand, Problem};
 use serde::Serialize;
 
+pub mod get_by_contest;
 pub mod get_contest_group_by_contest_series;
+pub mod get_summary;
+pub mod import_dry_run;
+pub mod list_import_runs;
+pub mod resolve_url;
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,6 +16,8 @@ pub struct ProblemResponse {
     pub problem_index: String,
     pub title: String,
     pub difficulty: Option<i32>,
+    // 補正後のdifficultyの色帯。gray..red
+    pub difficulty_band: Option<String>,
 }
 
 impl From<Problem> for ProblemResponse {
@@ -18,9 +25,12 @@ impl From<Problem> for ProblemResponse {
         Self {
             id: value.id,
             contest_code: value.contest_code,
-            problem_index: value.problem_index,
+            problem_index: value.problem_index.into(),
             title: value.title,
             difficulty: value.difficulty,
+            difficulty_band: value
+                .difficulty
+                .map(|difficulty| DifficultyBand::from_difficulty(difficulty).to_string()),
         }
     }
 }
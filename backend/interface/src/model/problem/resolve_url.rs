@@ -0,0 +1,78 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use shared::response::ApiResponse;
+use usecase::model::problem::resolve_url::ResolveProblemUrlError;
+
+use crate::error::ToHttpError;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveProblemUrlRequestParams {
+    pub url: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemNotImportedResponse {
+    pub problem_id: String,
+    pub contest_code: String,
+}
+
+// 未取り込みの問題だけは、取り込み待ちと分かるようにidを添えて404を返す
+pub struct ResolveProblemUrlRejection(ResolveProblemUrlError);
+
+impl From<ResolveProblemUrlError> for ResolveProblemUrlRejection {
+    fn from(value: ResolveProblemUrlError) -> Self {
+        Self(value)
+    }
+}
+
+impl IntoResponse for ResolveProblemUrlRejection {
+    fn into_response(self) -> Response {
+        match self.0 {
+            ResolveProblemUrlError::NotImported {
+                problem_id,
+                contest_code,
+            } => ApiResponse::err_with_data(
+                StatusCode::NOT_FOUND,
+                format!("problem has not been imported yet: {problem_id}"),
+                "PROBLEM_NOT_IMPORTED",
+                ProblemNotImportedResponse {
+                    problem_id,
+                    contest_code,
+                },
+            )
+            .into_response(),
+            e => e.to_http_error().into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::to_bytes, http::StatusCode, response::IntoResponse};
+    use usecase::model::problem::resolve_url::ResolveProblemUrlError;
+
+    use super::ResolveProblemUrlRejection;
+
+    #[tokio::test]
+    async fn not_imported_problem_is_a_structured_not_found() {
+        let resp = ResolveProblemUrlRejection::from(ResolveProblemUrlError::NotImported {
+            problem_id: "abc396_b".into(),
+            contest_code: "abc396".into(),
+        })
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["errorCode"], "PROBLEM_NOT_IMPORTED");
+        assert_eq!(
+            json["data"],
+            serde_json::json!({ "problemId": "abc396_b", "contestCode": "abc396" })
+        );
+    }
+}
@@ -8,6 +8,8 @@ use uuid::Uuid;
 pub struct GetLatestSolutionsRequest {
     pub sort_by: Option<String>,
     pub limit: Option<i32>,
+    pub min_difficulty: Option<i32>,
+    pub max_difficulty: Option<i32>,
 }
 
 #[derive(Serialize)]
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use usecase::model::solution::ProblemSolutionStats;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSolutionStatsRequest {
+    pub problem_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSolutionStatsResponse {
+    pub problem_id: String,
+    pub solution_count: i64,
+    pub top_voted_solution_id: Option<Uuid>,
+    pub total_votes: i64,
+    pub latest_activity_at: Option<DateTime<Utc>>,
+}
+
+impl From<ProblemSolutionStats> for GetSolutionStatsResponse {
+    fn from(value: ProblemSolutionStats) -> Self {
+        let ProblemSolutionStats {
+            problem_id,
+            solution_count,
+            top_voted_solution_id,
+            total_votes,
+            latest_activity_at,
+        } = value;
+
+        Self {
+            problem_id,
+            solution_count,
+            top_voted_solution_id,
+            total_votes,
+            latest_activity_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use usecase::model::solution::ProblemSolutionStats;
+
+    use super::{GetSolutionStatsRequest, GetSolutionStatsResponse};
+
+    #[test]
+    fn deserialize_camel_case_problem_ids() {
+        let req: GetSolutionStatsRequest =
+            serde_json::from_value(json!({ "problemIds": ["abc100_a", "abc100_b"] }))
+                .expect("valid json");
+        assert_eq!(req.problem_ids, vec!["abc100_a", "abc100_b"]);
+    }
+
+    #[test]
+    fn empty_stats_serialize_with_nulls() {
+        let resp = GetSolutionStatsResponse::from(ProblemSolutionStats::empty("abc100_a".into()));
+        assert_eq!(
+            serde_json::to_value(&resp).unwrap(),
+            json!({
+                "problemId": "abc100_a",
+                "solutionCount": 0,
+                "topVotedSolutionId": null,
+                "totalVotes": 0,
+                "latestActivityAt": null
+            })
+        );
+    }
+}
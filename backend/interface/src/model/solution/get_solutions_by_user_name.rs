@@ -7,6 +7,8 @@ use uuid::Uuid;
 #[serde(rename_all = "camelCase")]
 pub struct GetSolutionsByUserNameRequest {
     pub sort_by: Option<String>,
+    pub min_difficulty: Option<i32>,
+    pub max_difficulty: Option<i32>,
 }
 
 impl GetSolutionsByUserNameRequest {
@@ -71,7 +73,11 @@ mod tests {
 
     #[test]
     fn list_sort_defaults_to_latest() {
-        let req = GetSolutionsByUserNameRequest { sort_by: None };
+        let req = GetSolutionsByUserNameRequest {
+            sort_by: None,
+            min_difficulty: None,
+            max_difficulty: None,
+        };
         assert!(matches!(req.list_sort(), SolutionListSort::Latest));
     }
 
@@ -79,6 +85,8 @@ mod tests {
     fn list_sort_votes_is_mapped() {
         let req = GetSolutionsByUserNameRequest {
             sort_by: Some("votes".to_string()),
+            min_difficulty: None,
+            max_difficulty: None,
         };
         assert!(matches!(req.list_sort(), SolutionListSort::Votes));
     }
@@ -86,10 +94,13 @@ mod tests {
     #[test]
     fn deserialize_camel_case_sort_by() {
         let raw = json!({
-            "sortBy": "votes"
+            "sortBy": "votes",
+            "minDifficulty": 800,
         });
         let req: GetSolutionsByUserNameRequest = serde_json::from_value(raw).expect("valid json");
         assert_eq!(req.sort_by.as_deref(), Some("votes"));
+        assert_eq!(req.min_difficulty, Some(800));
+        assert_eq!(req.max_difficulty, None);
     }
 
     #[test]
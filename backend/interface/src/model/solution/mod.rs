@@ -6,6 +6,7 @@ pub mod get_comments_by_solution_id;
 pub mod get_latest_solutions;
 pub mod get_my_vote_status;
 pub mod get_solution_by_solution_id;
+pub mod get_solution_stats;
 pub mod get_solution_votes_count;
 pub mod get_solutions_by_problem_id;
 pub mod get_solutions_by_user_name;
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::model::user::list_sessions::SessionListItem;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionResponse {
+    pub id: Uuid,
+    pub authenticated_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub last_seen_at: DateTime<Utc>,
+    pub current: bool,
+}
+
+impl From<SessionListItem> for SessionResponse {
+    fn from(value: SessionListItem) -> Self {
+        Self {
+            id: value.id,
+            authenticated_at: value.authenticated_at,
+            user_agent: value.user_agent,
+            ip_address: value.ip_address,
+            last_seen_at: value.last_seen_at,
+            current: value.current,
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod create_user;
 pub mod delete_me;
 pub mod get_me;
+pub mod list_sessions;
+pub mod revoke_session;
 pub mod revoke_tokens;
@@ -0,0 +1,15 @@
+use serde::Serialize;
+use usecase::model::user::revoke_session::RevokeSessionOutput;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevokeSessionResponse {
+    pub id: Uuid,
+}
+
+impl From<RevokeSessionOutput> for RevokeSessionResponse {
+    fn from(value: RevokeSessionOutput) -> Self {
+        Self { id: value.id }
+    }
+}
@@ -0,0 +1,19 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+use registry::Registry;
+
+use crate::handler::{
+    contest::add_contest_series_handler, problem::list_problem_import_runs_handler,
+};
+
+pub fn build_admin_routers() -> Router<Registry> {
+    let routers = Router::new()
+        .route(
+            "/problem-import-runs",
+            get(list_problem_import_runs_handler),
+        )
+        .route("/contest-series", post(add_contest_series_handler));
+    Router::new().nest("/admin", routers)
+}
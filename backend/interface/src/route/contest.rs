@@ -1,12 +1,19 @@
 use axum::{Router, routing::get};
 use registry::Registry;
 
-use crate::handler::problem::get_problems_by_contest_handler;
+use crate::handler::{
+    problem::get_problems_by_contest_handler, solution::get_solutions_by_problem_appearance_handler,
+};
 
 pub fn build_contests_routers() -> Router<Registry> {
-    let routers = Router::new().route(
-        "/{contest_code}/problems",
-        get(get_problems_by_contest_handler),
-    );
+    let routers = Router::new()
+        .route(
+            "/{contest_code}/problems",
+            get(get_problems_by_contest_handler),
+        )
+        .route(
+            "/{contest_code}/problems/{problem_index}/solutions",
+            get(get_solutions_by_problem_appearance_handler),
+        );
     Router::new().nest("/contests", routers)
 }
@@ -0,0 +1,260 @@
+use axum::{
+    Router,
+    http::{HeaderName, HeaderValue, Method, header},
+};
+use shared::config::CorsConfig;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+const TRACEPARENT: HeaderName = HeaderName::from_static("traceparent");
+const TRACESTATE: HeaderName = HeaderName::from_static("tracestate");
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+// CORSのポリシーを分けるルートのまとまり
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorsRouteGroup {
+    // /health 以下。読み取りのみで、認証情報は送らせない
+    Health,
+    // 拡張機能やatcoder.jp上から呼ばれるAPI
+    Api,
+}
+
+impl CorsRouteGroup {
+    fn allow_methods(self) -> Vec<Method> {
+        match self {
+            CorsRouteGroup::Health => vec![Method::GET, Method::HEAD],
+            CorsRouteGroup::Api => vec![
+                Method::GET,
+                Method::HEAD,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ],
+        }
+    }
+
+    fn allow_headers(self) -> Vec<HeaderName> {
+        match self {
+            CorsRouteGroup::Health => vec![X_REQUEST_ID, TRACEPARENT, TRACESTATE],
+            CorsRouteGroup::Api => vec![
+                header::AUTHORIZATION,
+                header::CONTENT_TYPE,
+                X_REQUEST_ID,
+                TRACEPARENT,
+                TRACESTATE,
+            ],
+        }
+    }
+
+    fn expose_headers(self) -> Vec<HeaderName> {
+        match self {
+            CorsRouteGroup::Health => vec![X_REQUEST_ID],
+            // 旧パスの非推奨ヘッダーも拡張機能から読めるようにする
+            CorsRouteGroup::Api => vec![X_REQUEST_ID, DEPRECATION, SUNSET, header::LINK],
+        }
+    }
+
+    fn allow_credentials(self, config: &CorsConfig) -> bool {
+        match self {
+            CorsRouteGroup::Health => false,
+            CorsRouteGroup::Api => config.allow_credentials,
+        }
+    }
+}
+
+pub fn cors_layer(config: &CorsConfig, group: CorsRouteGroup) -> CorsLayer {
+    // 設定の読み込み時に形式を検証済み
+    let origins = config
+        .allowed_origins
+        .iter()
+        .filter_map(|origin| HeaderValue::from_str(origin).ok())
+        .collect::<Vec<_>>();
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods(group.allow_methods())
+        .allow_headers(group.allow_headers())
+        .expose_headers(group.expose_headers())
+        .allow_credentials(group.allow_credentials(config))
+        .max_age(config.max_age)
+}
+
+// preflightはメソッドの判定より先に応答する
+pub fn with_cors<S>(routers: Router<S>, config: &CorsConfig, group: CorsRouteGroup) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    routers.layer(cors_layer(config, group))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, Response, StatusCode, header},
+        routing::get,
+    };
+    use shared::config::CorsConfig;
+    use tower::ServiceExt;
+
+    use super::{CorsRouteGroup, with_cors};
+
+    const EXTENSION_ORIGIN: &str = "chrome-extension://abcdefghijklmnopabcdefghijklmnop";
+
+    fn config(allow_credentials: bool) -> CorsConfig {
+        CorsConfig {
+            allowed_origins: vec!["https://atcoder.jp".into(), EXTENSION_ORIGIN.into()],
+            allow_credentials,
+            max_age: Duration::from_secs(600),
+        }
+    }
+
+    fn app(config: &CorsConfig) -> Router {
+        let api = Router::new().route(
+            "/v1/solutions/{solution_id}",
+            get(|| async { "ok" }).patch(|| async { "ok" }),
+        );
+        let health = Router::new().route("/health/ready", get(|| async { "ok" }));
+        Router::new()
+            .merge(with_cors(api, config, CorsRouteGroup::Api))
+            .merge(with_cors(health, config, CorsRouteGroup::Health))
+    }
+
+    async fn preflight(app: Router, path: &str, origin: &str, method: &str) -> Response<Body> {
+        app.oneshot(
+            Request::options(path)
+                .header(header::ORIGIN, origin)
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, method)
+                .header(
+                    header::ACCESS_CONTROL_REQUEST_HEADERS,
+                    "authorization,content-type",
+                )
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response")
+    }
+
+    fn header_value(res: &Response<Body>, name: header::HeaderName) -> Option<&str> {
+        res.headers().get(name).and_then(|v| v.to_str().ok())
+    }
+
+    #[tokio::test]
+    async fn api_preflight_from_atcoder_allows_writes_with_authorization() {
+        let res = preflight(
+            app(&config(false)),
+            "/v1/solutions/abc",
+            "https://atcoder.jp",
+            "PATCH",
+        )
+        .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://atcoder.jp")
+        );
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_ALLOW_METHODS),
+            Some("GET,HEAD,POST,PUT,PATCH,DELETE")
+        );
+        let allowed_headers = header_value(&res, header::ACCESS_CONTROL_ALLOW_HEADERS).unwrap();
+        assert!(allowed_headers.contains("authorization"));
+        assert!(allowed_headers.contains("content-type"));
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_MAX_AGE),
+            Some("600")
+        );
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn api_preflight_from_extension_origin_is_allowed() {
+        let res = preflight(
+            app(&config(false)),
+            "/v1/solutions/abc",
+            EXTENSION_ORIGIN,
+            "GET",
+        )
+        .await;
+
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some(EXTENSION_ORIGIN)
+        );
+    }
+
+    #[tokio::test]
+    async fn preflight_from_unlisted_origin_has_no_allow_origin() {
+        let res = preflight(
+            app(&config(false)),
+            "/v1/solutions/abc",
+            "https://evil.example.com",
+            "PATCH",
+        )
+        .await;
+
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn credentials_are_allowed_only_on_api_routes() {
+        let config = config(true);
+
+        let api = preflight(
+            app(&config),
+            "/v1/solutions/abc",
+            "https://atcoder.jp",
+            "GET",
+        )
+        .await;
+        assert_eq!(
+            header_value(&api, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true")
+        );
+
+        let health = preflight(app(&config), "/health/ready", "https://atcoder.jp", "GET").await;
+        assert_eq!(
+            header_value(&health, header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            None
+        );
+        assert_eq!(
+            header_value(&health, header::ACCESS_CONTROL_ALLOW_METHODS),
+            Some("GET,HEAD")
+        );
+    }
+
+    #[tokio::test]
+    async fn simple_request_exposes_request_id_and_deprecation_headers() {
+        let res = app(&config(false))
+            .oneshot(
+                Request::get("/v1/solutions/abc")
+                    .header(header::ORIGIN, "https://atcoder.jp")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://atcoder.jp")
+        );
+        assert_eq!(
+            header_value(&res, header::ACCESS_CONTROL_EXPOSE_HEADERS),
+            Some("x-request-id,deprecation,sunset,link")
+        );
+    }
+}
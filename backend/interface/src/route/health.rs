@@ -1,11 +1,13 @@
 use axum::{Router, routing::get};
 use registry::Registry;
 
-use crate::handler::health::{health_check, health_check_db};
+use crate::handler::health::{health_check, health_check_db, liveness_handler, readiness_handler};
 
 pub fn build_health_check_routers() -> Router<Registry> {
     let routers = Router::new()
         .route("/", get(health_check))
-        .route("/db", get(health_check_db));
+        .route("/db", get(health_check_db))
+        .route("/live", get(liveness_handler))
+        .route("/ready", get(readiness_handler));
     Router::new().nest("/health", routers)
 }
@@ -0,0 +1,106 @@
+use axum::{
+    Router,
+    extract::Request,
+    http::{HeaderName, HeaderValue, header},
+    middleware::{self, Next},
+    response::Response,
+};
+
+// ルート直下の旧パスを非推奨にした日時 (RFC 9745)
+const LEGACY_DEPRECATION: &str = "@1792368000";
+// ルート直下の旧パスの提供終了予定日時 (RFC 8594)
+const LEGACY_SUNSET: &str = "Thu, 01 Apr 2027 00:00:00 GMT";
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+pub fn build_legacy_alias_routers<S>(
+    routers: Router<S>,
+    successor_prefix: &'static str,
+) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    routers.route_layer(middleware::from_fn(
+        move |req: Request, next: Next| async move {
+            let successor = format!(
+                "<{}{}>; rel=\"successor-version\"",
+                successor_prefix,
+                req.uri().path()
+            );
+            let res = next.run(req).await;
+            with_deprecation_headers(res, &successor)
+        },
+    ))
+}
+
+fn with_deprecation_headers(mut res: Response, successor: &str) -> Response {
+    let headers = res.headers_mut();
+    headers.insert(DEPRECATION, HeaderValue::from_static(LEGACY_DEPRECATION));
+    headers.insert(SUNSET, HeaderValue::from_static(LEGACY_SUNSET));
+    if let Ok(link) = HeaderValue::from_str(successor) {
+        headers.insert(header::LINK, link);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode, header},
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use super::{LEGACY_DEPRECATION, LEGACY_SUNSET, build_legacy_alias_routers};
+
+    fn routers() -> Router {
+        Router::new().route("/solutions/{solution_id}", get(|| async { "ok" }))
+    }
+
+    #[tokio::test]
+    async fn legacy_alias_adds_deprecation_headers_with_successor_link() {
+        let app = Router::new()
+            .nest("/v1", routers())
+            .merge(build_legacy_alias_routers(routers(), "/v1"));
+
+        let res = app
+            .oneshot(
+                Request::get("/solutions/abc")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()["deprecation"], LEGACY_DEPRECATION);
+        assert_eq!(res.headers()["sunset"], LEGACY_SUNSET);
+        assert_eq!(
+            res.headers()[header::LINK],
+            "</v1/solutions/abc>; rel=\"successor-version\""
+        );
+    }
+
+    #[tokio::test]
+    async fn versioned_route_has_no_deprecation_headers() {
+        let app = Router::new()
+            .nest("/v1", routers())
+            .merge(build_legacy_alias_routers(routers(), "/v1"));
+
+        let res = app
+            .oneshot(
+                Request::get("/v1/solutions/abc")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers().get("deprecation").is_none());
+        assert!(res.headers().get("sunset").is_none());
+    }
+}
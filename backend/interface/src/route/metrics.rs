@@ -0,0 +1,105 @@
+use std::time::Instant;
+
+use axum::{
+    Extension, Router,
+    extract::{MatchedPath, Request},
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+};
+use metrics::{counter, histogram};
+use metrics_exporter_prometheus::PrometheusHandle;
+use registry::Registry;
+
+use crate::handler::metrics::metrics_handler;
+
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+
+pub fn build_metrics_routers(handle: PrometheusHandle) -> Router<Registry> {
+    Router::new()
+        .route("/metrics", get(metrics_handler))
+        .layer(Extension(handle))
+}
+
+// ルーティング後に計測し、パスパラメータを含まないルート名で集計する
+pub fn with_http_metrics<S>(routers: Router<S>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    routers.route_layer(middleware::from_fn(track_http_metrics))
+}
+
+async fn track_http_metrics(req: Request, next: Next) -> Response {
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned())
+        .unwrap_or_else(|| "unmatched".to_owned());
+    let method = req.method().to_string();
+    let started = Instant::now();
+
+    let res = next.run(req).await;
+
+    let labels = [
+        ("method", method),
+        ("route", route),
+        ("status", res.status().as_u16().to_string()),
+    ];
+    counter!(HTTP_REQUESTS_TOTAL, &labels).increment(1);
+    histogram!(HTTP_REQUEST_DURATION_SECONDS, &labels).record(started.elapsed().as_secs_f64());
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode},
+        routing::get,
+    };
+    use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+    use tower::ServiceExt;
+
+    use super::with_http_metrics;
+
+    fn handle() -> &'static PrometheusHandle {
+        static HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+        HANDLE.get_or_init(|| {
+            PrometheusBuilder::new()
+                .install_recorder()
+                .expect("install recorder")
+        })
+    }
+
+    #[tokio::test]
+    async fn requests_are_counted_by_matched_route_and_status() {
+        let handle = handle();
+        let app = with_http_metrics(Router::new().nest(
+            "/v1",
+            Router::new().route(
+                "/solutions/{solution_id}",
+                get(|| async { StatusCode::CREATED }),
+            ),
+        ));
+
+        let res = app
+            .oneshot(
+                Request::get("/v1/solutions/abc")
+                    .body(Body::empty())
+                    .expect("request"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let rendered = handle.render();
+        assert!(rendered.contains(
+            r#"http_requests_total{method="GET",route="/v1/solutions/{solution_id}",status="201"} 1"#
+        ));
+        assert!(!rendered.contains("/v1/solutions/abc"));
+    }
+}
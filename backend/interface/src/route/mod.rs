@@ -1,7 +1,55 @@
+pub mod admin;
 pub mod contest;
+pub mod cors;
 pub mod health;
+pub mod legacy;
+pub mod metrics;
 pub mod problem;
 pub mod series;
 pub mod solution;
 pub mod user;
+pub mod v1;
 pub mod version;
+
+use axum::Router;
+use registry::Registry;
+
+use crate::route::legacy::build_legacy_alias_routers;
+
+// 公開しているAPIの版
+// 新しい版を追加する場合はバリアントと専用のrouter(handler/modelは版ごとに分ける)を足し、usecaseは共有する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    V1,
+}
+
+impl ApiVersion {
+    pub const ALL: [ApiVersion; 1] = [ApiVersion::V1];
+    // ルート直下の旧パスが指す版
+    pub const LEGACY_ALIAS: ApiVersion = ApiVersion::V1;
+
+    pub fn prefix(self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "/v1",
+        }
+    }
+
+    pub fn routers(self) -> Router<Registry> {
+        match self {
+            ApiVersion::V1 => v1::build_v1_routers(),
+        }
+    }
+}
+
+pub fn build_api_routers() -> Router<Registry> {
+    let legacy = ApiVersion::LEGACY_ALIAS;
+    ApiVersion::ALL
+        .into_iter()
+        .fold(Router::new(), |routers, version| {
+            routers.nest(version.prefix(), version.routers())
+        })
+        .merge(build_legacy_alias_routers(
+            legacy.routers(),
+            legacy.prefix(),
+        ))
+}
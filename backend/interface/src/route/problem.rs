@@ -1,16 +1,30 @@
-use axum::{Router, routing::get};
+use axum::{
+    Router,
+    routing::{get, post},
+};
 use registry::Registry;
 
 use crate::handler::{
-    problem::get_problem_by_id_handler, solution::get_solutions_by_problems_id_handler,
+    explanation_request::{request_explanation_handler, withdraw_explanation_request_handler},
+    problem::{
+        get_problem_by_id_handler, get_problem_summary_handler, resolve_problem_url_handler,
+    },
+    solution::{get_solution_stats_handler, get_solutions_by_problems_id_handler},
 };
 
 pub fn build_problem_routers() -> Router<Registry> {
     let routers = Router::new()
+        .route("/solution-stats", post(get_solution_stats_handler))
+        .route("/resolve", get(resolve_problem_url_handler))
         .route(
             "/{problem_id}/solutions",
             get(get_solutions_by_problems_id_handler),
         )
+        .route("/{problem_id}/summary", get(get_problem_summary_handler))
+        .route(
+            "/{problem_id}/explanation-requests",
+            post(request_explanation_handler).delete(withdraw_explanation_request_handler),
+        )
         .route("/{problem_id}", get(get_problem_by_id_handler));
     Router::new().nest("/problems", routers)
 }
@@ -2,7 +2,8 @@ use axum::{Router, routing::get};
 use registry::Registry;
 
 use crate::handler::{
-    contest::get_contests_by_series_handler, problem::get_contest_group_by_contest_series_handler,
+    contest::get_contests_by_series_handler, explanation_request::list_wanted_problems_handler,
+    problem::get_contest_group_by_contest_series_handler,
 };
 
 pub fn build_series_routers() -> Router<Registry> {
@@ -11,6 +12,10 @@ pub fn build_series_routers() -> Router<Registry> {
         .route(
             "/{series}/problem-groups",
             get(get_contest_group_by_contest_series_handler),
+        )
+        .route(
+            "/{series}/wanted-problems",
+            get(list_wanted_problems_handler),
         );
     Router::new().nest("/series", routers)
 }
@@ -1,9 +1,16 @@
-use axum::{Router, routing::get, routing::post};
+use axum::{Router, routing::delete, routing::get, routing::post};
 use registry::Registry;
 
 use crate::handler::{
+    explanation_request::list_my_explanation_requests_handler,
+    personal_token::{
+        create_personal_token_handler, list_personal_tokens_handler, revoke_personal_token_handler,
+    },
     solution::get_solutions_by_user_name_handler,
-    user::{create_user_handler, delete_me_handler, get_me_handler, revoke_tokens_handler},
+    user::{
+        create_user_handler, delete_me_handler, get_me_handler, list_sessions_handler,
+        revoke_session_handler, revoke_tokens_handler,
+    },
 };
 
 pub fn build_user_routers() -> Router<Registry> {
@@ -11,6 +18,20 @@ pub fn build_user_routers() -> Router<Registry> {
         .route("/", post(create_user_handler))
         .route("/me", get(get_me_handler).delete(delete_me_handler))
         .route("/me/revoke", post(revoke_tokens_handler))
+        .route("/me/sessions", get(list_sessions_handler))
+        .route(
+            "/me/explanation-requests",
+            get(list_my_explanation_requests_handler),
+        )
+        .route("/me/sessions/{session_id}", delete(revoke_session_handler))
+        .route(
+            "/me/tokens",
+            get(list_personal_tokens_handler).post(create_personal_token_handler),
+        )
+        .route(
+            "/me/tokens/{token_id}",
+            delete(revoke_personal_token_handler),
+        )
         .route(
             "/{user_name}/solutions",
             get(get_solutions_by_user_name_handler),
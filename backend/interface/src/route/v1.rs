@@ -0,0 +1,19 @@
+use axum::Router;
+use registry::Registry;
+
+use crate::route::{
+    admin::build_admin_routers, contest::build_contests_routers, problem::build_problem_routers,
+    series::build_series_routers, solution::build_solution_routers, user::build_user_routers,
+    version::build_version_routers,
+};
+
+pub fn build_v1_routers() -> Router<Registry> {
+    Router::new()
+        .merge(build_version_routers())
+        .merge(build_user_routers())
+        .merge(build_problem_routers())
+        .merge(build_series_routers())
+        .merge(build_solution_routers())
+        .merge(build_contests_routers())
+        .merge(build_admin_routers())
+}
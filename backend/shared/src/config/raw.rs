@@ -0,0 +1,367 @@
+use std::{fmt::Display, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+use super::{CONFIG_DIR_ENV, CONFIG_FILE_ENV, ConfigLayer, DEFAULT_CONFIG_DIR};
+use crate::env::{Environment, parse_env};
+
+// 設定ファイルの形。未指定の項目は既定値か環境変数で埋める
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawConfig {
+    pub server: RawServer,
+    pub database: RawDatabase,
+    pub atcoder_problems: RawAtcoderProblems,
+    pub auth: RawAuth,
+    pub log: RawLog,
+    pub metrics: RawMetrics,
+    pub telemetry: RawTelemetry,
+    pub cors: RawCors,
+    pub limits: RawLimits,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawServer {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub shutdown_drain_timeout_secs: Option<u64>,
+    pub shutdown_readiness_delay_secs: Option<u64>,
+    pub trusted_proxy_hops: Option<usize>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawDatabase {
+    pub url: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub database: Option<String>,
+    pub max_connections: Option<u32>,
+    pub acquire_timeout_secs: Option<u64>,
+    pub migrate_on_startup: Option<bool>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawAtcoderProblems {
+    pub base_endpoint: Option<String>,
+    pub difficulty_endpoint: Option<String>,
+    pub connect_timeout_secs: Option<u64>,
+    pub request_timeout_secs: Option<u64>,
+    pub requests_per_second: Option<f64>,
+    pub max_retries: Option<u32>,
+    pub retry_backoff_ms: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawAuth {
+    pub provider: Option<String>,
+    pub firebase_project_id: Option<String>,
+    pub local_jwt: RawLocalJwt,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawLocalJwt {
+    pub issuer: Option<String>,
+    pub audience: Option<String>,
+    pub secret: Option<String>,
+    pub public_key_path: Option<String>,
+    pub jwks_path: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawLog {
+    pub rust_log: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawMetrics {
+    pub enabled: Option<bool>,
+    pub bind_address: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawTelemetry {
+    pub otlp_endpoint: Option<String>,
+    pub service_name: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawCors {
+    pub allowed_origins: Option<Vec<String>>,
+    pub allow_credentials: Option<bool>,
+    pub max_age_secs: Option<u64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct RawLimits {
+    pub max_contest_group_offset: Option<usize>,
+    pub problem_upsert_chunk_size: Option<usize>,
+    pub difficulty_fetch_concurrency: Option<usize>,
+    pub difficulty_fetch_budget: Option<usize>,
+}
+
+pub(super) fn environment(
+    env: &dyn Fn(&str) -> Option<String>,
+    problems: &mut Vec<String>,
+) -> Environment {
+    match non_empty(env, "ENV").as_deref() {
+        None => Environment::Dev,
+        Some(value @ ("dev" | "stg" | "prd")) => parse_env(value),
+        Some(other) => {
+            problems.push(format!(
+                "ENV: unknown environment {other:?} (expected dev, stg or prd)"
+            ));
+            Environment::Dev
+        }
+    }
+}
+
+pub(super) fn read_layers(
+    environment: Environment,
+    env: &dyn Fn(&str) -> Option<String>,
+    problems: &mut Vec<String>,
+) -> Vec<ConfigLayer> {
+    let dir = non_empty(env, CONFIG_DIR_ENV).unwrap_or_else(|| DEFAULT_CONFIG_DIR.to_string());
+    let mut layers = Vec::new();
+    for name in ["default.toml".to_string(), format!("{environment}.toml")] {
+        let path = Path::new(&dir).join(name);
+        if path.is_file() {
+            read_layer(&path, &mut layers, problems);
+        }
+    }
+    if let Some(file) = non_empty(env, CONFIG_FILE_ENV) {
+        read_layer(Path::new(&file), &mut layers, problems);
+    }
+    layers
+}
+
+fn read_layer(path: &Path, layers: &mut Vec<ConfigLayer>, problems: &mut Vec<String>) {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => layers.push(ConfigLayer {
+            source: path.display().to_string(),
+            contents,
+        }),
+        Err(e) => problems.push(format!("{}: cannot read config file: {e}", path.display())),
+    }
+}
+
+pub(super) fn merge_layers(layers: &[ConfigLayer], problems: &mut Vec<String>) -> RawConfig {
+    let mut merged = toml::Table::new();
+    for layer in layers {
+        // 未知のキーや型の誤りはファイルごとに行番号付きで報告する
+        if let Err(e) = toml::from_str::<RawConfig>(&layer.contents) {
+            let line = e
+                .span()
+                .map(|span| layer.contents[..span.start].matches('\n').count() + 1);
+            match line {
+                Some(line) => problems.push(format!("{}:{line}: {}", layer.source, e.message())),
+                None => problems.push(format!("{}: {}", layer.source, e.message())),
+            }
+            continue;
+        }
+        if let Ok(table) = layer.contents.parse::<toml::Table>() {
+            merge_table(&mut merged, table);
+        }
+    }
+    RawConfig::deserialize(toml::Value::Table(merged)).unwrap_or_default()
+}
+
+fn merge_table(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                merge_table(base, overlay)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+pub(super) fn apply_env(
+    raw: &mut RawConfig,
+    env: &dyn Fn(&str) -> Option<String>,
+    problems: &mut Vec<String>,
+) {
+    let mut o = Overrides { env, problems };
+
+    o.string("HOST", &mut raw.server.host);
+    o.parse("PORT", &mut raw.server.port);
+    o.parse(
+        "SHUTDOWN_DRAIN_TIMEOUT_SECS",
+        &mut raw.server.shutdown_drain_timeout_secs,
+    );
+    o.parse(
+        "SHUTDOWN_READINESS_DELAY_SECS",
+        &mut raw.server.shutdown_readiness_delay_secs,
+    );
+    o.parse("TRUSTED_PROXY_HOPS", &mut raw.server.trusted_proxy_hops);
+
+    o.string("APP_DATABASE_URL", &mut raw.database.url);
+    o.string("DATABASE_HOST", &mut raw.database.host);
+    o.parse("DATABASE_PORT", &mut raw.database.port);
+    o.string("DATABASE_USERNAME", &mut raw.database.username);
+    o.string("DATABASE_PASSWORD", &mut raw.database.password);
+    o.string("DATABASE_NAME", &mut raw.database.database);
+    o.parse(
+        "DATABASE_MAX_CONNECTIONS",
+        &mut raw.database.max_connections,
+    );
+    o.parse(
+        "DATABASE_ACQUIRE_TIMEOUT_SECS",
+        &mut raw.database.acquire_timeout_secs,
+    );
+    o.bool(
+        "DATABASE_MIGRATE_ON_STARTUP",
+        &mut raw.database.migrate_on_startup,
+    );
+
+    o.string(
+        "ATCODER_PROBLEMS_BASE_ENDPOINT",
+        &mut raw.atcoder_problems.base_endpoint,
+    );
+    o.string(
+        "ATCODER_PROBLEMS_DIFFICULTY_ENDPOINT",
+        &mut raw.atcoder_problems.difficulty_endpoint,
+    );
+    o.parse(
+        "ATCODER_PROBLEMS_CONNECT_TIMEOUT_SECS",
+        &mut raw.atcoder_problems.connect_timeout_secs,
+    );
+    o.parse(
+        "ATCODER_PROBLEMS_REQUEST_TIMEOUT_SECS",
+        &mut raw.atcoder_problems.request_timeout_secs,
+    );
+    o.parse(
+        "ATCODER_PROBLEMS_REQUESTS_PER_SECOND",
+        &mut raw.atcoder_problems.requests_per_second,
+    );
+    o.parse(
+        "ATCODER_PROBLEMS_MAX_RETRIES",
+        &mut raw.atcoder_problems.max_retries,
+    );
+    o.parse(
+        "ATCODER_PROBLEMS_RETRY_BACKOFF_MS",
+        &mut raw.atcoder_problems.retry_backoff_ms,
+    );
+
+    o.string("AUTH_PROVIDER", &mut raw.auth.provider);
+    o.string("FIREBASE_PROJECT_ID", &mut raw.auth.firebase_project_id);
+    apply_local_jwt_env(&mut raw.auth.local_jwt, env);
+
+    o.string("RUST_LOG", &mut raw.log.rust_log);
+
+    o.bool("METRICS_ENABLED", &mut raw.metrics.enabled);
+    o.string("METRICS_BIND_ADDR", &mut raw.metrics.bind_address);
+
+    o.string(
+        "OTEL_EXPORTER_OTLP_ENDPOINT",
+        &mut raw.telemetry.otlp_endpoint,
+    );
+    o.string("OTEL_SERVICE_NAME", &mut raw.telemetry.service_name);
+
+    o.list("CORS_ALLOWED_ORIGINS", &mut raw.cors.allowed_origins);
+    o.bool("CORS_ALLOW_CREDENTIALS", &mut raw.cors.allow_credentials);
+    o.parse("CORS_MAX_AGE_SECS", &mut raw.cors.max_age_secs);
+
+    o.parse(
+        "MAX_CONTEST_GROUP_OFFSET",
+        &mut raw.limits.max_contest_group_offset,
+    );
+    o.parse(
+        "PROBLEM_UPSERT_CHUNK_SIZE",
+        &mut raw.limits.problem_upsert_chunk_size,
+    );
+    o.parse(
+        "DIFFICULTY_FETCH_CONCURRENCY",
+        &mut raw.limits.difficulty_fetch_concurrency,
+    );
+    o.parse(
+        "DIFFICULTY_FETCH_BUDGET",
+        &mut raw.limits.difficulty_fetch_budget,
+    );
+}
+
+pub(super) fn apply_local_jwt_env(raw: &mut RawLocalJwt, env: &dyn Fn(&str) -> Option<String>) {
+    let mut problems = Vec::new();
+    let mut o = Overrides {
+        env,
+        problems: &mut problems,
+    };
+    o.string("LOCAL_JWT_SECRET", &mut raw.secret);
+    o.string("LOCAL_JWT_PUBLIC_KEY_PATH", &mut raw.public_key_path);
+    o.string("LOCAL_JWT_JWKS_PATH", &mut raw.jwks_path);
+    o.string("LOCAL_JWT_ISSUER", &mut raw.issuer);
+    o.string("LOCAL_JWT_AUDIENCE", &mut raw.audience);
+}
+
+// 空文字の環境変数は未設定として扱う
+fn non_empty(env: &dyn Fn(&str) -> Option<String>, key: &str) -> Option<String> {
+    env(key).filter(|value| !value.trim().is_empty())
+}
+
+struct Overrides<'a> {
+    env: &'a dyn Fn(&str) -> Option<String>,
+    problems: &'a mut Vec<String>,
+}
+
+impl Overrides<'_> {
+    fn string(&mut self, key: &str, target: &mut Option<String>) {
+        if let Some(value) = non_empty(self.env, key) {
+            *target = Some(value);
+        }
+    }
+
+    fn parse<T>(&mut self, key: &str, target: &mut Option<T>)
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if let Some(value) = non_empty(self.env, key) {
+            match value.trim().parse() {
+                Ok(parsed) => *target = Some(parsed),
+                Err(e) => self
+                    .problems
+                    .push(format!("{key}: invalid value {value:?}: {e}")),
+            }
+        }
+    }
+
+    fn bool(&mut self, key: &str, target: &mut Option<bool>) {
+        if let Some(value) = non_empty(self.env, key) {
+            match value.trim() {
+                "true" | "1" => *target = Some(true),
+                "false" | "0" => *target = Some(false),
+                _ => self.problems.push(format!(
+                    "{key}: invalid value {value:?}: expected true, false, 1 or 0"
+                )),
+            }
+        }
+    }
+
+    // カンマ区切り
+    fn list(&mut self, key: &str, target: &mut Option<Vec<String>>) {
+        if let Some(value) = non_empty(self.env, key) {
+            *target = Some(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(String::from)
+                    .collect(),
+            );
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::{net::SocketAddr, time::Duration};
+
+use super::{
+    AppConfig, AtcoderProblemsConfig, AuthConfig, AuthProviderConfig, CorsConfig,
+    DEFAULT_SERVICE_NAME, DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SECS,
+    DEFAULT_SHUTDOWN_READINESS_DELAY_SECS, DatabaseConfig, LOCAL_JWT_DEFAULT_AUDIENCE,
+    LOCAL_JWT_DEFAULT_ISSUER, LimitsConfig, LocalJwtConfig, LocalJwtKey, LogConfig, MetricsConfig,
+    ServerConfig, TelemetryConfig,
+    raw::{RawAuth, RawConfig, RawLocalJwt},
+};
+use crate::env::Environment;
+
+pub const ATCODER_ORIGIN: &str = "https://atcoder.jp";
+// next devの既定ポート
+const LOCAL_FRONTEND_ORIGIN: &str = "http://localhost:3000";
+
+// Environmentごとの既定値
+struct Defaults {
+    max_connections: u32,
+    allowed_origins: Vec<String>,
+}
+
+impl Defaults {
+    fn for_environment(environment: Environment) -> Self {
+        match environment {
+            Environment::Dev => Self {
+                max_connections: 5,
+                allowed_origins: vec![ATCODER_ORIGIN.into(), LOCAL_FRONTEND_ORIGIN.into()],
+            },
+            Environment::Stg | Environment::Prd => Self {
+                max_connections: 10,
+                allowed_origins: vec![ATCODER_ORIGIN.into()],
+            },
+        }
+    }
+}
+
+pub(super) fn resolve(
+    environment: Environment,
+    raw: RawConfig,
+    problems: &mut Vec<String>,
+) -> AppConfig {
+    let defaults = Defaults::for_environment(environment);
+
+    let server = ServerConfig {
+        host: raw.server.host.unwrap_or_else(|| "0.0.0.0".to_string()),
+        port: raw.server.port.unwrap_or(8080),
+        shutdown_drain_timeout: positive_secs(
+            raw.server.shutdown_drain_timeout_secs,
+            DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SECS,
+            "server.shutdown_drain_timeout_secs",
+            problems,
+        ),
+        // 0なら待たずにdrainを始める
+        shutdown_readiness_delay: Duration::from_secs(
+            raw.server
+                .shutdown_readiness_delay_secs
+                .unwrap_or(DEFAULT_SHUTDOWN_READINESS_DELAY_SECS),
+        ),
+        trusted_proxy_hops: raw.server.trusted_proxy_hops.unwrap_or(0),
+    };
+
+    let url = raw.database.url;
+    if let Some(url) = &url
+        && !(url.starts_with("postgres://") || url.starts_with("postgresql://"))
+    {
+        problems.push(
+            "database.url (APP_DATABASE_URL) must start with postgres:// or postgresql://".into(),
+        );
+    }
+    // URLが無い場合は個別の接続情報が必要
+    let mut connection_field = |value: Option<String>, key: &str, env: &str| match (&url, value) {
+        (_, Some(value)) => value,
+        (Some(_), None) => String::new(),
+        (None, None) => {
+            problems.push(required(key, env));
+            String::new()
+        }
+    };
+    let host = connection_field(raw.database.host, "database.host", "DATABASE_HOST");
+    let username = connection_field(
+        raw.database.username,
+        "database.username",
+        "DATABASE_USERNAME",
+    );
+    let database_name =
+        connection_field(raw.database.database, "database.database", "DATABASE_NAME");
+    let database = DatabaseConfig {
+        app_database_url: url,
+        host,
+        port: raw.database.port.unwrap_or(5432),
+        username,
+        password: raw.database.password.unwrap_or_default(),
+        database: database_name,
+        max_connections: at_least_one(
+            raw.database.max_connections,
+            defaults.max_connections,
+            "database.max_connections",
+            problems,
+        ),
+        acquire_timeout: positive_secs(
+            raw.database.acquire_timeout_secs,
+            30,
+            "database.acquire_timeout_secs",
+            problems,
+        ),
+        migrate_on_startup: raw.database.migrate_on_startup.unwrap_or(false),
+    };
+
+    let atcoder_problems = AtcoderProblemsConfig {
+        base_endpoint: http_url(
+            raw.atcoder_problems.base_endpoint,
+            "atcoder_problems.base_endpoint",
+            "ATCODER_PROBLEMS_BASE_ENDPOINT",
+            problems,
+        ),
+        difficulty_endpoint: http_url(
+            raw.atcoder_problems.difficulty_endpoint,
+            "atcoder_problems.difficulty_endpoint",
+            "ATCODER_PROBLEMS_DIFFICULTY_ENDPOINT",
+            problems,
+        ),
+        connect_timeout: positive_secs(
+            raw.atcoder_problems.connect_timeout_secs,
+            10,
+            "atcoder_problems.connect_timeout_secs",
+            problems,
+        ),
+        request_timeout: positive_secs(
+            raw.atcoder_problems.request_timeout_secs,
+            30,
+            "atcoder_problems.request_timeout_secs",
+            problems,
+        ),
+        requests_per_second: positive_rate(
+            raw.atcoder_problems.requests_per_second,
+            1.0,
+            "atcoder_problems.requests_per_second",
+            problems,
+        ),
+        max_retries: raw.atcoder_problems.max_retries.unwrap_or(3),
+        retry_backoff: Duration::from_millis(at_least_one(
+            raw.atcoder_problems.retry_backoff_ms,
+            1_000,
+            "atcoder_problems.retry_backoff_ms",
+            problems,
+        )),
+    };
+
+    let auth = auth(environment, raw.auth, problems);
+
+    let log = LogConfig {
+        rust_log: raw.log.rust_log.unwrap_or_else(|| "info".to_string()),
+    };
+
+    if let Some(address) = &raw.metrics.bind_address
+        && address.parse::<SocketAddr>().is_err()
+    {
+        problems.push(format!(
+            "metrics.bind_address (METRICS_BIND_ADDR): {address:?} is not a socket address like 127.0.0.1:9090"
+        ));
+    }
+    let metrics = MetricsConfig {
+        enabled: raw.metrics.enabled.unwrap_or(false),
+        bind_address: raw.metrics.bind_address,
+    };
+
+    if let Some(endpoint) = &raw.telemetry.otlp_endpoint
+        && !is_http_url(endpoint)
+    {
+        problems.push(format!(
+            "telemetry.otlp_endpoint (OTEL_EXPORTER_OTLP_ENDPOINT): {endpoint:?} must be an http(s) URL"
+        ));
+    }
+    let telemetry = TelemetryConfig {
+        otlp_endpoint: raw.telemetry.otlp_endpoint,
+        service_name: raw
+            .telemetry
+            .service_name
+            .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string()),
+    };
+
+    let allowed_origins = raw.cors.allowed_origins.unwrap_or(defaults.allowed_origins);
+    for origin in &allowed_origins {
+        if !is_origin(origin) {
+            problems.push(format!(
+                "cors.allowed_origins (CORS_ALLOWED_ORIGINS): {origin:?} is not an origin like https://atcoder.jp or chrome-extension://<id>"
+            ));
+        }
+    }
+    let cors = CorsConfig {
+        allowed_origins,
+        allow_credentials: raw.cors.allow_credentials.unwrap_or(false),
+        max_age: Duration::from_secs(raw.cors.max_age_secs.unwrap_or(600)),
+    };
+
+    let limits = LimitsConfig {
+        max_contest_group_offset: at_least_one(
+            raw.limits.max_contest_group_offset,
+            5_000,
+            "limits.max_contest_group_offset",
+            problems,
+        ),
+        problem_upsert_chunk_size: at_least_one(
+            raw.limits.problem_upsert_chunk_size,
+            500,
+            "limits.problem_upsert_chunk_size",
+            problems,
+        ),
+        difficulty_fetch_concurrency: at_least_one(
+            raw.limits.difficulty_fetch_concurrency,
+            4,
+            "limits.difficulty_fetch_concurrency",
+            problems,
+        ),
+        difficulty_fetch_budget: at_least_one(
+            raw.limits.difficulty_fetch_budget,
+            1_000,
+            "limits.difficulty_fetch_budget",
+            problems,
+        ),
+    };
+
+    AppConfig {
+        environment,
+        server,
+        database,
+        atcoder_problems,
+        auth,
+        log,
+        metrics,
+        telemetry,
+        cors,
+        limits,
+    }
+}
+
+fn auth(environment: Environment, raw: RawAuth, problems: &mut Vec<String>) -> AuthConfig {
+    let provider = match raw.provider.as_deref() {
+        None | Some("firebase") => AuthProviderConfig::Firebase {
+            project_id: raw.firebase_project_id.unwrap_or_else(|| {
+                problems.push(required("auth.firebase_project_id", "FIREBASE_PROJECT_ID"));
+                String::new()
+            }),
+        },
+        Some("local") => {
+            if environment == Environment::Prd {
+                problems.push(
+                    "auth.provider (AUTH_PROVIDER): local is not allowed when ENV=prd".into(),
+                );
+            }
+            AuthProviderConfig::LocalJwt(local_jwt(raw.local_jwt, problems))
+        }
+        Some(other) => {
+            problems.push(format!(
+                "auth.provider (AUTH_PROVIDER): must be one of firebase, local (got {other:?})"
+            ));
+            AuthProviderConfig::Firebase {
+                project_id: String::new(),
+            }
+        }
+    };
+    AuthConfig { provider }
+}
+
+pub(super) fn local_jwt(raw: RawLocalJwt, problems: &mut Vec<String>) -> LocalJwtConfig {
+    let key = match (raw.secret, raw.public_key_path, raw.jwks_path) {
+        (Some(secret), None, None) => LocalJwtKey::Secret(secret),
+        (None, Some(path), None) => LocalJwtKey::RsaPublicKeyPemPath(path),
+        (None, None, Some(path)) => LocalJwtKey::JwksPath(path),
+        _ => {
+            problems.push(
+                "auth.local_jwt: AUTH_PROVIDER=local requires exactly one of secret (LOCAL_JWT_SECRET), public_key_path (LOCAL_JWT_PUBLIC_KEY_PATH), jwks_path (LOCAL_JWT_JWKS_PATH)".into(),
+            );
+            LocalJwtKey::Secret(String::new())
+        }
+    };
+    LocalJwtConfig {
+        issuer: raw
+            .issuer
+            .unwrap_or_else(|| LOCAL_JWT_DEFAULT_ISSUER.to_string()),
+        audience: raw
+            .audience
+            .unwrap_or_else(|| LOCAL_JWT_DEFAULT_AUDIENCE.to_string()),
+        key,
+    }
+}
+
+fn required(key: &str, env: &str) -> String {
+    format!("{key} is required (set {env} or `{key}` in a config file)")
+}
+
+fn http_url(value: Option<String>, key: &str, env: &str, problems: &mut Vec<String>) -> String {
+    match value {
+        None => {
+            problems.push(required(key, env));
+            String::new()
+        }
+        Some(value) if !is_http_url(&value) => {
+            problems.push(format!("{key} ({env}): {value:?} must be an http(s) URL"));
+            value
+        }
+        Some(value) => value,
+    }
+}
+
+fn positive_secs(
+    value: Option<u64>,
+    default: u64,
+    key: &str,
+    problems: &mut Vec<String>,
+) -> Duration {
+    Duration::from_secs(at_least_one(value, default, key, problems))
+}
+
+fn positive_rate(value: Option<f64>, default: f64, key: &str, problems: &mut Vec<String>) -> f64 {
+    let value = value.unwrap_or(default);
+    if !(value.is_finite() && value > 0.0) {
+        problems.push(format!("{key} must be a positive number"));
+    }
+    value
+}
+
+fn at_least_one<T>(value: Option<T>, default: T, key: &str, problems: &mut Vec<String>) -> T
+where
+    T: PartialOrd + From<u8>,
+{
+    let value = value.unwrap_or(default);
+    if value < T::from(1) {
+        problems.push(format!("{key} must be at least 1"));
+    }
+    value
+}
+
+fn is_http_url(value: &str) -> bool {
+    reqwest::Url::parse(value).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+// scheme://host[:port] の形でパスを含まないこと
+fn is_origin(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    !scheme.is_empty()
+        && !rest.is_empty()
+        && !rest.contains(['/', '?', '#'])
+        && reqwest::Url::parse(value).is_ok_and(|url| url.host_str().is_some())
+}
@@ -1,3 +1,9 @@
+use std::fmt;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Environment {
     Dev,
     Stg,
@@ -12,3 +18,23 @@ pub fn parse_env(env: &str) -> Environment {
         _ => Environment::Dev,
     }
 }
+
+impl Environment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Dev => "dev",
+            Environment::Stg => "stg",
+            Environment::Prd => "prd",
+        }
+    }
+
+    pub fn is_dev(&self) -> bool {
+        matches!(self, Environment::Dev)
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -51,6 +51,19 @@ where
             error_code: error_code.into(),
         }
     }
+
+    // クライアントが分岐できるよう、エラーの詳細をdataに載せる
+    pub fn err_with_data(
+        status: StatusCode,
+        msg: impl Into<String>,
+        error_code: impl Into<String>,
+        data: T,
+    ) -> Self {
+        Self {
+            data: Some(data),
+            ..Self::err_with_code(status, msg, Some(error_code.into()))
+        }
+    }
 }
 
 impl<T> IntoResponse for ApiResponse<T>
@@ -0,0 +1,207 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use backend::init_logger;
+use domain::model::{problem_import::ProblemImportChanges, user::Role};
+use interface::handler::problem::import_problems_usecase;
+use registry::Registry;
+use shared::config::AppConfig;
+use usecase::{
+    catalog::get_stats::GetCatalogStatsUsecase,
+    solution::{
+        delete::DeleteSolutionUsecase, delete_comment::DeleteCommentUsecase,
+        set_comment_hidden::SetCommentHiddenUsecase, set_hidden::SetSolutionHiddenUsecase,
+    },
+    user::{change_role::ChangeRoleUsecase, revoke_tokens::RevokeTokensUsecase},
+};
+use uuid::Uuid;
+
+const USAGE: &str = "usage: admin <command>
+
+commands:
+  grant-role <uid> <role>        set the user's role (user, admin)
+  revoke-role <uid>              set the user's role back to user
+  revoke-tokens <uid>            reject the user's tokens issued before now
+  delete-solution <id>
+  delete-comment <id>
+  hide-solution <id>             hide from lists and detail pages
+  unhide-solution <id>
+  hide-comment <id>
+  unhide-comment <id>
+  reimport-contest <code>        re-fetch one contest's problems and difficulties
+  recompute-difficulties         re-fetch difficulties for every problem
+  stats                          print catalog statistics";
+
+enum Command {
+    ChangeRole { uid: String, role: Role },
+    RevokeTokens { uid: String },
+    DeleteSolution { id: Uuid },
+    DeleteComment { id: Uuid },
+    SetSolutionHidden { id: Uuid, hidden: bool },
+    SetCommentHidden { id: Uuid, hidden: bool },
+    ReimportContest { contest_code: String },
+    RecomputeDifficulties,
+    Stats,
+}
+
+fn parse_id(value: &str) -> Result<Uuid> {
+    Uuid::parse_str(value).with_context(|| format!("invalid id: {value}"))
+}
+
+fn parse_args() -> Result<Command> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let command = match args.as_slice() {
+        ["grant-role", uid, role] => Command::ChangeRole {
+            uid: uid.to_string(),
+            role: Role::from_str(role).with_context(|| format!("unknown role: {role}"))?,
+        },
+        ["revoke-role", uid] => Command::ChangeRole {
+            uid: uid.to_string(),
+            role: Role::User,
+        },
+        ["revoke-tokens", uid] => Command::RevokeTokens {
+            uid: uid.to_string(),
+        },
+        ["delete-solution", id] => Command::DeleteSolution { id: parse_id(id)? },
+        ["delete-comment", id] => Command::DeleteComment { id: parse_id(id)? },
+        ["hide-solution", id] => Command::SetSolutionHidden {
+            id: parse_id(id)?,
+            hidden: true,
+        },
+        ["unhide-solution", id] => Command::SetSolutionHidden {
+            id: parse_id(id)?,
+            hidden: false,
+        },
+        ["hide-comment", id] => Command::SetCommentHidden {
+            id: parse_id(id)?,
+            hidden: true,
+        },
+        ["unhide-comment", id] => Command::SetCommentHidden {
+            id: parse_id(id)?,
+            hidden: false,
+        },
+        ["reimport-contest", contest_code] => Command::ReimportContest {
+            contest_code: contest_code.to_string(),
+        },
+        ["recompute-difficulties"] => Command::RecomputeDifficulties,
+        ["stats"] => Command::Stats,
+        _ => bail!("{USAGE}"),
+    };
+    Ok(command)
+}
+
+fn format_changes(changes: &ProblemImportChanges) -> String {
+    format!(
+        "{} inserted, {} updated, {} unchanged, {} missing upstream, {} difficulty fetch failure(s), {} deferred",
+        changes.inserted,
+        changes.updated,
+        changes.unchanged,
+        changes.removed,
+        changes.difficulty_fetch_failures,
+        changes.difficulty_fetch_deferred
+    )
+}
+
+async fn run(reg: &Registry, command: Command) -> Result<()> {
+    match command {
+        Command::ChangeRole { uid, role } => {
+            let output = ChangeRoleUsecase::new(reg.user_repository())
+                .run(uid, role)
+                .await?;
+            println!("{} is now {}", output.id, output.role);
+        }
+        Command::RevokeTokens { uid } => {
+            let output = RevokeTokensUsecase::new(reg.user_repository(), reg.session_repository())
+                .run(uid)
+                .await?;
+            println!("revoked tokens of {}", output.id);
+        }
+        Command::DeleteSolution { id } => {
+            DeleteSolutionUsecase::new(reg.solution_tx_manager(), reg.solution_service())
+                .run_as_admin(id)
+                .await?;
+            println!("deleted solution {id}");
+        }
+        Command::DeleteComment { id } => {
+            DeleteCommentUsecase::new(reg.solution_tx_manager(), reg.solution_service())
+                .run_as_admin(id)
+                .await?;
+            println!("deleted comment {id}");
+        }
+        Command::SetSolutionHidden { id, hidden } => {
+            SetSolutionHiddenUsecase::new(reg.solution_tx_manager())
+                .run(id, hidden)
+                .await?;
+            println!("{} solution {id}", if hidden { "hid" } else { "unhid" });
+        }
+        Command::SetCommentHidden { id, hidden } => {
+            SetCommentHiddenUsecase::new(reg.solution_tx_manager())
+                .run(id, hidden)
+                .await?;
+            println!("{} comment {id}", if hidden { "hid" } else { "unhid" });
+        }
+        Command::ReimportContest { contest_code } => {
+            let changes = import_problems_usecase(reg)
+                .run_for_contest(&contest_code)
+                .await?;
+            println!("re-imported {contest_code}: {}", format_changes(&changes));
+        }
+        Command::RecomputeDifficulties => {
+            let changes = import_problems_usecase(reg)
+                .recompute_difficulties()
+                .await?;
+            println!("recomputed difficulties: {}", format_changes(&changes));
+        }
+        Command::Stats => {
+            let output = GetCatalogStatsUsecase::new(
+                reg.catalog_service(),
+                reg.problem_import_run_repository(),
+            )
+            .run()
+            .await?;
+            let stats = output.stats;
+            println!("contests                     {}", stats.contests);
+            println!("problems                     {}", stats.problems);
+            println!(
+                "problems without difficulty  {}",
+                stats.problems_without_difficulty
+            );
+            println!(
+                "users                        {} ({} admin)",
+                stats.users, stats.admins
+            );
+            println!(
+                "solutions                    {} ({} hidden)",
+                stats.solutions, stats.hidden_solutions
+            );
+            println!(
+                "comments                     {} ({} hidden)",
+                stats.comments, stats.hidden_comments
+            );
+            println!("votes                        {}", stats.votes);
+            match output.last_problem_import {
+                Some(run) => println!(
+                    "last problem import          {} at {}",
+                    run.outcome,
+                    run.finished_at.to_rfc3339()
+                ),
+                None => println!("last problem import          never"),
+            }
+        }
+    }
+    Ok(())
+}
+
+// 運用作業用。サーバーと同じ設定で、既存のusecaseを呼び出す
+#[tokio::main]
+async fn main() -> Result<()> {
+    let command = parse_args()?;
+    let app_config = AppConfig::new()?;
+    let _telemetry = init_logger(&app_config)?;
+    let registry = Registry::new(app_config).context("failed to build the authenticator")?;
+
+    let result = run(&registry, command).await;
+    registry.close_pool().await;
+    result
+}
@@ -5,7 +5,12 @@ use shared::config::AppConfig;
 #[tokio::main]
 async fn main() -> Result<()> {
     let app_config = AppConfig::new()?;
-    init_logger(&app_config)?;
+    // 解決済みの設定を秘匿値を伏せて表示し、サーバーは起動しない
+    if std::env::args().skip(1).any(|arg| arg == "--print-config") {
+        print!("{}", app_config.to_redacted_toml()?);
+        return Ok(());
+    }
+    let _telemetry = init_logger(&app_config)?;
     run(app_config).await?;
     Ok(())
 }
@@ -0,0 +1,85 @@
+use anyhow::{Context, Result, bail};
+use infrastructure::database::migrate::{self, MigrationEntry};
+use shared::config::AppConfig;
+
+const USAGE: &str = "usage: migrate status | up [--dry-run] | down <version> [--dry-run]";
+
+enum Command {
+    Status,
+    Up { dry_run: bool },
+    // version より新しいものを戻す。0 は全て戻す
+    Down { version: i64, dry_run: bool },
+}
+
+fn parse_args() -> Result<Command> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let dry_run = args.iter().any(|arg| arg == "--dry-run");
+    let positional = args
+        .iter()
+        .filter(|arg| *arg != "--dry-run")
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    match positional.as_slice() {
+        ["status"] if !dry_run => Ok(Command::Status),
+        ["up"] => Ok(Command::Up { dry_run }),
+        ["down", version] => Ok(Command::Down {
+            version: version
+                .parse()
+                .with_context(|| format!("invalid version: {version}\n{USAGE}"))?,
+            dry_run,
+        }),
+        _ => bail!("{USAGE}"),
+    }
+}
+
+fn print_entries(entries: &[MigrationEntry]) {
+    for entry in entries {
+        println!(
+            "{:<9} {} {}",
+            entry.state.as_str(),
+            entry.version,
+            entry.description
+        );
+    }
+}
+
+// 埋め込んだマイグレーションを、サーバーと同じ設定のDBに適用する
+#[tokio::main]
+async fn main() -> Result<()> {
+    let command = parse_args()?;
+    let config = AppConfig::new()?;
+    let mut conn = migrate::connect(&config.database)
+        .await
+        .context("failed to connect to the database")?;
+
+    match command {
+        Command::Status => print_entries(&migrate::status(&mut conn).await?),
+        Command::Up { dry_run: true } => {
+            let pending = migrate::pending(&mut conn).await?;
+            println!("{} migration(s) would be applied", pending.len());
+            print_entries(&pending);
+        }
+        Command::Up { dry_run: false } => {
+            let applied = migrate::up(&mut conn).await?;
+            println!("applied {} migration(s)", applied.len());
+            print_entries(&applied);
+        }
+        Command::Down {
+            version,
+            dry_run: true,
+        } => {
+            let reverted = migrate::revertible(&mut conn, version).await?;
+            println!("{} migration(s) would be reverted", reverted.len());
+            print_entries(&reverted);
+        }
+        Command::Down {
+            version,
+            dry_run: false,
+        } => {
+            let reverted = migrate::down_to(&mut conn, version).await?;
+            println!("reverted {} migration(s)", reverted.len());
+            print_entries(&reverted);
+        }
+    }
+    Ok(())
+}
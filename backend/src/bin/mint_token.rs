@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use infrastructure::ports::external::local_jwt::LocalJwtIssuer;
+use shared::config::LocalJwtConfig;
+
+const USAGE: &str = "usage: mint_token --uid <uid> [--email <email>] [--ttl-secs <seconds>]";
+
+struct Args {
+    uid: String,
+    email: Option<String>,
+    ttl: Duration,
+}
+
+fn parse_args() -> Result<Args> {
+    let mut uid = None;
+    let mut email = None;
+    let mut ttl = Duration::from_secs(3600);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().with_context(|| format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--uid" => uid = Some(value()?),
+            "--email" => email = Some(value()?),
+            "--ttl-secs" => ttl = Duration::from_secs(value()?.parse()?),
+            _ => bail!("unknown argument: {arg}\n{USAGE}"),
+        }
+    }
+    Ok(Args {
+        uid: uid.context(USAGE)?,
+        email,
+        ttl,
+    })
+}
+
+// AUTH_PROVIDER=local で起動したサーバーが受け付けるトークンを標準出力に出す
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    let config = LocalJwtConfig::from_env()?;
+    let private_key = std::env::var("LOCAL_JWT_PRIVATE_KEY_PATH").ok();
+    let kid = std::env::var("LOCAL_JWT_KID").ok();
+    let issuer = LocalJwtIssuer::from_config(&config, private_key.as_deref(), kid)?;
+    println!(
+        "{}",
+        issuer.mint(&args.uid, args.email.as_deref(), args.ttl)?
+    );
+    Ok(())
+}
@@ -1,14 +1,16 @@
-use anyhow::Result;
-use backend::{init_logger, run_daily_job};
+use anyhow::{Context, Result};
+use backend::{init_logger, init_metrics, run_daily_job};
 use registry::Registry;
 use shared::config::AppConfig;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let dry_run = std::env::args().skip(1).any(|arg| arg == "--dry-run");
     let app_config = AppConfig::new()?;
-    init_logger(&app_config)?;
-    let registry = Registry::new(app_config);
+    let _telemetry = init_logger(&app_config)?;
+    let metrics_handle = init_metrics(&app_config)?;
+    let registry = Registry::new(app_config).context("failed to build the authenticator")?;
 
-    run_daily_job(&registry).await?;
+    run_daily_job(&registry, metrics_handle, dry_run).await?;
     Ok(())
 }
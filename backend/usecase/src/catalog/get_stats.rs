@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::problem_import::ProblemImportRunRepository;
+
+use crate::{
+    model::catalog::{CatalogError, CatalogStatsOutput},
+    service::catalog::CatalogService,
+};
+
+#[derive(new)]
+pub struct GetCatalogStatsUsecase {
+    service: Arc<dyn CatalogService>,
+    problem_import_run_repository: Arc<dyn ProblemImportRunRepository>,
+}
+
+impl GetCatalogStatsUsecase {
+    pub async fn run(&self) -> Result<CatalogStatsOutput, CatalogError> {
+        let stats = self.service.get_catalog_stats().await?;
+        let last_problem_import = self.problem_import_run_repository.latest().await?;
+        Ok(CatalogStatsOutput {
+            stats,
+            last_problem_import,
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    model::problem::{
+        ContestReclassification, ContestSeries, ContestSeriesCatalog, ContestSeriesDefinition,
+    },
+    ports::repository::contest_series::ContestSeriesRepository,
+};
+use tracing::info;
+
+use crate::model::contests::{
+    ContestError,
+    add_series::{
+        AddContestSeriesInput, AddContestSeriesOutput, MAX_SERIES_CODE_LENGTH,
+        MAX_SERIES_DISPLAY_NAME_LENGTH,
+    },
+};
+
+#[derive(new)]
+pub struct AddContestSeriesUsecase {
+    contest_series_repository: Arc<dyn ContestSeriesRepository>,
+}
+
+impl AddContestSeriesUsecase {
+    pub async fn run(
+        &self,
+        input: AddContestSeriesInput,
+    ) -> Result<AddContestSeriesOutput, ContestError> {
+        let definition = validate(input)?;
+        let mut definitions = self.contest_series_repository.list().await?;
+        if definitions.iter().any(|d| d.code == definition.code) {
+            return Err(ContestError::Conflict(format!(
+                "contest series already exists: {}",
+                definition.code
+            )));
+        }
+        definitions.push(definition.clone());
+        let catalog = ContestSeriesCatalog::new(definitions)
+            .map_err(|e| ContestError::BadRequest(e.to_string()))?;
+
+        // 追加後の定義で全コンテストを分類し直し、変わるものだけ付け替える
+        let reclassified = self
+            .contest_series_repository
+            .list_contest_assignments()
+            .await?
+            .into_iter()
+            .filter_map(|(contest_code, from)| {
+                let to = catalog.classify(&contest_code);
+                (to != from).then_some(ContestReclassification {
+                    contest_code,
+                    from,
+                    to,
+                })
+            })
+            .collect::<Vec<_>>();
+        self.contest_series_repository
+            .create_and_reclassify(&definition, &reclassified)
+            .await?;
+        info!(
+            series = %definition.code,
+            reclassified = reclassified.len(),
+            "contest series added"
+        );
+
+        Ok(AddContestSeriesOutput {
+            definition,
+            reclassified,
+            catalog,
+        })
+    }
+}
+
+fn validate(input: AddContestSeriesInput) -> Result<ContestSeriesDefinition, ContestError> {
+    let code = input.code.trim().to_ascii_uppercase();
+    let valid_code = code.len() <= MAX_SERIES_CODE_LENGTH
+        && code.starts_with(|c: char| c.is_ascii_uppercase())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    if !valid_code {
+        return Err(ContestError::BadRequest(format!(
+            "code must be 1 to {MAX_SERIES_CODE_LENGTH} letters, digits or underscores starting with a letter"
+        )));
+    }
+    let display_name = input.display_name.trim().to_string();
+    if display_name.is_empty() || display_name.chars().count() > MAX_SERIES_DISPLAY_NAME_LENGTH {
+        return Err(ContestError::BadRequest(format!(
+            "displayName must be 1 to {MAX_SERIES_DISPLAY_NAME_LENGTH} characters"
+        )));
+    }
+    let code_pattern = input.code_pattern.trim().to_string();
+    if code_pattern.is_empty() {
+        return Err(ContestError::BadRequest(
+            "codePattern cannot be empty".to_string(),
+        ));
+    }
+    Ok(ContestSeriesDefinition {
+        code: ContestSeries::new(code),
+        display_name,
+        code_pattern: Some(code_pattern),
+        sort_order: input.sort_order,
+    })
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    model::problem::ContestSeriesCatalog,
+    ports::repository::contest_series::ContestSeriesRepository,
+};
+
+use crate::model::contests::ContestError;
+
+#[derive(new)]
+pub struct LoadContestSeriesUsecase {
+    contest_series_repository: Arc<dyn ContestSeriesRepository>,
+}
+
+impl LoadContestSeriesUsecase {
+    pub async fn run(&self) -> Result<ContestSeriesCatalog, ContestError> {
+        let definitions = self.contest_series_repository.list().await?;
+        ContestSeriesCatalog::new(definitions).map_err(|e| ContestError::DBError(e.to_string()))
+    }
+}
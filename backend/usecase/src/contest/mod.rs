@@ -1 +1,3 @@
+pub mod add_series;
 pub mod get_by_series;
+pub mod load_series;
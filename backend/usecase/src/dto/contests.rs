@@ -1,8 +1,14 @@
+use chrono::{DateTime, Utc};
+
 use crate::model::contests::ContestListItem;
 
 pub struct ContestListItemView {
     pub code: String,
     pub series_code: String,
+    pub title: Option<String>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub duration_second: Option<i64>,
+    pub rate_change: Option<String>,
 }
 
 impl From<ContestListItem> for ContestListItemView {
@@ -10,6 +16,10 @@ impl From<ContestListItem> for ContestListItemView {
         Self {
             code: value.code,
             series_code: value.series_code,
+            title: value.title,
+            start_at: value.start_at,
+            duration_second: value.duration_second,
+            rate_change: value.rate_change,
         }
     }
 }
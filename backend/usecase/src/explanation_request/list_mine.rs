@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    model::explanation_request::ExplanationRequest,
+    ports::repository::explanation_request::ExplanationRequestRepository,
+};
+
+use crate::model::explanation_request::ExplanationRequestError;
+
+#[derive(new)]
+pub struct ListMyExplanationRequestsUsecase {
+    explanation_request_repository: Arc<dyn ExplanationRequestRepository>,
+}
+
+impl ListMyExplanationRequestsUsecase {
+    pub async fn run(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<ExplanationRequest>, ExplanationRequestError> {
+        Ok(self
+            .explanation_request_repository
+            .list_by_user(&user_id)
+            .await?)
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    model::{
+        explanation_request::{WantedProblem, WantedProblemFilter},
+        problem::{ContestSeries, DifficultyBand},
+    },
+    ports::repository::explanation_request::ExplanationRequestRepository,
+};
+
+use crate::model::explanation_request::ExplanationRequestError;
+
+const DEFAULT_WANTED_LIMIT: usize = 50;
+const MAX_WANTED_LIMIT: usize = 100;
+
+#[derive(new)]
+pub struct ListWantedProblemsUsecase {
+    explanation_request_repository: Arc<dyn ExplanationRequestRepository>,
+}
+
+impl ListWantedProblemsUsecase {
+    pub async fn run(
+        &self,
+        series: ContestSeries,
+        band: Option<DifficultyBand>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<WantedProblem>, ExplanationRequestError> {
+        let filter = WantedProblemFilter {
+            series,
+            band,
+            limit: limit
+                .unwrap_or(DEFAULT_WANTED_LIMIT)
+                .clamp(1, MAX_WANTED_LIMIT) as i64,
+            offset: offset.unwrap_or(0) as i64,
+        };
+        Ok(self
+            .explanation_request_repository
+            .list_wanted(&filter)
+            .await?)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod list_mine;
+pub mod list_wanted;
+pub mod request;
+pub mod withdraw;
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::explanation_request::ExplanationRequestRepository;
+
+use crate::model::explanation_request::ExplanationRequestError;
+use crate::service::solution::SolutionService;
+
+#[derive(new)]
+pub struct RequestExplanationUsecase {
+    explanation_request_repository: Arc<dyn ExplanationRequestRepository>,
+    service: Arc<dyn SolutionService>,
+}
+
+impl RequestExplanationUsecase {
+    // 新たにリクエストした場合はtrue。同じ問題へのリクエストは1人1件まで。
+    // 解説が無くなった後に達成済みのリクエストをし直すと、未達成に戻してtrueを返す
+    pub async fn run(
+        &self,
+        user_id: String,
+        problem_id: &str,
+    ) -> Result<bool, ExplanationRequestError> {
+        let problem_id = problem_id.trim();
+        if problem_id.is_empty() {
+            return Err(ExplanationRequestError::BadRequest(
+                "problemId cannot be empty".to_string(),
+            ));
+        }
+        if !self.service.problem_exists(problem_id).await? {
+            return Err(ExplanationRequestError::NotFound(
+                "problem not found".to_string(),
+            ));
+        }
+        let stats = self
+            .service
+            .get_solution_stats_by_problem_ids(&[problem_id.to_string()])
+            .await?;
+        if stats.iter().any(|stat| stat.solution_count > 0) {
+            return Err(ExplanationRequestError::Conflict(
+                "problem already has solutions".to_string(),
+            ));
+        }
+
+        Ok(self
+            .explanation_request_repository
+            .create(&user_id, problem_id)
+            .await?)
+    }
+}
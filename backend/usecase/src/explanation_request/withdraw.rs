@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::explanation_request::ExplanationRequestRepository;
+
+use crate::model::explanation_request::ExplanationRequestError;
+
+#[derive(new)]
+pub struct WithdrawExplanationRequestUsecase {
+    explanation_request_repository: Arc<dyn ExplanationRequestRepository>,
+}
+
+impl WithdrawExplanationRequestUsecase {
+    pub async fn run(
+        &self,
+        user_id: String,
+        problem_id: &str,
+    ) -> Result<(), ExplanationRequestError> {
+        self.explanation_request_repository
+            .delete(&user_id, problem_id.trim())
+            .await?;
+        Ok(())
+    }
+}
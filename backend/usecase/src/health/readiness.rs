@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::Utc;
+use derive_new::new;
+use domain::ports::{
+    external::auth::AuthenticatorPort,
+    repository::{health::HealthCheckRepository, problem_import::ProblemImportRunRepository},
+};
+
+use crate::model::health::{DatabaseCheck, KeySetCheck, ReadinessReport};
+
+#[derive(new)]
+pub struct CheckReadinessUsecase {
+    health_check_repository: Arc<dyn HealthCheckRepository>,
+    problem_import_run_repository: Arc<dyn ProblemImportRunRepository>,
+    auth_port: Arc<dyn AuthenticatorPort>,
+}
+
+impl CheckReadinessUsecase {
+    pub async fn run(&self) -> ReadinessReport {
+        let started = Instant::now();
+        let reachable = self.health_check_repository.check_db().await;
+        let database = DatabaseCheck {
+            reachable,
+            latency: started.elapsed(),
+        };
+
+        let migrations = self.health_check_repository.migration_status().await;
+
+        let status = self.auth_port.key_set_status().await;
+        let jwks = KeySetCheck {
+            fresh: status.is_fresh(Utc::now()),
+            status,
+        };
+
+        let last_problem_import = self.problem_import_run_repository.latest().await;
+
+        ReadinessReport {
+            database,
+            migrations,
+            jwks,
+            last_problem_import,
+        }
+    }
+}
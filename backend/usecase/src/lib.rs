@@ -1,6 +1,11 @@
+pub mod catalog;
 pub mod contest;
 pub mod dto;
+pub mod explanation_request;
+pub mod health;
+pub mod metrics;
 pub mod model;
+pub mod personal_token;
 pub mod problem;
 pub mod service;
 pub mod solution;
@@ -0,0 +1,11 @@
+// usecaseが記録するメトリクス名。記録先(Prometheus等)はエントリポイントで設定する
+pub const SOLUTIONS_CREATED_TOTAL: &str = "solutions_created_total";
+pub const COMMENTS_CREATED_TOTAL: &str = "comments_created_total";
+pub const VOTES_CREATED_TOTAL: &str = "votes_created_total";
+pub const EXPLANATION_REQUESTS_FULFILLED_TOTAL: &str = "explanation_requests_fulfilled_total";
+
+pub const PROBLEM_IMPORT_RUNS_TOTAL: &str = "problem_import_runs_total";
+pub const PROBLEM_IMPORT_DURATION_SECONDS: &str = "problem_import_duration_seconds";
+pub const PROBLEM_IMPORT_PROBLEMS: &str = "problem_import_problems";
+pub const PROBLEM_IMPORT_DIFFICULTY_FETCH_FAILURES_TOTAL: &str =
+    "problem_import_difficulty_fetch_failures_total";
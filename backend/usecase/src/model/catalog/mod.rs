@@ -0,0 +1,34 @@
+use domain::{error::repository::RepositoryError, model::problem_import::ProblemImportRun};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Default)]
+pub struct CatalogStats {
+    pub contests: i64,
+    pub problems: i64,
+    pub problems_without_difficulty: i64,
+    pub users: i64,
+    pub admins: i64,
+    pub solutions: i64,
+    pub hidden_solutions: i64,
+    pub comments: i64,
+    pub hidden_comments: i64,
+    pub votes: i64,
+}
+
+#[derive(Debug)]
+pub struct CatalogStatsOutput {
+    pub stats: CatalogStats,
+    pub last_problem_import: Option<ProblemImportRun>,
+}
+
+#[derive(Debug, Error)]
+pub enum CatalogError {
+    #[error("{0}")]
+    DBError(String),
+}
+
+impl From<RepositoryError> for CatalogError {
+    fn from(value: RepositoryError) -> Self {
+        CatalogError::DBError(value.to_string())
+    }
+}
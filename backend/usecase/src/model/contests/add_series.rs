@@ -0,0 +1,21 @@
+use domain::model::problem::{
+    ContestReclassification, ContestSeriesCatalog, ContestSeriesDefinition,
+};
+
+pub const MAX_SERIES_CODE_LENGTH: usize = 32;
+pub const MAX_SERIES_DISPLAY_NAME_LENGTH: usize = 100;
+
+pub struct AddContestSeriesInput {
+    pub code: String,
+    pub display_name: String,
+    pub code_pattern: String,
+    pub sort_order: i32,
+}
+
+#[derive(Debug)]
+pub struct AddContestSeriesOutput {
+    pub definition: ContestSeriesDefinition,
+    pub reclassified: Vec<ContestReclassification>,
+    // 追加後のシリーズ一覧
+    pub catalog: ContestSeriesCatalog,
+}
@@ -1,10 +1,18 @@
+use chrono::{DateTime, Utc};
 use domain::error::repository::RepositoryError;
 use thiserror::Error;
 
+pub mod add_series;
+
 #[derive(Debug, Clone)]
 pub struct ContestListItem {
     pub code: String,
     pub series_code: String,
+    // contests.jsonを取り込むまでは空
+    pub title: Option<String>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub duration_second: Option<i64>,
+    pub rate_change: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -12,6 +20,8 @@ pub enum ContestError {
     #[error("{0}")]
     BadRequest(String),
     #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
     DBError(String),
     #[error("{0}")]
     NotFound(String),
@@ -22,7 +32,7 @@ impl From<RepositoryError> for ContestError {
         match value {
             RepositoryError::NotFound(msg) => ContestError::NotFound(msg),
             RepositoryError::TransactionError(msg) => ContestError::DBError(msg.to_string()),
-            RepositoryError::UniqueViolation(msg) => ContestError::DBError(msg),
+            RepositoryError::UniqueViolation(msg) => ContestError::Conflict(msg),
             RepositoryError::ForeignKeyViolation(msg) => ContestError::DBError(msg.to_string()),
             RepositoryError::NotNullViolation(msg) => ContestError::DBError(msg.to_string()),
             RepositoryError::CheckViolation(msg) => ContestError::DBError(msg.to_string()),
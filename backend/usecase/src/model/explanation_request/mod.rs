@@ -0,0 +1,30 @@
+use domain::error::repository::RepositoryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExplanationRequestError {
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    DBError(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Conflict(String),
+}
+
+impl From<RepositoryError> for ExplanationRequestError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::NotFound(msg) => ExplanationRequestError::NotFound(msg),
+            RepositoryError::ForeignKeyViolation(msg) => ExplanationRequestError::NotFound(msg),
+            RepositoryError::TransactionError(msg) => ExplanationRequestError::DBError(msg),
+            RepositoryError::UniqueViolation(msg) => ExplanationRequestError::Conflict(msg),
+            RepositoryError::NotNullViolation(msg) => ExplanationRequestError::DBError(msg),
+            RepositoryError::CheckViolation(msg) => ExplanationRequestError::DBError(msg),
+            RepositoryError::Connection(msg) => ExplanationRequestError::DBError(msg),
+            RepositoryError::Query(msg) => ExplanationRequestError::DBError(msg),
+            RepositoryError::Unexpected(msg) => ExplanationRequestError::DBError(msg),
+        }
+    }
+}
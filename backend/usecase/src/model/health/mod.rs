@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use domain::{
+    error::repository::RepositoryError, model::health::MigrationStatus,
+    model::problem_import::ProblemImportRun, ports::external::auth::KeySetStatus,
+};
+
+pub struct DatabaseCheck {
+    pub reachable: bool,
+    pub latency: Duration,
+}
+
+pub struct KeySetCheck {
+    pub status: KeySetStatus,
+    pub fresh: bool,
+}
+
+pub struct ReadinessReport {
+    pub database: DatabaseCheck,
+    pub migrations: Result<MigrationStatus, RepositoryError>,
+    pub jwks: KeySetCheck,
+    pub last_problem_import: Result<Option<ProblemImportRun>, RepositoryError>,
+}
+
+impl ReadinessReport {
+    // JWKSは初回認証時に取得され、インポートは日次ジョブのため判定には含めない
+    pub fn is_ready(&self) -> bool {
+        self.database.reachable
+            && self
+                .migrations
+                .as_ref()
+                .is_ok_and(|status| !status.is_behind())
+    }
+}
@@ -1,4 +1,8 @@
+pub mod catalog;
 pub mod contests;
+pub mod explanation_request;
+pub mod health;
+pub mod personal_token;
 pub mod problem;
 pub mod solution;
 pub mod user;
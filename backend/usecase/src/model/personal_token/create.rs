@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Validate)]
+pub struct CreatePersonalTokenInput {
+    pub user_id: String,
+    #[validate(length(min = 1, max = 100), custom(function = "validate_not_blank"))]
+    pub name: String,
+    #[validate(length(min = 1, message = "scopes cannot be empty"))]
+    pub scopes: Vec<String>,
+}
+
+fn validate_not_blank(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Err(ValidationError::new("blank"));
+    }
+    Ok(())
+}
+
+#[derive(new, Debug)]
+pub struct CreatePersonalTokenOutput {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<String>,
+    // 平文は発行時のレスポンスでのみ返す
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use domain::model::personal_token::PersonalToken;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct PersonalTokenListItem {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub token_prefix: String,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PersonalToken> for PersonalTokenListItem {
+    fn from(value: PersonalToken) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            scopes: value.scopes.iter().map(|s| s.to_string()).collect(),
+            token_prefix: value.token_prefix,
+            last_used_at: value.last_used_at,
+            created_at: value.created_at,
+        }
+    }
+}
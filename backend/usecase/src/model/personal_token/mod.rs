@@ -0,0 +1,32 @@
+pub mod create;
+pub mod list;
+pub mod revoke;
+
+use domain::error::repository::RepositoryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PersonalTokenError {
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    DBError(String),
+    #[error("{0}")]
+    NotFound(String),
+}
+
+impl From<RepositoryError> for PersonalTokenError {
+    fn from(value: RepositoryError) -> Self {
+        match value {
+            RepositoryError::NotFound(msg) => PersonalTokenError::NotFound(msg),
+            RepositoryError::ForeignKeyViolation(msg) => PersonalTokenError::NotFound(msg),
+            RepositoryError::TransactionError(msg) => PersonalTokenError::DBError(msg),
+            RepositoryError::UniqueViolation(msg) => PersonalTokenError::DBError(msg),
+            RepositoryError::NotNullViolation(msg) => PersonalTokenError::DBError(msg),
+            RepositoryError::CheckViolation(msg) => PersonalTokenError::DBError(msg),
+            RepositoryError::Connection(msg) => PersonalTokenError::DBError(msg),
+            RepositoryError::Query(msg) => PersonalTokenError::DBError(msg),
+            RepositoryError::Unexpected(msg) => PersonalTokenError::DBError(msg),
+        }
+    }
+}
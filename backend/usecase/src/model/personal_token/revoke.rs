@@ -0,0 +1,7 @@
+use derive_new::new;
+use uuid::Uuid;
+
+#[derive(new, Debug)]
+pub struct RevokePersonalTokenOutput {
+    pub id: Uuid,
+}
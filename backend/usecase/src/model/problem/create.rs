@@ -1,4 +1,16 @@
-use domain::error::{external::ExternalError, repository::RepositoryError};
+use domain::{
+    error::{external::ExternalError, repository::RepositoryError},
+    model::problem::{ContestSeriesParseError, Problem},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImportProblemsSettings {
+    pub chunk_size: usize,
+    // 同時に待つ難易度の取得数。リクエストの頻度はport側で制限する
+    pub difficulty_fetch_concurrency: usize,
+    // 1回の取り込みで難易度を取得する最大件数
+    pub difficulty_fetch_budget: usize,
+}
 
 #[derive(thiserror::Error, Debug)]
 pub enum ImportProblemsUsecaseError {
@@ -6,4 +18,42 @@ pub enum ImportProblemsUsecaseError {
     Fetch(#[from] ExternalError),
     #[error(transparent)]
     Repository(#[from] RepositoryError),
+    #[error("contest not found in AtCoder Problems: {0}")]
+    ContestNotFound(String),
+    #[error(transparent)]
+    ContestSeries(#[from] ContestSeriesParseError),
+}
+
+// 書き込まずに取り込みを試した結果
+#[derive(Debug, Clone)]
+pub struct ImportDryRunReport {
+    pub fetched: usize,
+    pub inserts: Vec<Problem>,
+    pub updates: Vec<ProblemUpdate>,
+    pub unchanged: usize,
+    // problemsにあるが取得結果に無い
+    pub removed: Vec<Problem>,
+    pub duplicate_indexes: Vec<DuplicateProblemIndex>,
+    pub unknown_series: Vec<UnknownSeriesContest>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProblemUpdate {
+    pub before: Problem,
+    pub after: Problem,
+}
+
+// 取り込み後に同じ(contest_code, problem_index)を持つ問題
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateProblemIndex {
+    pub contest_code: String,
+    pub problem_index: String,
+    pub problem_ids: Vec<String>,
+}
+
+// 書き込む問題のうち、既知のシリーズに当てはまらずOTHERになるコンテスト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownSeriesContest {
+    pub contest_code: String,
+    pub problem_ids: Vec<String>,
 }
@@ -1 +1,7 @@
+use domain::model::problem::{ContestMetadata, Problem};
 
+pub struct ContestProblems {
+    // contests.jsonを取り込むまでは空
+    pub contest: Option<ContestMetadata>,
+    pub problems: Vec<Problem>,
+}
@@ -1,8 +1,13 @@
-use std::{cmp::Reverse, collections::BTreeMap};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+};
 
-use domain::model::problem::Problem;
+use chrono::{DateTime, Utc};
+use domain::model::problem::{ContestSortKey, Problem};
 
-pub struct ContestGroupCollection(pub BTreeMap<Reverse<String>, Vec<Problem>>);
+// 新しいコンテストが先に来る
+pub struct ContestGroupCollection(pub BTreeMap<Reverse<ContestSortKey>, Vec<Problem>>);
 
 pub struct ContestGroupPage {
     pub items: ContestGroupCollection,
@@ -10,12 +15,19 @@ pub struct ContestGroupPage {
     pub total_contest_count: usize,
 }
 
-impl From<Vec<Problem>> for ContestGroupCollection {
-    fn from(value: Vec<Problem>) -> Self {
+impl ContestGroupCollection {
+    pub fn new(problems: Vec<Problem>, sort_keys: Vec<ContestSortKey>) -> Self {
+        let start_ats = sort_keys
+            .into_iter()
+            .map(|key| (key.code, key.start_at))
+            .collect::<HashMap<String, Option<DateTime<Utc>>>>();
         let mut map = BTreeMap::new();
-        for p in value {
-            let contest = p.contest_code.to_owned();
-            map.entry(Reverse(contest)).or_insert(Vec::new()).push(p);
+        for p in problems {
+            let key = ContestSortKey {
+                start_at: start_ats.get(&p.contest_code).copied().flatten(),
+                code: p.contest_code.to_owned(),
+            };
+            map.entry(Reverse(key)).or_insert(Vec::new()).push(p);
         }
         Self(map)
     }
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use domain::model::problem::{ContestMetadata, Problem};
+use uuid::Uuid;
+
+use crate::model::solution::TagCount;
+
+// 拡張機能が問題ページごとに1回で取得するためのまとめ
+#[derive(Debug, Clone)]
+pub struct ProblemSummary {
+    pub problem: Problem,
+    // contests.jsonを取り込むまでは空
+    pub contest: Option<ContestMetadata>,
+    pub solution_count: i64,
+    pub total_votes: i64,
+    pub top_tags: Vec<TagCount>,
+    pub top_solution: Option<TopSolutionPreview>,
+    // 同じコンテストでindex順に前後の問題
+    pub previous_problem: Option<Problem>,
+    pub next_problem: Option<Problem>,
+}
+
+// 得票の最も多い解説。同数なら新しいもの
+#[derive(Debug, Clone)]
+pub struct TopSolutionPreview {
+    pub id: Uuid,
+    pub title: String,
+    pub user_name: String,
+    pub votes_count: i64,
+    pub tags: Vec<String>,
+    // 本文の先頭
+    pub body_preview: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -4,6 +4,8 @@ use thiserror::Error;
 pub mod create;
 pub mod get_by_contest;
 pub mod get_contest_group_by_contest_series;
+pub mod get_summary;
+pub mod resolve_url;
 
 #[derive(Debug, Error)]
 pub enum ProblemError {
@@ -0,0 +1,28 @@
+use domain::{error::repository::RepositoryError, model::problem::url::AtcoderTaskUrlError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ResolveProblemUrlError {
+    #[error("{0}")]
+    BadRequest(String),
+    // URLは正しいが、問題がまだ取り込まれていない
+    #[error("problem has not been imported yet: {problem_id}")]
+    NotImported {
+        problem_id: String,
+        contest_code: String,
+    },
+    #[error("{0}")]
+    DBError(String),
+}
+
+impl From<AtcoderTaskUrlError> for ResolveProblemUrlError {
+    fn from(value: AtcoderTaskUrlError) -> Self {
+        ResolveProblemUrlError::BadRequest(value.to_string())
+    }
+}
+
+impl From<RepositoryError> for ResolveProblemUrlError {
+    fn from(value: RepositoryError) -> Self {
+        ResolveProblemUrlError::DBError(value.to_string())
+    }
+}
@@ -77,6 +77,37 @@ pub struct SolutionComment {
     pub updated_at: DateTime<Utc>,
 }
 
+// 問題ごとの解説の集計。解説の無い問題は0件として返す
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProblemSolutionStats {
+    pub problem_id: String,
+    pub solution_count: i64,
+    // 得票の最も多い解説。同数なら新しいもの
+    pub top_voted_solution_id: Option<Uuid>,
+    pub total_votes: i64,
+    // 解説の投稿・更新、投票、コメントのうち最も新しいもの
+    pub latest_activity_at: Option<DateTime<Utc>>,
+}
+
+impl ProblemSolutionStats {
+    pub fn empty(problem_id: String) -> Self {
+        Self {
+            problem_id,
+            solution_count: 0,
+            top_voted_solution_id: None,
+            total_votes: 0,
+            latest_activity_at: None,
+        }
+    }
+}
+
+// 問題の解説に付いたタグと、そのタグの付いた解説の数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagCount {
+    pub name: String,
+    pub solution_count: i64,
+}
+
 #[derive(Debug, Error)]
 pub enum SolutionError {
     #[error("{0}")]
@@ -0,0 +1,8 @@
+use derive_new::new;
+use domain::model::user::Role;
+
+#[derive(new, Debug)]
+pub struct ChangeRoleOutput {
+    pub id: String,
+    pub role: Role,
+}
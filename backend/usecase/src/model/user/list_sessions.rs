@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use domain::model::session::UserSession;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct SessionListItem {
+    pub id: Uuid,
+    pub authenticated_at: DateTime<Utc>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub last_seen_at: DateTime<Utc>,
+    // リクエストに使われたトークンのセッションか
+    pub current: bool,
+}
+
+impl SessionListItem {
+    pub fn from_session(session: UserSession, current_authenticated_at: i64) -> Self {
+        Self {
+            id: session.id,
+            current: session.authenticated_at.timestamp() == current_authenticated_at,
+            authenticated_at: session.authenticated_at,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            last_seen_at: session.last_seen_at,
+        }
+    }
+}
@@ -1,6 +1,9 @@
+pub mod change_role;
 pub mod create;
 pub mod delete_me;
 pub mod get_me;
+pub mod list_sessions;
+pub mod revoke_session;
 pub mod revoke_tokens;
 
 use domain::error::repository::RepositoryError;
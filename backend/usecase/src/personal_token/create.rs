@@ -0,0 +1,69 @@
+use std::{str::FromStr, sync::Arc};
+
+use derive_new::new;
+use domain::{
+    model::personal_token::{NewPersonalToken, TokenScope},
+    ports::{
+        external::{id::IdProviderPort, personal_token::PersonalTokenGeneratorPort},
+        repository::personal_token::PersonalTokenRepository,
+    },
+};
+use itertools::Itertools;
+use validator::Validate;
+
+use crate::model::personal_token::{
+    PersonalTokenError,
+    create::{CreatePersonalTokenInput, CreatePersonalTokenOutput},
+};
+
+#[derive(new)]
+pub struct CreatePersonalTokenUsecase {
+    id_provider: Arc<dyn IdProviderPort>,
+    token_generator: Arc<dyn PersonalTokenGeneratorPort>,
+    personal_token_repository: Arc<dyn PersonalTokenRepository>,
+}
+
+impl CreatePersonalTokenUsecase {
+    pub async fn run(
+        &self,
+        input: CreatePersonalTokenInput,
+    ) -> Result<CreatePersonalTokenOutput, PersonalTokenError> {
+        input
+            .validate()
+            .map_err(|e| PersonalTokenError::BadRequest(e.to_string()))?;
+        let scopes = input
+            .scopes
+            .iter()
+            .map(|s| {
+                TokenScope::from_str(s)
+                    .map_err(|_| PersonalTokenError::BadRequest(format!("unknown scope: {}", s)))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unique()
+            .collect::<Vec<_>>();
+
+        let generated = self.token_generator.generate();
+        let new_token = NewPersonalToken {
+            id: self.id_provider.new_personal_token_id(),
+            user_id: input.user_id,
+            name: input.name.trim().to_string(),
+            scopes,
+            token_hash: generated.hash,
+            token_prefix: generated.prefix,
+        };
+        let created = self
+            .personal_token_repository
+            .create(&new_token)
+            .await
+            .map_err(PersonalTokenError::from)?;
+
+        Ok(CreatePersonalTokenOutput::new(
+            created.id,
+            created.name,
+            created.scopes.iter().map(|s| s.to_string()).collect(),
+            generated.plain,
+            created.created_at,
+        ))
+    }
+}
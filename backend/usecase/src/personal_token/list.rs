@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::personal_token::PersonalTokenRepository;
+
+use crate::model::personal_token::{PersonalTokenError, list::PersonalTokenListItem};
+
+#[derive(new)]
+pub struct ListPersonalTokensUsecase {
+    personal_token_repository: Arc<dyn PersonalTokenRepository>,
+}
+
+impl ListPersonalTokensUsecase {
+    pub async fn run(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<PersonalTokenListItem>, PersonalTokenError> {
+        let tokens = self
+            .personal_token_repository
+            .list_by_user(&user_id)
+            .await
+            .map_err(PersonalTokenError::from)?;
+        Ok(tokens
+            .into_iter()
+            .map(PersonalTokenListItem::from)
+            .collect())
+    }
+}
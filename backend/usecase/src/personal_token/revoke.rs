@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::personal_token::PersonalTokenRepository;
+use uuid::Uuid;
+
+use crate::model::personal_token::{PersonalTokenError, revoke::RevokePersonalTokenOutput};
+
+#[derive(new)]
+pub struct RevokePersonalTokenUsecase {
+    personal_token_repository: Arc<dyn PersonalTokenRepository>,
+}
+
+impl RevokePersonalTokenUsecase {
+    pub async fn run(
+        &self,
+        user_id: String,
+        token_id: Uuid,
+    ) -> Result<RevokePersonalTokenOutput, PersonalTokenError> {
+        self.personal_token_repository
+            .revoke(&user_id, token_id)
+            .await
+            .map_err(PersonalTokenError::from)?;
+        Ok(RevokePersonalTokenOutput::new(token_id))
+    }
+}
@@ -1,82 +1,438 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 
+use chrono::{DateTime, Utc};
 use derive_new::new;
 use domain::{
-    model::problem::ContestSeries,
+    model::{
+        problem::{
+            ContestMetadata, ContestSeriesCatalog, Problem, ProblemAppearance, ProblemIndex,
+        },
+        problem_import::{ImportOutcome, NewProblemImportRun, ProblemImportChanges},
+    },
     ports::{
         external::atcoder_problems::AtcoderProblemsPort,
-        repository::problem::{ProblemRepository, tx::ProblemTxManager},
+        repository::{
+            contest_series::ContestSeriesRepository,
+            problem::{ProblemRepository, tx::ProblemTxManager},
+            problem_import::ProblemImportRunRepository,
+        },
     },
 };
+use futures::stream::{self, StreamExt};
+use metrics::{counter, gauge, histogram};
 use tracing::{info, warn};
 
-use crate::model::problem::create::ImportProblemsUsecaseError;
+use crate::metrics::{
+    PROBLEM_IMPORT_DIFFICULTY_FETCH_FAILURES_TOTAL, PROBLEM_IMPORT_DURATION_SECONDS,
+    PROBLEM_IMPORT_PROBLEMS, PROBLEM_IMPORT_RUNS_TOTAL,
+};
+use crate::model::problem::create::{
+    DuplicateProblemIndex, ImportDryRunReport, ImportProblemsSettings, ImportProblemsUsecaseError,
+    ProblemUpdate, UnknownSeriesContest,
+};
 
-const PROBLEM_UPSERT_CHUNK_SIZE: usize = 500;
+const MAX_LOGGED_REMOVED_IDS: usize = 20;
 
 #[derive(new)]
 pub struct ImportProblemsUsecase {
     atcoder_problems_port: Arc<dyn AtcoderProblemsPort>,
     problem_repository: Arc<dyn ProblemRepository>,
     problem_tx_manager: Arc<dyn ProblemTxManager>,
+    problem_import_run_repository: Arc<dyn ProblemImportRunRepository>,
+    contest_series_repository: Arc<dyn ContestSeriesRepository>,
+    settings: ImportProblemsSettings,
 }
 
 impl ImportProblemsUsecase {
-    pub async fn run(&self) -> Result<(), ImportProblemsUsecaseError> {
-        info!("problem import started");
+    pub async fn run(&self) -> Result<ProblemImportChanges, ImportProblemsUsecaseError> {
+        self.run_recorded(false).await
+    }
+
+    // 取得済みの難易度も取り直す
+    pub async fn recompute_difficulties(
+        &self,
+    ) -> Result<ProblemImportChanges, ImportProblemsUsecaseError> {
+        self.run_recorded(true).await
+    }
+
+    // 1コンテストだけ取り込み直す。全体の取り込みではないため実行記録は残さない
+    pub async fn run_for_contest(
+        &self,
+        contest_code: &str,
+    ) -> Result<ProblemImportChanges, ImportProblemsUsecaseError> {
+        let mut problem_count = None;
+        self.import(&mut problem_count, Some(contest_code), true)
+            .await
+    }
+
+    // 取得とADTの読み替えまで行い、書き込まずに差分と衝突を報告する
+    pub async fn dry_run(&self) -> Result<ImportDryRunReport, ImportProblemsUsecaseError> {
+        let (problems, existing) = self.fetch_with_existing(None).await?;
+        let diff = diff_problems(&problems, &existing);
+        let catalog = self.contest_series_catalog().await?;
+
+        let mut unknown_series = BTreeMap::<&str, Vec<String>>::new();
+        for problem in diff.writes.iter() {
+            if catalog.find(problem.contest_code.as_str()).is_none() {
+                unknown_series
+                    .entry(problem.contest_code.as_str())
+                    .or_default()
+                    .push(problem.id.clone());
+            }
+        }
+
+        // 取り込み後のproblemsで衝突を見るため、取得結果に無い既存の問題も含める
+        let mut by_index = BTreeMap::<(&str, &ProblemIndex), Vec<String>>::new();
+        for problem in problems.iter().chain(diff.removed.iter().copied()) {
+            by_index
+                .entry((problem.contest_code.as_str(), &problem.problem_index))
+                .or_default()
+                .push(problem.id.clone());
+        }
+
+        Ok(ImportDryRunReport {
+            fetched: problems.len(),
+            inserts: diff.inserts.iter().map(|p| (*p).clone()).collect(),
+            updates: diff
+                .updates
+                .iter()
+                .map(|(before, after)| ProblemUpdate {
+                    before: (*before).clone(),
+                    after: (*after).clone(),
+                })
+                .collect(),
+            unchanged: diff.unchanged,
+            removed: diff.removed.iter().map(|p| (*p).clone()).collect(),
+            duplicate_indexes: by_index
+                .into_iter()
+                .filter(|(_, problem_ids)| problem_ids.len() > 1)
+                .map(
+                    |((contest_code, problem_index), mut problem_ids)| DuplicateProblemIndex {
+                        contest_code: contest_code.to_string(),
+                        problem_index: problem_index.to_string(),
+                        problem_ids: {
+                            problem_ids.sort();
+                            problem_ids
+                        },
+                    },
+                )
+                .collect(),
+            unknown_series: unknown_series
+                .into_iter()
+                .map(|(contest_code, problem_ids)| UnknownSeriesContest {
+                    contest_code: contest_code.to_string(),
+                    problem_ids,
+                })
+                .collect(),
+        })
+    }
+
+    async fn run_recorded(
+        &self,
+        refresh_difficulties: bool,
+    ) -> Result<ProblemImportChanges, ImportProblemsUsecaseError> {
+        let started_at = Utc::now();
+        let started = Instant::now();
+        let mut problem_count = None;
+        let result = self
+            .import(&mut problem_count, None, refresh_difficulties)
+            .await;
+        let outcome = if result.is_ok() {
+            ImportOutcome::Success
+        } else {
+            ImportOutcome::Failure
+        };
+        let outcome_label = outcome.to_string();
+        histogram!(PROBLEM_IMPORT_DURATION_SECONDS, "outcome" => outcome_label.clone())
+            .record(started.elapsed().as_secs_f64());
+        counter!(PROBLEM_IMPORT_RUNS_TOTAL, "outcome" => outcome_label).increment(1);
+        self.record_run(started_at, outcome, problem_count, &result)
+            .await;
+        result
+    }
+
+    // 記録の失敗でインポート結果を変えない
+    async fn record_run(
+        &self,
+        started_at: DateTime<Utc>,
+        outcome: ImportOutcome,
+        problem_count: Option<i32>,
+        result: &Result<ProblemImportChanges, ImportProblemsUsecaseError>,
+    ) {
+        let run = NewProblemImportRun {
+            started_at,
+            finished_at: Utc::now(),
+            outcome,
+            problem_count,
+            changes: result.as_ref().ok().copied(),
+            error_message: result.as_ref().err().map(|e| e.to_string()),
+        };
+        if let Err(error) = self.problem_import_run_repository.record(&run).await {
+            warn!(error = ?error, "failed to record problem import run");
+        }
+    }
+
+    async fn fetch_with_existing(
+        &self,
+        contest_code: Option<&str>,
+    ) -> Result<(Vec<Problem>, HashMap<String, Problem>), ImportProblemsUsecaseError> {
         let mut problems = self.atcoder_problems_port.fetch_problems().await?;
         info!(problems = problems.len(), "problem import fetched");
+        if let Some(contest_code) = contest_code {
+            problems.retain(|problem| problem.contest_code == contest_code);
+            if problems.is_empty() {
+                return Err(ImportProblemsUsecaseError::ContestNotFound(
+                    contest_code.to_string(),
+                ));
+            }
+        }
+        // get_problems_by_contestは他のコンテストから出題された問題も返すため、元のコンテストで絞る
+        let existing = self
+            .problem_repository
+            .get_all_problems()
+            .await?
+            .into_iter()
+            .filter(|problem| contest_code.is_none_or(|code| problem.contest_code == code))
+            .map(|problem| (problem.id.clone(), problem))
+            .collect::<HashMap<String, Problem>>();
+        Ok((problems, existing))
+    }
+
+    async fn contest_series_catalog(
+        &self,
+    ) -> Result<ContestSeriesCatalog, ImportProblemsUsecaseError> {
+        let definitions = self.contest_series_repository.list().await?;
+        Ok(ContestSeriesCatalog::new(definitions)?)
+    }
 
-        let all_problem_ids = problems
+    // 取り込む問題の出題のうち、保存済みと異なるものだけ返す。問題は自分のコンテストにも出題されたものとして扱う。
+    // 同じコンテストの同じindexに複数の問題があることもあるため、出題はコンテストと問題の組で区別する
+    async fn changed_problem_appearances(
+        &self,
+        problems: &[Problem],
+    ) -> Result<Vec<ProblemAppearance>, ImportProblemsUsecaseError> {
+        let problem_ids = problems
             .iter()
-            .map(|problem| problem.id.clone())
+            .map(|problem| problem.id.as_str())
+            .collect::<HashSet<&str>>();
+        let mut appearances = problems
+            .iter()
+            .map(|problem| ProblemAppearance {
+                contest_code: problem.contest_code.clone(),
+                problem_index: problem.problem_index.clone(),
+                problem_id: problem.id.clone(),
+            })
             .collect::<Vec<_>>();
-        let existing_difficulty_problem_ids = self
+        match self.atcoder_problems_port.fetch_problem_appearances().await {
+            Ok(fetched) => appearances.extend(
+                fetched
+                    .into_iter()
+                    .filter(|appearance| problem_ids.contains(appearance.problem_id.as_str())),
+            ),
+            Err(error) => {
+                // 取れなくても保存済みの出題は残し、問題の取り込みは続ける
+                warn!(error = ?error, "failed to fetch problem appearances. keep stored ones and continue");
+            }
+        }
+        // 自分のコンテストへの出題は問題のindexを優先する
+        appearances.sort_by(|a, b| {
+            (a.contest_code.as_str(), a.problem_id.as_str())
+                .cmp(&(b.contest_code.as_str(), b.problem_id.as_str()))
+        });
+        appearances
+            .dedup_by(|a, b| a.contest_code == b.contest_code && a.problem_id == b.problem_id);
+
+        let stored = self
             .problem_repository
-            .get_problem_ids_with_difficulty(all_problem_ids.as_slice())
+            .get_all_problem_appearances()
             .await?
             .into_iter()
-            .collect::<HashSet<String>>();
+            .collect::<HashSet<ProblemAppearance>>();
+        let mut changed = appearances
+            .into_iter()
+            .filter(|appearance| !stored.contains(appearance))
+            .collect::<Vec<_>>();
+        changed.sort_by(|a, b| {
+            (
+                a.contest_code.as_str(),
+                &a.problem_index,
+                a.problem_id.as_str(),
+            )
+                .cmp(&(
+                    b.contest_code.as_str(),
+                    &b.problem_index,
+                    b.problem_id.as_str(),
+                ))
+        });
+        Ok(changed)
+    }
 
-        let mut difficulty_fetch_target_count = 0usize;
-        for problem in &mut problems {
-            if existing_difficulty_problem_ids.contains(problem.id.as_str()) {
-                continue;
+    // 取り込む問題と出題のコンテストのうち、保存済みと異なるものだけ返す
+    async fn changed_contest_metadata(
+        &self,
+        problems: &[Problem],
+        appearances: &[ProblemAppearance],
+    ) -> Result<Vec<ContestMetadata>, ImportProblemsUsecaseError> {
+        let fetched = match self.atcoder_problems_port.fetch_contests().await {
+            Ok(fetched) => fetched,
+            Err(error) => {
+                // コンテスト情報が取れなくても問題の取り込みは続ける
+                warn!(error = ?error, "failed to fetch contests. keep stored metadata and continue");
+                return Ok(vec![]);
             }
+        };
+        let stored = self
+            .problem_repository
+            .get_all_contest_metadata()
+            .await?
+            .into_iter()
+            .map(|contest| (contest.code.clone(), contest))
+            .collect::<HashMap<String, ContestMetadata>>();
+        let contest_codes = problems
+            .iter()
+            .map(|problem| problem.contest_code.as_str())
+            .chain(
+                appearances
+                    .iter()
+                    .map(|appearance| appearance.contest_code.as_str()),
+            )
+            .collect::<HashSet<&str>>();
+        Ok(fetched
+            .into_iter()
+            .filter(|contest| {
+                contest_codes.contains(contest.code.as_str())
+                    && stored.get(contest.code.as_str()) != Some(contest)
+            })
+            .collect())
+    }
 
-            difficulty_fetch_target_count += 1;
-            match self
-                .atcoder_problems_port
-                .fetch_difficulty(problem.id.as_str())
-                .await
-            {
-                Ok(difficulty) => {
-                    problem.difficulty = difficulty;
-                }
+    async fn import(
+        &self,
+        problem_count: &mut Option<i32>,
+        contest_code: Option<&str>,
+        refresh_difficulties: bool,
+    ) -> Result<ProblemImportChanges, ImportProblemsUsecaseError> {
+        info!(contest_code, refresh_difficulties, "problem import started");
+        let (mut problems, existing) = self.fetch_with_existing(contest_code).await?;
+        if contest_code.is_none() {
+            gauge!(PROBLEM_IMPORT_PROBLEMS).set(problems.len() as f64);
+        }
+        *problem_count = Some(problems.len() as i32);
+
+        let mut changes = ProblemImportChanges::default();
+        let mut targets = Vec::new();
+        for (index, problem) in problems.iter_mut().enumerate() {
+            // 取得できなかった場合も保存済みの値を残す
+            problem.difficulty = existing
+                .get(problem.id.as_str())
+                .and_then(|stored| stored.difficulty);
+            if refresh_difficulties || problem.difficulty.is_none() {
+                targets.push(index);
+            }
+        }
+        // 難易度の無い問題を先に取得し、上限を超えた分は次回以降に回す
+        targets.sort_by_key(|&index| problems[index].difficulty.is_some());
+        let deferred = targets
+            .len()
+            .saturating_sub(self.settings.difficulty_fetch_budget);
+        targets.truncate(self.settings.difficulty_fetch_budget);
+        changes.difficulty_fetch_deferred = deferred as i32;
+
+        let port = &self.atcoder_problems_port;
+        let results = stream::iter(targets.iter().map(|&index| {
+            let problem_id = problems[index].id.clone();
+            async move {
+                let result = port.fetch_difficulty(problem_id.as_str()).await;
+                (index, problem_id, result)
+            }
+        }))
+        .buffer_unordered(self.settings.difficulty_fetch_concurrency)
+        .collect::<Vec<_>>()
+        .await;
+        for (index, problem_id, result) in results {
+            match result {
+                Ok(Some(difficulty)) => problems[index].difficulty = Some(difficulty),
+                Ok(None) => {}
                 Err(error) => {
+                    changes.difficulty_fetch_failures += 1;
+                    counter!(PROBLEM_IMPORT_DIFFICULTY_FETCH_FAILURES_TOTAL).increment(1);
                     warn!(
-                        problem_id = problem.id,
+                        problem_id,
                         error = ?error,
-                        "failed to fetch problem difficulty. keep null and continue"
+                        "failed to fetch problem difficulty. keep stored value and continue"
                     );
                 }
             }
         }
         info!(
             total = problems.len(),
-            skipped = existing_difficulty_problem_ids.len(),
-            fetched = difficulty_fetch_target_count,
+            skipped = problems.len() - targets.len() - deferred,
+            fetched = targets.len(),
+            deferred,
+            concurrency = self.settings.difficulty_fetch_concurrency,
             "problem difficulty fetch summary"
         );
 
-        let contests = problems
+        let diff = diff_problems(&problems, &existing);
+        for (stored, fetched) in diff.updates.iter() {
+            if stored.title != fetched.title {
+                info!(
+                    problem_id = fetched.id,
+                    old_title = stored.title,
+                    new_title = fetched.title,
+                    "problem renamed upstream"
+                );
+            }
+        }
+        if !diff.removed.is_empty() {
+            // 解説が紐づくため削除はせず、気づけるように残す
+            warn!(
+                removed = diff.removed.len(),
+                problem_ids = ?diff
+                    .removed
+                    .iter()
+                    .take(MAX_LOGGED_REMOVED_IDS)
+                    .map(|problem| problem.id.as_str())
+                    .collect::<Vec<_>>(),
+                "problems missing from upstream"
+            );
+        }
+        changes.inserted = diff.inserts.len() as i32;
+        changes.updated = diff.updates.len() as i32;
+        changes.unchanged = diff.unchanged as i32;
+        changes.removed = diff.removed.len() as i32;
+        info!(
+            inserted = changes.inserted,
+            updated = changes.updated,
+            unchanged = changes.unchanged,
+            removed = changes.removed,
+            "problem import diff"
+        );
+
+        let writes = diff.writes.into_iter().cloned().collect::<Vec<Problem>>();
+        let appearances = self.changed_problem_appearances(&problems).await?;
+        let contest_metadata = self
+            .changed_contest_metadata(&problems, &appearances)
+            .await?;
+        if writes.is_empty() && contest_metadata.is_empty() && appearances.is_empty() {
+            info!("problem import has nothing to write");
+            return Ok(changes);
+        }
+
+        let catalog = self.contest_series_catalog().await?;
+        let contests = writes
             .iter()
-            .map(|problem| {
-                let series = ContestSeries::try_from(problem.contest_code.as_str())
-                    .unwrap_or(ContestSeries::OTHER);
-                let series_code: &str = series.into();
-                (problem.contest_code.clone(), series_code.to_string())
+            .map(|problem| problem.contest_code.as_str())
+            .chain(
+                appearances
+                    .iter()
+                    .map(|appearance| appearance.contest_code.as_str()),
+            )
+            .map(|contest_code| {
+                let series = catalog.classify(contest_code);
+                (contest_code.to_string(), series.to_string())
             })
             .collect::<HashSet<(String, String)>>();
 
@@ -88,24 +444,93 @@ impl ImportProblemsUsecase {
         );
         uow.problems().upsert_contests_bulk(&contests).await?;
         info!(
-            total = problems.len(),
-            chunk_size = PROBLEM_UPSERT_CHUNK_SIZE,
+            contests = contest_metadata.len(),
+            "problem import updating contest metadata"
+        );
+        uow.problems()
+            .update_contest_metadata_bulk(&contest_metadata)
+            .await?;
+        info!(
+            total = writes.len(),
+            chunk_size = self.settings.chunk_size,
             "problem import upserting problems in chunks"
         );
 
-        for (chunk_index, chunk) in problems.chunks(PROBLEM_UPSERT_CHUNK_SIZE).enumerate() {
+        for (chunk_index, chunk) in writes.chunks(self.settings.chunk_size).enumerate() {
             uow.problems().upsert_problems_bulk(chunk).await?;
-            let processed = ((chunk_index + 1) * PROBLEM_UPSERT_CHUNK_SIZE).min(problems.len());
+            let processed = ((chunk_index + 1) * self.settings.chunk_size).min(writes.len());
             info!(
                 chunk = chunk_index + 1,
                 chunk_size = chunk.len(),
                 processed,
-                total = problems.len(),
+                total = writes.len(),
                 "problem import chunk completed"
             );
         }
+        info!(
+            appearances = appearances.len(),
+            "problem import upserting problem appearances"
+        );
+        uow.problems()
+            .upsert_problem_appearances_bulk(&appearances)
+            .await?;
         uow.commit().await?;
         info!("problem import committed");
-        Ok(())
+        Ok(changes)
     }
 }
+
+struct ProblemDiff<'a> {
+    inserts: Vec<&'a Problem>,
+    // (保存済み, 取得結果)
+    updates: Vec<(&'a Problem, &'a Problem)>,
+    unchanged: usize,
+    removed: Vec<&'a Problem>,
+    // 取得順の挿入と更新
+    writes: Vec<&'a Problem>,
+}
+
+fn diff_problems<'a>(
+    fetched: &'a [Problem],
+    existing: &'a HashMap<String, Problem>,
+) -> ProblemDiff<'a> {
+    let mut diff = ProblemDiff {
+        inserts: Vec::new(),
+        updates: Vec::new(),
+        unchanged: 0,
+        removed: Vec::new(),
+        writes: Vec::new(),
+    };
+    for problem in fetched {
+        match existing.get(problem.id.as_str()) {
+            None => {
+                diff.inserts.push(problem);
+                diff.writes.push(problem);
+            }
+            Some(stored) if is_changed(stored, problem) => {
+                diff.updates.push((stored, problem));
+                diff.writes.push(problem);
+            }
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+
+    let fetched_ids = fetched
+        .iter()
+        .map(|problem| problem.id.as_str())
+        .collect::<HashSet<&str>>();
+    diff.removed = existing
+        .values()
+        .filter(|problem| !fetched_ids.contains(problem.id.as_str()))
+        .collect();
+    diff.removed.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+    diff
+}
+
+// 取得できなかった難易度は保存済みの値を残すため、差分として扱わない
+fn is_changed(stored: &Problem, fetched: &Problem) -> bool {
+    stored.contest_code != fetched.contest_code
+        || stored.problem_index != fetched.problem_index
+        || stored.title != fetched.title
+        || (fetched.difficulty.is_some() && fetched.difficulty != stored.difficulty)
+}
@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use derive_new::new;
-use domain::{model::problem::Problem, ports::repository::problem::ProblemRepository};
+use domain::ports::repository::problem::ProblemRepository;
 
-use crate::model::problem::ProblemError;
+use crate::model::problem::{ProblemError, get_by_contest::ContestProblems};
 
 #[derive(new)]
 pub struct GetProblemsByContestUsecase {
@@ -11,7 +11,7 @@ pub struct GetProblemsByContestUsecase {
 }
 
 impl GetProblemsByContestUsecase {
-    pub async fn run(&self, contest: &str) -> Result<Vec<Problem>, ProblemError> {
+    pub async fn run(&self, contest: &str) -> Result<ContestProblems, ProblemError> {
         if contest.trim().is_empty() {
             return Err(ProblemError::BadRequest(
                 "contest cannot be empty".to_string(),
@@ -23,17 +23,17 @@ impl GetProblemsByContestUsecase {
             .get_problems_by_contest(contest)
             .await
             .map_err(ProblemError::from)?;
-        pbs.sort_by(|a, b| {
-            let apid = a.problem_index.to_lowercase();
-            let bpid = b.problem_index.to_lowercase();
-            if apid == "ex" {
-                return std::cmp::Ordering::Greater;
-            } else if bpid == "ex" {
-                return std::cmp::Ordering::Less;
-            }
-            apid.cmp(&bpid)
-        });
+        pbs.sort_by(|a, b| a.problem_index.cmp(&b.problem_index));
 
-        Ok(pbs)
+        let contest = self
+            .problem_repository
+            .get_contest_metadata(contest)
+            .await
+            .map_err(ProblemError::from)?;
+
+        Ok(ContestProblems {
+            contest,
+            problems: pbs,
+        })
     }
 }
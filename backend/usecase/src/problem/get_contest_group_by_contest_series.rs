@@ -1,7 +1,11 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use derive_new::new;
-use domain::{model::problem::ContestSeries, ports::repository::problem::ProblemRepository};
+use domain::{
+    model::problem::{ContestSeries, DifficultyRange},
+    ports::repository::problem::ProblemRepository,
+};
 
 use crate::model::problem::{
     ProblemError,
@@ -21,6 +25,7 @@ impl GetContestGroupByContestSeriesUsecase {
         &self,
         series: ContestSeries,
         query: Option<String>,
+        difficulty: DifficultyRange,
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<ContestGroupPage, ProblemError> {
@@ -31,7 +36,7 @@ impl GetContestGroupByContestSeriesUsecase {
         let (mut pbs, has_more) = if let Some(query) = normalized_query {
             (
                 self.problem_repository
-                    .search_problems_by_contest_series(series, &query)
+                    .search_problems_by_contest_series(series, &query, difficulty)
                     .await
                     .map_err(ProblemError::from)?,
                 false,
@@ -43,7 +48,7 @@ impl GetContestGroupByContestSeriesUsecase {
             let offset = offset.unwrap_or(0);
             let mut contest_codes = self
                 .problem_repository
-                .get_contest_codes_by_series(series, (limit + 1) as i64, offset as i64)
+                .get_contest_codes_by_series(series, difficulty, (limit + 1) as i64, offset as i64)
                 .await
                 .map_err(ProblemError::from)?;
             let has_more = contest_codes.len() > limit;
@@ -51,25 +56,27 @@ impl GetContestGroupByContestSeriesUsecase {
 
             (
                 self.problem_repository
-                    .get_problems_by_contest_codes(&contest_codes)
+                    .get_problems_by_contest_codes(&contest_codes, difficulty)
                     .await
                     .map_err(ProblemError::from)?,
                 has_more,
             )
         };
 
-        pbs.sort_by(|a, b| {
-            let apid = a.problem_index.to_lowercase();
-            let bpid = b.problem_index.to_lowercase();
-            if apid == "ex" {
-                return std::cmp::Ordering::Greater;
-            } else if bpid == "ex" {
-                return std::cmp::Ordering::Less;
-            }
-            apid.cmp(&bpid)
-        });
+        pbs.sort_by(|a, b| a.problem_index.cmp(&b.problem_index));
 
-        let items = ContestGroupCollection::from(pbs);
+        let contest_codes = pbs
+            .iter()
+            .map(|problem| problem.contest_code.clone())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect::<Vec<String>>();
+        let sort_keys = self
+            .problem_repository
+            .get_contest_sort_keys(&contest_codes)
+            .await
+            .map_err(ProblemError::from)?;
+        let items = ContestGroupCollection::new(pbs, sort_keys);
         let total_contest_count = items.0.len();
 
         Ok(ContestGroupPage {
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::repository::RepositoryError, ports::repository::problem::ProblemRepository};
+
+use crate::model::problem::{
+    ProblemError,
+    get_summary::{ProblemSummary, TopSolutionPreview},
+};
+use crate::model::solution::{ProblemSolutionStats, SolutionListSort};
+use crate::service::solution::SolutionService;
+
+pub const SUMMARY_TOP_TAGS_LIMIT: i64 = 5;
+pub const SOLUTION_BODY_PREVIEW_CHARS: usize = 200;
+
+#[derive(new)]
+pub struct GetProblemSummaryUsecase {
+    problem_repository: Arc<dyn ProblemRepository>,
+    solution_service: Arc<dyn SolutionService>,
+}
+
+impl GetProblemSummaryUsecase {
+    pub async fn run(&self, problem_id: &str) -> Result<ProblemSummary, ProblemError> {
+        let problem_id = problem_id.trim();
+        if problem_id.is_empty() {
+            return Err(ProblemError::BadRequest(
+                "problemId cannot be empty".to_string(),
+            ));
+        }
+
+        let problem = self
+            .problem_repository
+            .get_problem_by_id(problem_id)
+            .await?;
+        let contest = self
+            .problem_repository
+            .get_contest_metadata(&problem.contest_code)
+            .await?;
+
+        let mut contest_problems = self
+            .problem_repository
+            .get_problems_by_contest(&problem.contest_code)
+            .await?;
+        contest_problems.sort_by(|a, b| a.problem_index.cmp(&b.problem_index));
+        let position = contest_problems.iter().position(|p| p.id == problem.id);
+        let previous_problem = position
+            .and_then(|i| i.checked_sub(1))
+            .and_then(|i| contest_problems.get(i).cloned());
+        let next_problem = position.and_then(|i| contest_problems.get(i + 1).cloned());
+
+        let stats = self
+            .solution_service
+            .get_solution_stats_by_problem_ids(std::slice::from_ref(&problem.id))
+            .await?
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| ProblemSolutionStats::empty(problem.id.clone()));
+        let top_tags = self
+            .solution_service
+            .get_top_tags_by_problem_id(&problem.id, SUMMARY_TOP_TAGS_LIMIT)
+            .await?;
+        let top_solution = self.top_solution_preview(&problem.id).await?;
+
+        Ok(ProblemSummary {
+            problem,
+            contest,
+            solution_count: stats.solution_count,
+            total_votes: stats.total_votes,
+            top_tags,
+            top_solution,
+            previous_problem,
+            next_problem,
+        })
+    }
+
+    async fn top_solution_preview(
+        &self,
+        problem_id: &str,
+    ) -> Result<Option<TopSolutionPreview>, ProblemError> {
+        let Some(top) = self
+            .solution_service
+            .get_solutions_by_problem_id(problem_id.to_string(), SolutionListSort::Votes, Some(1))
+            .await?
+            .into_iter()
+            .next()
+        else {
+            return Ok(None);
+        };
+        // 一覧の取得後に非表示・削除された場合は無いものとする
+        let details = match self
+            .solution_service
+            .get_solution_by_solution_id(top.id)
+            .await
+        {
+            Ok(details) => details,
+            Err(RepositoryError::NotFound(_)) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Some(TopSolutionPreview {
+            id: top.id,
+            title: top.title,
+            user_name: top.user_name,
+            votes_count: top.votes_count,
+            tags: details.tags,
+            body_preview: details
+                .body_md
+                .chars()
+                .take(SOLUTION_BODY_PREVIEW_CHARS)
+                .collect(),
+            created_at: top.created_at,
+        }))
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    model::problem_import::ProblemImportRun,
+    ports::repository::problem_import::ProblemImportRunRepository,
+};
+
+use crate::model::problem::ProblemError;
+
+const DEFAULT_RUN_LIMIT: usize = 20;
+const MAX_RUN_LIMIT: usize = 100;
+
+#[derive(new)]
+pub struct ListProblemImportRunsUsecase {
+    problem_import_run_repository: Arc<dyn ProblemImportRunRepository>,
+}
+
+impl ListProblemImportRunsUsecase {
+    pub async fn run(&self, limit: Option<usize>) -> Result<Vec<ProblemImportRun>, ProblemError> {
+        let limit = limit.unwrap_or(DEFAULT_RUN_LIMIT).clamp(1, MAX_RUN_LIMIT);
+        self.problem_import_run_repository
+            .list_recent(limit as i64)
+            .await
+            .map_err(ProblemError::from)
+    }
+}
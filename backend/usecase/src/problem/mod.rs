@@ -2,3 +2,6 @@ pub mod create;
 pub mod get_by_contest;
 pub mod get_by_id;
 pub mod get_contest_group_by_contest_series;
+pub mod get_summary;
+pub mod list_import_runs;
+pub mod resolve_url;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::repository::RepositoryError,
+    model::problem::{Problem, url::AtcoderTaskUrl},
+    ports::repository::problem::ProblemRepository,
+};
+
+use crate::model::problem::resolve_url::ResolveProblemUrlError;
+
+#[derive(new)]
+pub struct ResolveProblemUrlUsecase {
+    problem_repository: Arc<dyn ProblemRepository>,
+}
+
+impl ResolveProblemUrlUsecase {
+    pub async fn run(&self, url: &str) -> Result<Problem, ResolveProblemUrlError> {
+        if url.trim().is_empty() {
+            return Err(ResolveProblemUrlError::BadRequest(
+                "url cannot be empty".to_string(),
+            ));
+        }
+
+        // ADTのURLでもtask screen nameは元の問題のidになっている
+        let task = AtcoderTaskUrl::parse(url)?;
+        match self
+            .problem_repository
+            .get_problem_by_id(&task.task_id)
+            .await
+        {
+            Ok(problem) => Ok(problem),
+            Err(RepositoryError::NotFound(_)) => Err(ResolveProblemUrlError::NotImported {
+                contest_code: task.canonical_contest_code(),
+                problem_id: task.task_id,
+            }),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
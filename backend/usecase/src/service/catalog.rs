@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use domain::error::repository::RepositoryError;
+
+use crate::model::catalog::CatalogStats;
+
+#[async_trait]
+pub trait CatalogService: Send + Sync {
+    async fn get_catalog_stats(&self) -> Result<CatalogStats, RepositoryError>;
+}
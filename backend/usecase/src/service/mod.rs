@@ -1,2 +1,3 @@
+pub mod catalog;
 pub mod contest;
 pub mod solution;
@@ -1,9 +1,10 @@
 use async_trait::async_trait;
-use domain::error::repository::RepositoryError;
+use domain::{error::repository::RepositoryError, model::problem::DifficultyRange};
 use uuid::Uuid;
 
 use crate::model::solution::{
-    SolutionComment, SolutionDetails, SolutionListItem, SolutionListSort, UserSolutionListItem,
+    ProblemSolutionStats, SolutionComment, SolutionDetails, SolutionListItem, SolutionListSort,
+    TagCount, UserSolutionListItem,
 };
 
 #[async_trait]
@@ -11,6 +12,7 @@ pub trait SolutionService: Send + Sync {
     async fn get_latest_solutions(
         &self,
         limit: Option<i32>,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<SolutionListItem>, RepositoryError>;
     async fn get_solutions_by_problem_id(
         &self,
@@ -26,6 +28,7 @@ pub trait SolutionService: Send + Sync {
         &self,
         user_name: String,
         sort: SolutionListSort,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<UserSolutionListItem>, RepositoryError>;
     async fn user_name_exists(&self, user_name: &str) -> Result<bool, RepositoryError>;
     async fn get_solution_votes_count(&self, solution_id: Uuid) -> Result<i64, RepositoryError>;
@@ -44,4 +47,15 @@ pub trait SolutionService: Send + Sync {
     async fn get_comment_user_id(&self, comment_id: Uuid) -> Result<String, RepositoryError>;
     async fn get_user_name_by_id(&self, user_id: &str) -> Result<String, RepositoryError>;
     async fn problem_exists(&self, problem_id: &str) -> Result<bool, RepositoryError>;
+    // 解説のある問題の分だけ返す
+    async fn get_solution_stats_by_problem_ids(
+        &self,
+        problem_ids: &[String],
+    ) -> Result<Vec<ProblemSolutionStats>, RepositoryError>;
+    // 多く使われている順。同数ならタグ名順
+    async fn get_top_tags_by_problem_id(
+        &self,
+        problem_id: &str,
+        limit: i64,
+    ) -> Result<Vec<TagCount>, RepositoryError>;
 }
@@ -3,9 +3,11 @@ use std::sync::Arc;
 use derive_new::new;
 use domain::ports::{external::id::IdProviderPort, repository::solution::tx::SolutionTxManager};
 use itertools::Itertools;
+use metrics::counter;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::metrics::{EXPLANATION_REQUESTS_FULFILLED_TOTAL, SOLUTIONS_CREATED_TOTAL};
 use crate::model::solution::{
     SolutionError,
     create::{CreateSolutionInput, from_create_solution_input_for_solution},
@@ -56,8 +58,15 @@ impl CreateSolutionUsecase {
         let solution_id = uow.solutions().create(&solution).await?;
 
         uow.solutions().replace_tags(solution_id, &tag_ids).await?;
+        // 最初の解説であれば、リクエストしていた人に達成済みとして通知する
+        let fulfilled = uow
+            .explanation_requests()
+            .fulfill_explanation_requests(&solution.problem_id, solution_id)
+            .await?;
 
         uow.commit().await?;
+        counter!(SOLUTIONS_CREATED_TOTAL).increment(1);
+        counter!(EXPLANATION_REQUESTS_FULFILLED_TOTAL).increment(fulfilled);
 
         Ok(uid)
     }
@@ -2,10 +2,12 @@ use std::sync::Arc;
 
 use derive_new::new;
 use domain::ports::repository::solution::tx::SolutionTxManager;
+use metrics::counter;
 use validator::Validate;
 
 use crate::{
     dto::solution::CreatedCommentView,
+    metrics::COMMENTS_CREATED_TOTAL,
     model::solution::{CreatedComment, SolutionError, create_comment::CreateCommentInput},
     service::solution::SolutionService,
 };
@@ -37,6 +39,7 @@ impl CreateCommentUsecase {
             .create_comment(&input.user_id, input.solution_id, &input.body_md)
             .await?;
         uow.commit().await?;
+        counter!(COMMENTS_CREATED_TOTAL).increment(1);
 
         Ok(CreatedCommentView::from(CreatedComment {
             id: created.id,
@@ -26,6 +26,15 @@ impl DeleteSolutionUsecase {
             ));
         }
 
+        self.delete(solution_id).await
+    }
+
+    // 管理者による削除。所有者と公開状態を問わない
+    pub async fn run_as_admin(&self, solution_id: Uuid) -> Result<Uuid, SolutionError> {
+        self.delete(solution_id).await
+    }
+
+    async fn delete(&self, solution_id: Uuid) -> Result<Uuid, SolutionError> {
         let mut uow = self.txm.begin().await?;
         uow.solutions().delete(solution_id).await?;
         uow.commit().await?;
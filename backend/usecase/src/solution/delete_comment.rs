@@ -26,6 +26,15 @@ impl DeleteCommentUsecase {
             ));
         }
 
+        self.delete(comment_id).await
+    }
+
+    // 管理者による削除。所有者と公開状態を問わない
+    pub async fn run_as_admin(&self, comment_id: Uuid) -> Result<Uuid, SolutionError> {
+        self.delete(comment_id).await
+    }
+
+    async fn delete(&self, comment_id: Uuid) -> Result<Uuid, SolutionError> {
         let mut uow = self.txm.begin().await?;
         uow.comments().delete_comment(comment_id).await?;
         uow.commit().await?;
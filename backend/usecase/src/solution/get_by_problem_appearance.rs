@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::repository::RepositoryError, ports::repository::problem::ProblemRepository};
+
+use crate::{
+    dto::solution::SolutionListItemView,
+    model::solution::{SolutionError, SolutionListSort},
+    service::solution::SolutionService,
+    solution::get_by_problem_id::GetSolutionsByProblemIdUsecase,
+};
+
+// 他のコンテストでの出題(contest_code, problem_index)から元の問題の解説を引く
+#[derive(new)]
+pub struct GetSolutionsByProblemAppearanceUsecase {
+    problem_repository: Arc<dyn ProblemRepository>,
+    service: Arc<dyn SolutionService>,
+}
+
+impl GetSolutionsByProblemAppearanceUsecase {
+    pub async fn run(
+        &self,
+        contest_code: &str,
+        problem_index: &str,
+        sort: SolutionListSort,
+        limit: Option<i32>,
+    ) -> Result<Vec<SolutionListItemView>, SolutionError> {
+        if contest_code.trim().is_empty() || problem_index.trim().is_empty() {
+            return Err(SolutionError::BadRequest(
+                "contest_code and problem_index cannot be empty".to_string(),
+            ));
+        }
+
+        let problem_id = self
+            .problem_repository
+            .get_problem_id_by_appearance(contest_code.trim(), problem_index.trim())
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound(_) => {
+                    SolutionError::NotFound("problem not found".to_string())
+                }
+                e => SolutionError::from(e),
+            })?;
+
+        GetSolutionsByProblemIdUsecase::new(self.service.clone())
+            .run(problem_id, sort, limit)
+            .await
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use derive_new::new;
+use domain::model::problem::DifficultyRange;
 
 use crate::{
     dto::solution::UserSolutionListItemView,
@@ -18,6 +19,7 @@ impl GetSolutionsByUserNameUsecase {
         &self,
         user_name: String,
         sort: SolutionListSort,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<UserSolutionListItemView>, SolutionError> {
         let normalized = user_name.trim();
         if normalized.is_empty() {
@@ -33,7 +35,7 @@ impl GetSolutionsByUserNameUsecase {
 
         let items = self
             .service
-            .get_solutions_by_user_name(normalized.to_string(), sort)
+            .get_solutions_by_user_name(normalized.to_string(), sort, difficulty)
             .await?;
         Ok(items
             .into_iter()
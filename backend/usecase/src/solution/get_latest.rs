@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use derive_new::new;
+use domain::model::problem::DifficultyRange;
 
 use crate::{
     dto::solution::SolutionListItemView, model::solution::SolutionError,
@@ -16,8 +17,9 @@ impl GetLatestSolutionsUsecase {
     pub async fn run(
         &self,
         limit: Option<i32>,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<SolutionListItemView>, SolutionError> {
-        let items = self.service.get_latest_solutions(limit).await?;
+        let items = self.service.get_latest_solutions(limit, difficulty).await?;
         Ok(items.into_iter().map(SolutionListItemView::from).collect())
     }
 }
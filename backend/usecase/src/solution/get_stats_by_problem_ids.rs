@@ -0,0 +1,65 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use derive_new::new;
+
+use crate::{
+    model::solution::{ProblemSolutionStats, SolutionError},
+    service::solution::SolutionService,
+};
+
+// 1ページ分の問題一覧(100コンテスト)をまとめて問い合わせられる数
+pub const MAX_SOLUTION_STATS_PROBLEM_IDS: usize = 1000;
+
+#[derive(new)]
+pub struct GetSolutionStatsByProblemIdsUsecase {
+    service: Arc<dyn SolutionService>,
+}
+
+impl GetSolutionStatsByProblemIdsUsecase {
+    // 重複を除き、指定された順に返す
+    pub async fn run(
+        &self,
+        problem_ids: Vec<String>,
+    ) -> Result<Vec<ProblemSolutionStats>, SolutionError> {
+        let mut seen = HashSet::new();
+        let mut unique = Vec::with_capacity(problem_ids.len());
+        for problem_id in problem_ids {
+            let problem_id = problem_id.trim();
+            if problem_id.is_empty() {
+                return Err(SolutionError::BadRequest(
+                    "problem_ids cannot contain empty ids".to_string(),
+                ));
+            }
+            if seen.insert(problem_id.to_string()) {
+                unique.push(problem_id.to_string());
+            }
+        }
+        if unique.is_empty() {
+            return Err(SolutionError::BadRequest(
+                "problem_ids cannot be empty".to_string(),
+            ));
+        }
+        if unique.len() > MAX_SOLUTION_STATS_PROBLEM_IDS {
+            return Err(SolutionError::BadRequest(format!(
+                "problem_ids must be at most {MAX_SOLUTION_STATS_PROBLEM_IDS}"
+            )));
+        }
+
+        let mut stats = self
+            .service
+            .get_solution_stats_by_problem_ids(&unique)
+            .await?
+            .into_iter()
+            .map(|stats| (stats.problem_id.clone(), stats))
+            .collect::<HashMap<_, _>>();
+        Ok(unique
+            .into_iter()
+            .map(|problem_id| {
+                stats
+                    .remove(&problem_id)
+                    .unwrap_or_else(|| ProblemSolutionStats::empty(problem_id))
+            })
+            .collect())
+    }
+}
@@ -2,13 +2,17 @@ pub mod create;
 pub mod create_comment;
 pub mod delete;
 pub mod delete_comment;
+pub mod get_by_problem_appearance;
 pub mod get_by_problem_id;
 pub mod get_by_solution_id;
 pub mod get_by_user_name;
 pub mod get_comments_by_solution_id;
 pub mod get_latest;
 pub mod get_my_vote_status;
+pub mod get_stats_by_problem_ids;
 pub mod get_votes_count;
+pub mod set_comment_hidden;
+pub mod set_hidden;
 pub mod unvote;
 pub mod update;
 pub mod update_comment;
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::solution::tx::SolutionTxManager;
+use uuid::Uuid;
+
+use crate::model::solution::SolutionError;
+
+#[derive(new)]
+pub struct SetCommentHiddenUsecase {
+    txm: Arc<dyn SolutionTxManager>,
+}
+
+impl SetCommentHiddenUsecase {
+    pub async fn run(&self, comment_id: Uuid, hidden: bool) -> Result<Uuid, SolutionError> {
+        let mut uow = self.txm.begin().await?;
+        uow.comments()
+            .set_comment_hidden(comment_id, hidden)
+            .await?;
+        uow.commit().await?;
+        Ok(comment_id)
+    }
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::solution::tx::SolutionTxManager;
+use uuid::Uuid;
+
+use crate::model::solution::SolutionError;
+
+// 管理者が解説を非表示にする。非表示の解説は一覧・詳細に出ない
+#[derive(new)]
+pub struct SetSolutionHiddenUsecase {
+    txm: Arc<dyn SolutionTxManager>,
+}
+
+impl SetSolutionHiddenUsecase {
+    pub async fn run(&self, solution_id: Uuid, hidden: bool) -> Result<Uuid, SolutionError> {
+        let mut uow = self.txm.begin().await?;
+        uow.solutions().set_hidden(solution_id, hidden).await?;
+        uow.commit().await?;
+        Ok(solution_id)
+    }
+}
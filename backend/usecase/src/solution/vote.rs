@@ -2,8 +2,10 @@ use std::sync::Arc;
 
 use derive_new::new;
 use domain::ports::repository::solution::tx::SolutionTxManager;
+use metrics::counter;
 use uuid::Uuid;
 
+use crate::metrics::VOTES_CREATED_TOTAL;
 use crate::model::solution::SolutionError;
 use crate::service::solution::SolutionService;
 
@@ -21,8 +23,11 @@ impl VoteSolutionUsecase {
         }
 
         let mut uow = self.txm.begin().await?;
-        uow.votes().like(&user_id, solution_id).await?;
+        let created = uow.votes().like(&user_id, solution_id).await?;
         uow.commit().await?;
+        if created {
+            counter!(VOTES_CREATED_TOTAL).increment(1);
+        }
         Ok(())
     }
 }
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{model::user::Role, ports::repository::user::UserRepository};
+
+use crate::model::user::{UserError, change_role::ChangeRoleOutput};
+
+#[derive(new)]
+pub struct ChangeRoleUsecase {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl ChangeRoleUsecase {
+    pub async fn run(&self, uid: String, role: Role) -> Result<ChangeRoleOutput, UserError> {
+        self.user_repository
+            .update_role(&uid, role)
+            .await
+            .map_err(UserError::from)?;
+        Ok(ChangeRoleOutput::new(uid, role))
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::session::SessionRepository;
+
+use crate::model::user::{UserError, list_sessions::SessionListItem};
+
+#[derive(new)]
+pub struct ListSessionsUsecase {
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+impl ListSessionsUsecase {
+    pub async fn run(
+        &self,
+        uid: String,
+        current_authenticated_at: i64,
+    ) -> Result<Vec<SessionListItem>, UserError> {
+        let sessions = self
+            .session_repository
+            .list_active_by_user(&uid)
+            .await
+            .map_err(UserError::from)?;
+        Ok(sessions
+            .into_iter()
+            .map(|s| SessionListItem::from_session(s, current_authenticated_at))
+            .collect())
+    }
+}
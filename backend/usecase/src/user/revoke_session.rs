@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::ports::repository::session::SessionRepository;
+use uuid::Uuid;
+
+use crate::model::user::{UserError, revoke_session::RevokeSessionOutput};
+
+#[derive(new)]
+pub struct RevokeSessionUsecase {
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+impl RevokeSessionUsecase {
+    pub async fn run(
+        &self,
+        uid: String,
+        session_id: Uuid,
+    ) -> Result<RevokeSessionOutput, UserError> {
+        self.session_repository
+            .revoke(&uid, session_id)
+            .await
+            .map_err(UserError::from)?;
+        Ok(RevokeSessionOutput::new(session_id))
+    }
+}
@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use derive_new::new;
-use domain::ports::repository::user::UserRepository;
+use domain::ports::repository::{session::SessionRepository, user::UserRepository};
 
 use crate::model::user::{UserError, revoke_tokens::RevokeTokensOutput};
 
 #[derive(new)]
 pub struct RevokeTokensUsecase {
     user_repository: Arc<dyn UserRepository>,
+    session_repository: Arc<dyn SessionRepository>,
 }
 
 impl RevokeTokensUsecase {
@@ -16,6 +17,11 @@ impl RevokeTokensUsecase {
             .revoke_tokens_by_uid(&uid)
             .await
             .map_err(UserError::from)?;
+        // 失効済みのトークンに紐づくセッションを一覧に残さない
+        self.session_repository
+            .revoke_all_by_user(&uid)
+            .await
+            .map_err(UserError::from)?;
         Ok(RevokeTokensOutput::new(uid))
     }
 }
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use domain::{
+    error::repository::RepositoryError,
+    model::problem::{ContestReclassification, ContestSeries, ContestSeriesDefinition},
+    ports::repository::contest_series::ContestSeriesRepository,
+};
+use usecase::{
+    contest::add_series::AddContestSeriesUsecase,
+    model::contests::{ContestError, add_series::AddContestSeriesInput},
+};
+
+#[derive(Default)]
+struct DummyContestSeriesRepository {
+    definitions: Vec<ContestSeriesDefinition>,
+    assignments: Vec<(String, ContestSeries)>,
+    created: Mutex<Vec<(ContestSeriesDefinition, Vec<ContestReclassification>)>>,
+}
+
+#[async_trait]
+impl ContestSeriesRepository for DummyContestSeriesRepository {
+    async fn list(&self) -> Result<Vec<ContestSeriesDefinition>, RepositoryError> {
+        Ok(self.definitions.to_owned())
+    }
+
+    async fn list_contest_assignments(
+        &self,
+    ) -> Result<Vec<(String, ContestSeries)>, RepositoryError> {
+        Ok(self.assignments.to_owned())
+    }
+
+    async fn create_and_reclassify(
+        &self,
+        definition: &ContestSeriesDefinition,
+        reclassifications: &[ContestReclassification],
+    ) -> Result<(), RepositoryError> {
+        self.created
+            .lock()
+            .unwrap()
+            .push((definition.clone(), reclassifications.to_vec()));
+        Ok(())
+    }
+}
+
+fn definition(code: &str, pattern: Option<&str>, sort_order: i32) -> ContestSeriesDefinition {
+    ContestSeriesDefinition {
+        code: ContestSeries::new(code),
+        display_name: code.into(),
+        code_pattern: pattern.map(Into::into),
+        sort_order,
+    }
+}
+
+fn repository() -> DummyContestSeriesRepository {
+    DummyContestSeriesRepository {
+        definitions: vec![
+            definition("ABC", Some("^abc"), 10),
+            definition("OTHER", None, 1000),
+        ],
+        assignments: vec![
+            ("abc400".into(), ContestSeries::ABC),
+            ("past202412".into(), ContestSeries::OTHER),
+            ("typical90".into(), ContestSeries::OTHER),
+        ],
+        ..Default::default()
+    }
+}
+
+fn input(code: &str, pattern: &str) -> AddContestSeriesInput {
+    AddContestSeriesInput {
+        code: code.into(),
+        display_name: "PAST".into(),
+        code_pattern: pattern.into(),
+        sort_order: 60,
+    }
+}
+
+#[tokio::test]
+async fn add_series_reclassifies_only_matching_contests() -> Result<()> {
+    let repo = Arc::new(repository());
+    let uc = AddContestSeriesUsecase::new(repo.clone());
+
+    let output = uc.run(input(" past ", r"^past\d+")).await?;
+
+    assert_eq!(output.definition.code, ContestSeries::new("PAST"));
+    assert_eq!(
+        output.reclassified,
+        vec![ContestReclassification {
+            contest_code: "past202412".into(),
+            from: ContestSeries::OTHER,
+            to: ContestSeries::new("PAST"),
+        }]
+    );
+    assert_eq!(output.catalog.parse("past")?, ContestSeries::new("PAST"));
+    assert_eq!(repo.created.lock().unwrap().len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_series_rejects_existing_codes_and_invalid_input() -> Result<()> {
+    let repo = Arc::new(repository());
+    let uc = AddContestSeriesUsecase::new(repo.clone());
+
+    for (code, pattern) in [("abc", "^abc"), ("other", "^x")] {
+        let err = uc
+            .run(input(code, pattern))
+            .await
+            .expect_err("should conflict");
+        assert!(matches!(err, ContestError::Conflict(_)));
+    }
+    for (code, pattern) in [
+        ("1PAST", "^past"),
+        ("PA-ST", "^past"),
+        ("PAST", "(past"),
+        ("PAST", " "),
+    ] {
+        let err = uc
+            .run(input(code, pattern))
+            .await
+            .expect_err("should be rejected");
+        assert!(matches!(err, ContestError::BadRequest(_)));
+    }
+    assert!(repo.created.lock().unwrap().is_empty());
+    Ok(())
+}
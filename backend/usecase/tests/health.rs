@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::{
+    error::repository::RepositoryError,
+    model::{
+        health::MigrationStatus,
+        problem_import::{NewProblemImportRun, ProblemImportRun},
+    },
+    ports::{
+        external::auth::{AuthError, AuthenticatorPort, KeySetStatus, Principal},
+        repository::{health::HealthCheckRepository, problem_import::ProblemImportRunRepository},
+    },
+};
+use usecase::health::readiness::CheckReadinessUsecase;
+
+struct DummyHealthCheckRepository {
+    reachable: bool,
+    pending_versions: Vec<i64>,
+}
+
+#[async_trait]
+impl HealthCheckRepository for DummyHealthCheckRepository {
+    async fn check_db(&self) -> bool {
+        self.reachable
+    }
+
+    async fn migration_status(&self) -> Result<MigrationStatus, RepositoryError> {
+        Ok(MigrationStatus {
+            applied_version: Some(1),
+            expected_version: Some(self.pending_versions.last().copied().unwrap_or(1)),
+            pending_versions: self.pending_versions.clone(),
+        })
+    }
+}
+
+struct DummyProblemImportRunRepository;
+
+#[async_trait]
+impl ProblemImportRunRepository for DummyProblemImportRunRepository {
+    async fn record(
+        &self,
+        _run: &NewProblemImportRun,
+    ) -> Result<ProblemImportRun, RepositoryError> {
+        Err(RepositoryError::Unexpected("unused".into()))
+    }
+
+    async fn latest(&self) -> Result<Option<ProblemImportRun>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn list_recent(&self, _limit: i64) -> Result<Vec<ProblemImportRun>, RepositoryError> {
+        Ok(vec![])
+    }
+}
+
+struct DummyAuthenticator {
+    status: KeySetStatus,
+}
+
+#[async_trait]
+impl AuthenticatorPort for DummyAuthenticator {
+    async fn verify_id_token(&self, _token: &str) -> Result<Principal, AuthError> {
+        Err(AuthError::Unauthorized)
+    }
+
+    async fn key_set_status(&self) -> KeySetStatus {
+        self.status.clone()
+    }
+}
+
+fn usecase(
+    reachable: bool,
+    pending_versions: Vec<i64>,
+    status: KeySetStatus,
+) -> CheckReadinessUsecase {
+    CheckReadinessUsecase::new(
+        Arc::new(DummyHealthCheckRepository {
+            reachable,
+            pending_versions,
+        }),
+        Arc::new(DummyProblemImportRunRepository),
+        Arc::new(DummyAuthenticator { status }),
+    )
+}
+
+#[tokio::test]
+async fn readiness_is_ready_when_db_reachable_and_migrations_applied() {
+    let report = usecase(true, vec![], KeySetStatus::NotLoaded).run().await;
+
+    assert!(report.is_ready());
+    assert!(report.database.reachable);
+    assert!(!report.jwks.fresh);
+}
+
+#[tokio::test]
+async fn readiness_fails_when_migrations_are_behind() {
+    let report = usecase(true, vec![2, 3], KeySetStatus::NotApplicable)
+        .run()
+        .await;
+
+    assert!(!report.is_ready());
+    assert_eq!(report.migrations.unwrap().pending_versions, vec![2, 3]);
+}
+
+#[tokio::test]
+async fn readiness_fails_when_db_unreachable() {
+    let report = usecase(false, vec![], KeySetStatus::NotApplicable)
+        .run()
+        .await;
+
+    assert!(!report.is_ready());
+}
+
+#[tokio::test]
+async fn readiness_reports_jwks_freshness() {
+    let now = Utc::now();
+    let fresh = KeySetStatus::Loaded {
+        fetched_at: now,
+        expires_at: now + Duration::minutes(5),
+    };
+    let stale = KeySetStatus::Loaded {
+        fetched_at: now - Duration::minutes(10),
+        expires_at: now - Duration::minutes(5),
+    };
+
+    assert!(usecase(true, vec![], fresh).run().await.jwks.fresh);
+    // 鍵が期限切れでもreadinessは落とさない
+    let report = usecase(true, vec![], stale).run().await;
+    assert!(!report.jwks.fresh);
+    assert!(report.is_ready());
+}
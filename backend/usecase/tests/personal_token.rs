@@ -0,0 +1,169 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::{
+    error::repository::RepositoryError,
+    model::personal_token::{NewPersonalToken, PersonalToken, TokenScope},
+    ports::{
+        external::{
+            id::IdProviderPort,
+            personal_token::{GeneratedPersonalToken, PersonalTokenGeneratorPort},
+        },
+        repository::personal_token::PersonalTokenRepository,
+    },
+};
+use usecase::{
+    model::personal_token::{PersonalTokenError, create::CreatePersonalTokenInput},
+    personal_token::{create::CreatePersonalTokenUsecase, revoke::RevokePersonalTokenUsecase},
+};
+use uuid::Uuid;
+
+struct DummyIdProvider;
+
+impl IdProviderPort for DummyIdProvider {
+    fn new_solution_id(&self) -> Uuid {
+        Uuid::nil()
+    }
+    fn new_personal_token_id(&self) -> Uuid {
+        Uuid::nil()
+    }
+}
+
+struct DummyGenerator;
+
+impl PersonalTokenGeneratorPort for DummyGenerator {
+    fn generate(&self) -> GeneratedPersonalToken {
+        GeneratedPersonalToken {
+            plain: "aspat_plain".into(),
+            hash: "hashed".into(),
+            prefix: "aspat_pl".into(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct DummyPersonalTokenRepository {
+    created: Mutex<Vec<NewPersonalToken>>,
+}
+
+#[async_trait]
+impl PersonalTokenRepository for DummyPersonalTokenRepository {
+    async fn create(&self, token: &NewPersonalToken) -> Result<PersonalToken, RepositoryError> {
+        self.created.lock().unwrap().push(token.to_owned());
+        Ok(PersonalToken {
+            id: token.id,
+            user_id: token.user_id.to_owned(),
+            name: token.name.to_owned(),
+            scopes: token.scopes.to_owned(),
+            token_prefix: token.token_prefix.to_owned(),
+            last_used_at: None,
+            created_at: Utc::now(),
+        })
+    }
+    async fn list_by_user(&self, _user_id: &str) -> Result<Vec<PersonalToken>, RepositoryError> {
+        Ok(vec![])
+    }
+    async fn revoke(&self, user_id: &str, token_id: Uuid) -> Result<(), RepositoryError> {
+        if user_id == "owner" {
+            return Ok(());
+        }
+        Err(RepositoryError::NotFound(format!(
+            "personal token not found: {}",
+            token_id
+        )))
+    }
+    async fn find_active_by_hash(
+        &self,
+        _token_hash: &str,
+    ) -> Result<Option<PersonalToken>, RepositoryError> {
+        Ok(None)
+    }
+    async fn touch_last_used(&self, _token_id: Uuid) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
+
+fn create_usecase(repo: Arc<DummyPersonalTokenRepository>) -> CreatePersonalTokenUsecase {
+    CreatePersonalTokenUsecase::new(Arc::new(DummyIdProvider), Arc::new(DummyGenerator), repo)
+}
+
+#[tokio::test]
+async fn create_personal_token_stores_hash_and_returns_plain_once() -> Result<()> {
+    let repo = Arc::new(DummyPersonalTokenRepository::default());
+    let uc = create_usecase(repo.to_owned());
+
+    let output = uc
+        .run(CreatePersonalTokenInput {
+            user_id: "owner".into(),
+            name: " cli ".into(),
+            scopes: vec!["read".into(), "write:solutions".into(), "read".into()],
+        })
+        .await?;
+
+    assert_eq!(output.token, "aspat_plain");
+    assert_eq!(output.name, "cli");
+    assert_eq!(output.scopes, vec!["read", "write:solutions"]);
+
+    let created = repo.created.lock().unwrap();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].token_hash, "hashed");
+    assert_eq!(
+        created[0].scopes,
+        vec![TokenScope::Read, TokenScope::WriteSolutions]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_personal_token_rejects_unknown_scope() {
+    let repo = Arc::new(DummyPersonalTokenRepository::default());
+    let uc = create_usecase(repo.to_owned());
+
+    let res = uc
+        .run(CreatePersonalTokenInput {
+            user_id: "owner".into(),
+            name: "cli".into(),
+            scopes: vec!["admin".into()],
+        })
+        .await;
+
+    assert!(matches!(res, Err(PersonalTokenError::BadRequest(_))));
+    assert!(repo.created.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn create_personal_token_rejects_empty_scopes_and_blank_name() {
+    let uc = create_usecase(Arc::new(DummyPersonalTokenRepository::default()));
+
+    let empty_scopes = uc
+        .run(CreatePersonalTokenInput {
+            user_id: "owner".into(),
+            name: "cli".into(),
+            scopes: vec![],
+        })
+        .await;
+    assert!(matches!(
+        empty_scopes,
+        Err(PersonalTokenError::BadRequest(_))
+    ));
+
+    let blank_name = uc
+        .run(CreatePersonalTokenInput {
+            user_id: "owner".into(),
+            name: "  ".into(),
+            scopes: vec!["read".into()],
+        })
+        .await;
+    assert!(matches!(blank_name, Err(PersonalTokenError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn revoke_personal_token_of_other_user_is_not_found() {
+    let uc = RevokePersonalTokenUsecase::new(Arc::new(DummyPersonalTokenRepository::default()));
+
+    assert!(uc.run("owner".into(), Uuid::nil()).await.is_ok());
+    let res = uc.run("other".into(), Uuid::nil()).await;
+    assert!(matches!(res, Err(PersonalTokenError::NotFound(_))));
+}
@@ -1,22 +1,62 @@
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
 
 use anyhow::Result;
 use async_trait::async_trait;
 use domain::{
     error::{external::ExternalError, repository::RepositoryError},
-    model::problem::Problem,
+    model::{
+        problem::{
+            ContestMetadata, ContestReclassification, ContestSeries, ContestSeriesCatalog,
+            ContestSeriesDefinition, ContestSortKey, DifficultyRange, Problem, ProblemAppearance,
+        },
+        problem_import::{
+            ImportOutcome, NewProblemImportRun, ProblemImportChanges, ProblemImportRun,
+        },
+    },
     ports::{
         external::atcoder_problems::AtcoderProblemsPort,
-        repository::problem::{
-            ProblemRepository,
-            tx::{ProblemRepositoryTx, ProblemTxManager, ProblemUnitOfWork},
+        repository::{
+            contest_series::ContestSeriesRepository,
+            problem::{
+                ProblemRepository,
+                tx::{ProblemRepositoryTx, ProblemTxManager, ProblemUnitOfWork},
+            },
+            problem_import::ProblemImportRunRepository,
         },
     },
 };
-use usecase::problem::create::ImportProblemsUsecase;
+use usecase::{
+    model::problem::{
+        create::{
+            DuplicateProblemIndex, ImportProblemsSettings, ImportProblemsUsecaseError,
+            UnknownSeriesContest,
+        },
+        resolve_url::ResolveProblemUrlError,
+    },
+    problem::{
+        create::ImportProblemsUsecase, get_by_contest::GetProblemsByContestUsecase,
+        get_contest_group_by_contest_series::GetContestGroupByContestSeriesUsecase,
+        resolve_url::ResolveProblemUrlUsecase,
+    },
+};
+
+fn settings() -> ImportProblemsSettings {
+    ImportProblemsSettings {
+        chunk_size: 500,
+        difficulty_fetch_concurrency: 4,
+        difficulty_fetch_budget: 1_000,
+    }
+}
 
+#[derive(Default)]
 struct DummyAtcoderProblemsPort {
     item: Vec<Problem>,
+    contests: Vec<ContestMetadata>,
+    appearances: Vec<ProblemAppearance>,
 }
 
 #[async_trait]
@@ -25,6 +65,14 @@ impl AtcoderProblemsPort for DummyAtcoderProblemsPort {
         Ok(self.item.to_owned())
     }
 
+    async fn fetch_contests(&self) -> Result<Vec<ContestMetadata>, ExternalError> {
+        Ok(self.contests.to_owned())
+    }
+
+    async fn fetch_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, ExternalError> {
+        Ok(self.appearances.to_owned())
+    }
+
     async fn fetch_difficulty(&self, _problem_id: &str) -> Result<Option<i32>, ExternalError> {
         Ok(None)
     }
@@ -34,8 +82,11 @@ impl AtcoderProblemsPort for DummyAtcoderProblemsPort {
 struct TxCalls {
     contests: Vec<(String, String)>,
     problems: Vec<Problem>,
+    contest_metadata: Vec<ContestMetadata>,
+    appearances: Vec<ProblemAppearance>,
     contests_bulk_calls: usize,
     problems_bulk_calls: usize,
+    begins: usize,
     commits: usize,
 }
 
@@ -69,7 +120,7 @@ impl ProblemRepositoryTx for DummyProblemUow {
         self.shared.lock().unwrap().problems.push(Problem {
             id: problem_id.to_string(),
             contest_code: contest_code.to_string(),
-            problem_index: problem_index.to_string(),
+            problem_index: problem_index.into(),
             title: title.to_string(),
             difficulty,
         });
@@ -92,6 +143,30 @@ impl ProblemRepositoryTx for DummyProblemUow {
         calls.problems.extend(problems.iter().cloned());
         Ok(())
     }
+
+    async fn update_contest_metadata_bulk(
+        &mut self,
+        contests: &[ContestMetadata],
+    ) -> Result<(), RepositoryError> {
+        self.shared
+            .lock()
+            .unwrap()
+            .contest_metadata
+            .extend(contests.iter().cloned());
+        Ok(())
+    }
+
+    async fn upsert_problem_appearances_bulk(
+        &mut self,
+        appearances: &[ProblemAppearance],
+    ) -> Result<(), RepositoryError> {
+        self.shared
+            .lock()
+            .unwrap()
+            .appearances
+            .extend(appearances.iter().cloned());
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -117,20 +192,49 @@ struct DummyProblemTxManager {
 #[async_trait]
 impl ProblemTxManager for DummyProblemTxManager {
     async fn begin(&self) -> Result<Box<dyn ProblemUnitOfWork>, RepositoryError> {
+        self.shared.lock().unwrap().begins += 1;
         Ok(Box::new(DummyProblemUow {
             shared: self.shared.clone(),
         }))
     }
 }
 
-struct DummyProblemRepository;
+#[derive(Default)]
+struct DummyProblemRepository {
+    stored: Vec<Problem>,
+    contests: Vec<ContestMetadata>,
+    // 保存済みの問題の自分のコンテストでの出題に加えて持つもの
+    appearances: Vec<ProblemAppearance>,
+}
+
+impl DummyProblemRepository {
+    fn all_appearances(&self) -> Vec<ProblemAppearance> {
+        self.stored
+            .iter()
+            .map(|problem| ProblemAppearance {
+                contest_code: problem.contest_code.clone(),
+                problem_index: problem.problem_index.clone(),
+                problem_id: problem.id.clone(),
+            })
+            .chain(self.appearances.iter().cloned())
+            .collect()
+    }
+}
 
 #[async_trait]
 impl ProblemRepository for DummyProblemRepository {
-    async fn create_records(&self, _problems: Vec<Problem>) -> Result<(), RepositoryError> {
+    async fn create_records(
+        &self,
+        _problems: Vec<Problem>,
+        _catalog: &ContestSeriesCatalog,
+    ) -> Result<(), RepositoryError> {
         Ok(())
     }
 
+    async fn get_all_problems(&self) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(self.stored.to_owned())
+    }
+
     async fn get_problem_ids_with_difficulty(
         &self,
         _problem_ids: &[String],
@@ -140,14 +244,15 @@ impl ProblemRepository for DummyProblemRepository {
 
     async fn get_problems_by_contest_series(
         &self,
-        _series: domain::model::problem::ContestSeries,
+        _series: ContestSeries,
     ) -> Result<Vec<Problem>, RepositoryError> {
         Ok(vec![])
     }
 
     async fn get_contest_codes_by_series(
         &self,
-        _series: domain::model::problem::ContestSeries,
+        _series: ContestSeries,
+        _difficulty: DifficultyRange,
         _limit: i64,
         _offset: i64,
     ) -> Result<Vec<String>, RepositoryError> {
@@ -157,26 +262,180 @@ impl ProblemRepository for DummyProblemRepository {
     async fn get_problems_by_contest_codes(
         &self,
         _contest_codes: &[String],
+        _difficulty: DifficultyRange,
     ) -> Result<Vec<Problem>, RepositoryError> {
         Ok(vec![])
     }
 
     async fn search_problems_by_contest_series(
         &self,
-        _series: domain::model::problem::ContestSeries,
+        _series: ContestSeries,
         _query: &str,
+        difficulty: DifficultyRange,
     ) -> Result<Vec<Problem>, RepositoryError> {
-        Ok(vec![])
+        Ok(self
+            .stored
+            .iter()
+            .filter(|problem| difficulty.contains(problem.difficulty))
+            .cloned()
+            .collect())
     }
 
     async fn get_problem_by_id(&self, problem_id: &str) -> Result<Problem, RepositoryError> {
-        Err(RepositoryError::NotFound(problem_id.to_string()))
+        self.stored
+            .iter()
+            .find(|problem| problem.id == problem_id)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound(problem_id.to_string()))
     }
 
     async fn get_problems_by_contest(
         &self,
-        _contest: &str,
+        contest: &str,
     ) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(self
+            .all_appearances()
+            .into_iter()
+            .filter(|appearance| appearance.contest_code == contest)
+            .filter_map(|appearance| {
+                let problem = self
+                    .stored
+                    .iter()
+                    .find(|problem| problem.id == appearance.problem_id)?;
+                Some(Problem {
+                    contest_code: appearance.contest_code,
+                    problem_index: appearance.problem_index,
+                    ..problem.clone()
+                })
+            })
+            .collect())
+    }
+
+    async fn get_all_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, RepositoryError> {
+        Ok(self.all_appearances())
+    }
+
+    async fn get_problem_id_by_appearance(
+        &self,
+        contest_code: &str,
+        problem_index: &str,
+    ) -> Result<String, RepositoryError> {
+        self.all_appearances()
+            .into_iter()
+            .find(|appearance| {
+                appearance.contest_code == contest_code
+                    && appearance.problem_index.as_str() == problem_index
+            })
+            .map(|appearance| appearance.problem_id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("{contest_code}/{problem_index}")))
+    }
+
+    async fn get_contest_sort_keys(
+        &self,
+        contest_codes: &[String],
+    ) -> Result<Vec<ContestSortKey>, RepositoryError> {
+        Ok(contest_codes
+            .iter()
+            .map(|code| ContestSortKey {
+                start_at: self
+                    .contests
+                    .iter()
+                    .find(|metadata| &metadata.code == code)
+                    .map(|metadata| metadata.start_at),
+                code: code.to_owned(),
+            })
+            .collect())
+    }
+
+    async fn get_all_contest_metadata(&self) -> Result<Vec<ContestMetadata>, RepositoryError> {
+        Ok(self.contests.to_owned())
+    }
+
+    async fn get_contest_metadata(
+        &self,
+        contest: &str,
+    ) -> Result<Option<ContestMetadata>, RepositoryError> {
+        Ok(self
+            .contests
+            .iter()
+            .find(|metadata| metadata.code == contest)
+            .cloned())
+    }
+}
+
+// マイグレーションで入る定義と同じ
+struct DummyContestSeriesRepository {
+    definitions: Vec<ContestSeriesDefinition>,
+}
+
+impl Default for DummyContestSeriesRepository {
+    fn default() -> Self {
+        let definitions = [
+            ("ABC", Some("^abc"), 10),
+            ("ARC", Some("^arc"), 20),
+            ("AGC", Some("^agc"), 30),
+            ("AHC", Some("^ahc"), 40),
+            ("AWC", Some("^awc"), 50),
+            ("OTHER", None, 1000),
+        ]
+        .into_iter()
+        .map(|(code, pattern, sort_order)| ContestSeriesDefinition {
+            code: ContestSeries::new(code),
+            display_name: code.into(),
+            code_pattern: pattern.map(Into::into),
+            sort_order,
+        })
+        .collect();
+        Self { definitions }
+    }
+}
+
+#[async_trait]
+impl ContestSeriesRepository for DummyContestSeriesRepository {
+    async fn list(&self) -> Result<Vec<ContestSeriesDefinition>, RepositoryError> {
+        Ok(self.definitions.to_owned())
+    }
+
+    async fn list_contest_assignments(
+        &self,
+    ) -> Result<Vec<(String, ContestSeries)>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn create_and_reclassify(
+        &self,
+        _definition: &ContestSeriesDefinition,
+        _reclassifications: &[ContestReclassification],
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct DummyProblemImportRunRepository {
+    runs: Mutex<Vec<NewProblemImportRun>>,
+}
+
+#[async_trait]
+impl ProblemImportRunRepository for DummyProblemImportRunRepository {
+    async fn record(&self, run: &NewProblemImportRun) -> Result<ProblemImportRun, RepositoryError> {
+        self.runs.lock().unwrap().push(run.clone());
+        Ok(ProblemImportRun {
+            id: uuid::Uuid::nil(),
+            started_at: run.started_at,
+            finished_at: run.finished_at,
+            outcome: run.outcome,
+            problem_count: run.problem_count,
+            changes: run.changes,
+            error_message: run.error_message.clone(),
+        })
+    }
+
+    async fn latest(&self) -> Result<Option<ProblemImportRun>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn list_recent(&self, _limit: i64) -> Result<Vec<ProblemImportRun>, RepositoryError> {
         Ok(vec![])
     }
 }
@@ -207,16 +466,32 @@ async fn usecase_imports_problems_in_single_uow() -> Result<()> {
                 difficulty: None,
             },
         ],
+        ..Default::default()
     });
     let calls = Arc::new(Mutex::new(TxCalls::default()));
     let txm = Arc::new(DummyProblemTxManager {
         shared: calls.clone(),
     });
-    let repo = Arc::new(DummyProblemRepository);
+    let repo = Arc::new(DummyProblemRepository::default());
+
+    let runs = Arc::new(DummyProblemImportRunRepository::default());
 
-    let uc = ImportProblemsUsecase::new(port, repo, txm);
+    let uc = ImportProblemsUsecase::new(
+        port,
+        repo,
+        txm,
+        runs.to_owned(),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
     uc.run().await.unwrap();
 
+    let runs = runs.runs.lock().unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].outcome, ImportOutcome::Success);
+    assert_eq!(runs[0].problem_count, Some(3));
+    assert_eq!(runs[0].changes.map(|c| c.inserted), Some(3));
+
     let calls = calls.lock().unwrap();
     assert_eq!(calls.commits, 1);
     assert_eq!(calls.contests_bulk_calls, 1);
@@ -231,3 +506,789 @@ async fn usecase_imports_problems_in_single_uow() -> Result<()> {
     assert_eq!(sent.title, "A - Example");
     Ok(())
 }
+
+struct FailingAtcoderProblemsPort;
+
+#[async_trait]
+impl AtcoderProblemsPort for FailingAtcoderProblemsPort {
+    async fn fetch_problems(&self) -> Result<Vec<Problem>, ExternalError> {
+        Err(ExternalError::ExternalUnavailable)
+    }
+
+    async fn fetch_contests(&self) -> Result<Vec<ContestMetadata>, ExternalError> {
+        Ok(vec![])
+    }
+
+    async fn fetch_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, ExternalError> {
+        Ok(vec![])
+    }
+
+    async fn fetch_difficulty(&self, _problem_id: &str) -> Result<Option<i32>, ExternalError> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn usecase_records_failed_import_run() -> Result<()> {
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: Arc::new(Mutex::new(TxCalls::default())),
+    });
+    let runs = Arc::new(DummyProblemImportRunRepository::default());
+    let uc = ImportProblemsUsecase::new(
+        Arc::new(FailingAtcoderProblemsPort),
+        Arc::new(DummyProblemRepository::default()),
+        txm,
+        runs.to_owned(),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    uc.run().await.expect_err("fetch should fail");
+
+    let runs = runs.runs.lock().unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].outcome, ImportOutcome::Failure);
+    assert_eq!(runs[0].problem_count, None);
+    assert_eq!(runs[0].changes, None);
+    assert!(runs[0].error_message.is_some());
+    Ok(())
+}
+
+fn problem(id: &str, contest_code: &str) -> Problem {
+    Problem {
+        id: id.into(),
+        contest_code: contest_code.into(),
+        problem_index: "A".into(),
+        title: "A - Example".into(),
+        difficulty: None,
+    }
+}
+
+#[tokio::test]
+async fn run_for_contest_imports_only_that_contest_without_recording_a_run() -> Result<()> {
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: vec![problem("abc234_a", "abc234"), problem("abc235_a", "abc235")],
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let runs = Arc::new(DummyProblemImportRunRepository::default());
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository::default()),
+        txm,
+        runs.to_owned(),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let changes = uc.run_for_contest("abc235").await?;
+    assert_eq!(changes.inserted, 1);
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.commits, 1);
+    assert_eq!(calls.problems.len(), 1);
+    assert_eq!(calls.problems[0].id, "abc235_a");
+    assert_eq!(calls.contests, vec![("abc235".into(), "ABC".into())]);
+    assert!(runs.runs.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_for_unknown_contest_writes_nothing() -> Result<()> {
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: vec![problem("abc234_a", "abc234")],
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository::default()),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let err = uc
+        .run_for_contest("abc999")
+        .await
+        .expect_err("should be not found");
+    assert!(matches!(
+        err,
+        ImportProblemsUsecaseError::ContestNotFound(_)
+    ));
+    assert_eq!(calls.lock().unwrap().commits, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn usecase_writes_only_inserted_and_changed_problems() -> Result<()> {
+    let stored = vec![
+        Problem {
+            difficulty: Some(400),
+            ..problem("abc234_a", "abc234")
+        },
+        Problem {
+            title: "B - Old Title".into(),
+            problem_index: "B".into(),
+            ..problem("abc234_b", "abc234")
+        },
+        problem("abc233_a", "abc233"),
+    ];
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: vec![
+            problem("abc234_a", "abc234"),
+            Problem {
+                title: "B - New Title".into(),
+                problem_index: "B".into(),
+                ..problem("abc234_b", "abc234")
+            },
+            problem("abc235_a", "abc235"),
+        ],
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let runs = Arc::new(DummyProblemImportRunRepository::default());
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository {
+            stored,
+            ..Default::default()
+        }),
+        txm,
+        runs.to_owned(),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let changes = uc.run().await?;
+    let expected = ProblemImportChanges {
+        inserted: 1,
+        updated: 1,
+        unchanged: 1,
+        removed: 1,
+        difficulty_fetch_failures: 0,
+        difficulty_fetch_deferred: 0,
+    };
+    assert_eq!(changes, expected);
+    assert_eq!(runs.runs.lock().unwrap()[0].changes, Some(expected));
+
+    let calls = calls.lock().unwrap();
+    let written = calls
+        .problems
+        .iter()
+        .map(|problem| problem.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(written, vec!["abc234_b", "abc235_a"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn usecase_skips_the_transaction_when_nothing_changed() -> Result<()> {
+    let stored = vec![problem("abc234_a", "abc234")];
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: stored.to_owned(),
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository {
+            stored,
+            ..Default::default()
+        }),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let changes = uc.run().await?;
+    assert_eq!(changes.unchanged, 1);
+    assert_eq!(calls.lock().unwrap().commits, 0);
+    Ok(())
+}
+
+fn contest_metadata(code: &str, rate_change: &str) -> ContestMetadata {
+    ContestMetadata {
+        code: code.into(),
+        title: format!("Contest {code}"),
+        start_at: "2022-01-01T12:00:00Z".parse().unwrap(),
+        duration_second: 6000,
+        rate_change: rate_change.into(),
+    }
+}
+
+#[tokio::test]
+async fn usecase_updates_only_changed_metadata_of_imported_contests() -> Result<()> {
+    let stored = vec![problem("abc234_a", "abc234"), problem("abc235_a", "abc235")];
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: stored.to_owned(),
+        contests: vec![
+            contest_metadata("abc234", "~ 1999"),
+            contest_metadata("abc235", "~ 1999"),
+            contest_metadata("adt_easy_20250430_3", "-"),
+        ],
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository {
+            stored,
+            contests: vec![
+                contest_metadata("abc234", "~ 1999"),
+                contest_metadata("abc235", "-"),
+            ],
+            ..Default::default()
+        }),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let changes = uc.run().await?;
+    assert_eq!(changes.unchanged, 2);
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.commits, 1);
+    assert!(calls.problems.is_empty());
+    assert_eq!(
+        calls.contest_metadata,
+        vec![contest_metadata("abc235", "~ 1999")]
+    );
+    Ok(())
+}
+
+fn appearance(contest_code: &str, problem_index: &str, problem_id: &str) -> ProblemAppearance {
+    ProblemAppearance {
+        contest_code: contest_code.into(),
+        problem_index: problem_index.into(),
+        problem_id: problem_id.into(),
+    }
+}
+
+#[tokio::test]
+async fn usecase_writes_only_new_appearances_of_imported_problems() -> Result<()> {
+    let stored = vec![problem("abc395_a", "abc395")];
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: stored.to_owned(),
+        contests: vec![contest_metadata("adt_easy_20250430_3", "-")],
+        appearances: vec![
+            appearance("abc395", "A", "abc395_a"),
+            appearance("adt_easy_20250430_3", "B", "abc395_a"),
+            // 取り込まない問題の出題は書き込まない
+            appearance("adt_easy_20250430_3", "C", "abc999_z"),
+        ],
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository {
+            stored,
+            ..Default::default()
+        }),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let changes = uc.run().await?;
+    assert_eq!(changes.unchanged, 1);
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.commits, 1);
+    assert!(calls.problems.is_empty());
+    assert_eq!(
+        calls.appearances,
+        vec![appearance("adt_easy_20250430_3", "B", "abc395_a")]
+    );
+    assert_eq!(
+        calls.contests,
+        vec![("adt_easy_20250430_3".to_string(), "OTHER".to_string())]
+    );
+    assert_eq!(
+        calls.contest_metadata,
+        vec![contest_metadata("adt_easy_20250430_3", "-")]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn usecase_keeps_every_problem_sharing_a_contest_index() -> Result<()> {
+    // 同じコンテストの同じindexの2問は、どちらの出題も書き込む
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: vec![
+            problem("abc050_a", "abc050"),
+            problem("abc050_a_2", "abc050"),
+        ],
+        contests: vec![],
+        appearances: vec![appearance("adt_easy_20250430_1", "A", "abc050_a")],
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository::default()),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    uc.run().await?;
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(
+        calls.appearances,
+        vec![
+            appearance("abc050", "A", "abc050_a"),
+            appearance("abc050", "A", "abc050_a_2"),
+            appearance("adt_easy_20250430_1", "A", "abc050_a"),
+        ]
+    );
+    Ok(())
+}
+
+struct ContestsUnavailablePort {
+    item: Vec<Problem>,
+}
+
+#[async_trait]
+impl AtcoderProblemsPort for ContestsUnavailablePort {
+    async fn fetch_problems(&self) -> Result<Vec<Problem>, ExternalError> {
+        Ok(self.item.to_owned())
+    }
+
+    async fn fetch_contests(&self) -> Result<Vec<ContestMetadata>, ExternalError> {
+        Err(ExternalError::ExternalUnavailable)
+    }
+
+    async fn fetch_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, ExternalError> {
+        Ok(vec![])
+    }
+
+    async fn fetch_difficulty(&self, _problem_id: &str) -> Result<Option<i32>, ExternalError> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn usecase_keeps_importing_when_contests_fetch_fails() -> Result<()> {
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: Arc::new(Mutex::new(TxCalls::default())),
+    });
+    let uc = ImportProblemsUsecase::new(
+        Arc::new(ContestsUnavailablePort {
+            item: vec![problem("abc234_a", "abc234")],
+        }),
+        Arc::new(DummyProblemRepository::default()),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let changes = uc.run().await?;
+    assert_eq!(changes.inserted, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn dry_run_reports_changes_and_collisions_without_a_transaction() -> Result<()> {
+    let stored = vec![problem("abc234_a", "abc234"), problem("abc233_a", "abc233")];
+    let port = Arc::new(DummyAtcoderProblemsPort {
+        item: vec![
+            Problem {
+                title: "A - Renamed".into(),
+                ..problem("abc234_a", "abc234")
+            },
+            problem("abc235_a", "abc235"),
+            problem("abc235_a2", "abc235"),
+            problem("typical90_a", "typical90"),
+        ],
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let runs = Arc::new(DummyProblemImportRunRepository::default());
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository {
+            stored,
+            ..Default::default()
+        }),
+        txm,
+        runs.to_owned(),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+
+    let report = uc.dry_run().await?;
+    assert_eq!(report.fetched, 4);
+    let inserted = report
+        .inserts
+        .iter()
+        .map(|problem| problem.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(inserted, vec!["abc235_a", "abc235_a2", "typical90_a"]);
+    assert_eq!(report.updates.len(), 1);
+    assert_eq!(report.updates[0].before.title, "A - Example");
+    assert_eq!(report.updates[0].after.title, "A - Renamed");
+    assert_eq!(report.removed[0].id, "abc233_a");
+    assert_eq!(
+        report.duplicate_indexes,
+        vec![DuplicateProblemIndex {
+            contest_code: "abc235".into(),
+            problem_index: "A".into(),
+            problem_ids: vec!["abc235_a".into(), "abc235_a2".into()],
+        }]
+    );
+    assert_eq!(
+        report.unknown_series,
+        vec![UnknownSeriesContest {
+            contest_code: "typical90".into(),
+            problem_ids: vec!["typical90_a".into()],
+        }]
+    );
+
+    assert_eq!(calls.lock().unwrap().begins, 0);
+    assert!(runs.runs.lock().unwrap().is_empty());
+    Ok(())
+}
+
+// 同時に実行中の難易度取得の最大数を記録する
+#[derive(Default)]
+struct CountingDifficultyPort {
+    item: Vec<Problem>,
+    calls: AtomicUsize,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+#[async_trait]
+impl AtcoderProblemsPort for CountingDifficultyPort {
+    async fn fetch_problems(&self) -> Result<Vec<Problem>, ExternalError> {
+        Ok(self.item.to_owned())
+    }
+
+    async fn fetch_contests(&self) -> Result<Vec<ContestMetadata>, ExternalError> {
+        Ok(vec![])
+    }
+
+    async fn fetch_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, ExternalError> {
+        Ok(vec![])
+    }
+
+    async fn fetch_difficulty(&self, problem_id: &str) -> Result<Option<i32>, ExternalError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        if problem_id.ends_with("_x") {
+            return Err(ExternalError::ExternalUnavailable);
+        }
+        Ok(Some(1200))
+    }
+}
+
+#[tokio::test]
+async fn difficulties_are_fetched_concurrently_within_the_budget() -> Result<()> {
+    let stored = vec![Problem {
+        difficulty: Some(400),
+        ..problem("abc234_a", "abc234")
+    }];
+    let port = Arc::new(CountingDifficultyPort {
+        item: (0..10)
+            .map(|i| problem(&format!("abc300_{i}"), "abc300"))
+            .chain([problem("abc234_a", "abc234")])
+            .collect(),
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port.to_owned(),
+        Arc::new(DummyProblemRepository {
+            stored,
+            ..Default::default()
+        }),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        ImportProblemsSettings {
+            difficulty_fetch_concurrency: 3,
+            difficulty_fetch_budget: 6,
+            ..settings()
+        },
+    );
+
+    let changes = uc.run().await?;
+    assert_eq!(changes.inserted, 10);
+    assert_eq!(changes.unchanged, 1);
+    assert_eq!(changes.difficulty_fetch_deferred, 4);
+    assert_eq!(port.calls.load(Ordering::SeqCst), 6);
+    assert_eq!(port.max_in_flight.load(Ordering::SeqCst), 3);
+
+    let calls = calls.lock().unwrap();
+    let with_difficulty = calls
+        .problems
+        .iter()
+        .filter(|problem| problem.difficulty == Some(1200))
+        .count();
+    assert_eq!(with_difficulty, 6);
+    Ok(())
+}
+
+#[tokio::test]
+async fn recompute_fetches_missing_difficulties_first_and_keeps_stored_on_failure() -> Result<()> {
+    let stored = vec![
+        Problem {
+            difficulty: Some(400),
+            ..problem("abc234_x", "abc234")
+        },
+        problem("abc234_b", "abc234"),
+    ];
+    let port = Arc::new(CountingDifficultyPort {
+        item: vec![problem("abc234_x", "abc234"), problem("abc234_b", "abc234")],
+        ..Default::default()
+    });
+    let calls = Arc::new(Mutex::new(TxCalls::default()));
+    let txm = Arc::new(DummyProblemTxManager {
+        shared: calls.clone(),
+    });
+    let uc = ImportProblemsUsecase::new(
+        port.to_owned(),
+        Arc::new(DummyProblemRepository {
+            stored,
+            ..Default::default()
+        }),
+        txm,
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        ImportProblemsSettings {
+            difficulty_fetch_budget: 1,
+            ..settings()
+        },
+    );
+
+    let changes = uc.recompute_difficulties().await?;
+    assert_eq!(changes.difficulty_fetch_deferred, 1);
+    assert_eq!(changes.updated, 1);
+    assert_eq!(calls.lock().unwrap().problems[0].id, "abc234_b");
+
+    // 失敗しても保存済みの難易度は変わらない
+    let uc = ImportProblemsUsecase::new(
+        port,
+        Arc::new(DummyProblemRepository {
+            stored: vec![Problem {
+                difficulty: Some(400),
+                ..problem("abc234_x", "abc234")
+            }],
+            ..Default::default()
+        }),
+        Arc::new(DummyProblemTxManager {
+            shared: Arc::new(Mutex::new(TxCalls::default())),
+        }),
+        Arc::new(DummyProblemImportRunRepository::default()),
+        Arc::new(DummyContestSeriesRepository::default()),
+        settings(),
+    );
+    let changes = uc.run_for_contest("abc234").await?;
+    assert_eq!(changes.difficulty_fetch_failures, 1);
+    assert_eq!(changes.unchanged, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn contest_groups_are_ordered_newest_first() -> Result<()> {
+    let uc = GetContestGroupByContestSeriesUsecase::new(Arc::new(DummyProblemRepository {
+        stored: vec![
+            problem("abc99_a", "abc99"),
+            problem("abc400_a", "abc400"),
+            problem("abc100_a", "abc100"),
+            problem("abc401_a", "abc401"),
+        ],
+        contests: vec![
+            ContestMetadata {
+                start_at: "2025-04-12T12:00:00Z".parse().unwrap(),
+                ..contest_metadata("abc401", "~ 1999")
+            },
+            ContestMetadata {
+                start_at: "2018-04-28T12:00:00Z".parse().unwrap(),
+                ..contest_metadata("abc99", "~ 1999")
+            },
+        ],
+        ..Default::default()
+    }));
+
+    let page = uc
+        .run(
+            ContestSeries::ABC,
+            Some("abc".into()),
+            DifficultyRange::default(),
+            None,
+            None,
+        )
+        .await?;
+    let codes = page
+        .items
+        .0
+        .keys()
+        .map(|key| key.0.code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["abc401", "abc99", "abc400", "abc100"]);
+    assert_eq!(page.total_contest_count, 4);
+    Ok(())
+}
+
+#[tokio::test]
+async fn contest_groups_are_filtered_by_clipped_difficulty() -> Result<()> {
+    let with_difficulty = |id: &str, contest_code: &str, difficulty: Option<i32>| Problem {
+        difficulty,
+        ..problem(id, contest_code)
+    };
+    let uc = GetContestGroupByContestSeriesUsecase::new(Arc::new(DummyProblemRepository {
+        stored: vec![
+            with_difficulty("abc300_a", "abc300", Some(-100)),
+            with_difficulty("abc300_b", "abc300", Some(900)),
+            with_difficulty("abc301_a", "abc301", Some(-2000)),
+            with_difficulty("abc302_a", "abc302", None),
+        ],
+        ..Default::default()
+    }));
+
+    // -100は補正で115になる
+    let page = uc
+        .run(
+            ContestSeries::ABC,
+            Some("abc".into()),
+            DifficultyRange::new(Some(100), Some(1199)),
+            None,
+            None,
+        )
+        .await?;
+    let ids = page
+        .items
+        .0
+        .values()
+        .flatten()
+        .map(|problem| problem.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["abc300_a", "abc300_b"]);
+    Ok(())
+}
+
+fn indexed_problem(contest_code: &str, problem_index: &str) -> Problem {
+    Problem {
+        id: format!("{contest_code}_{}", problem_index.to_lowercase()),
+        problem_index: problem_index.into(),
+        ..problem("", contest_code)
+    }
+}
+
+#[tokio::test]
+async fn contest_problems_are_ordered_by_problem_index() -> Result<()> {
+    let stored = [
+        ("abc300", ["Ex", "B", "H", "A"].as_slice()),
+        ("tessoku-book", &["b1", "a10", "a2", "a1"]),
+        ("typical90", &["AA", "B", "Z", "A"]),
+        ("practice2", &["10", "2", "1"]),
+    ];
+    let repo = Arc::new(DummyProblemRepository {
+        stored: stored
+            .iter()
+            .flat_map(|(contest_code, indexes)| {
+                indexes
+                    .iter()
+                    .map(|index| indexed_problem(contest_code, index))
+            })
+            .collect(),
+        ..Default::default()
+    });
+    let uc = GetProblemsByContestUsecase::new(repo);
+
+    let expected = [
+        ("abc300", ["A", "B", "H", "Ex"].as_slice()),
+        ("tessoku-book", &["a1", "a2", "a10", "b1"]),
+        ("typical90", &["A", "B", "Z", "AA"]),
+        ("practice2", &["1", "2", "10"]),
+    ];
+    for (contest_code, indexes) in expected {
+        let problems = uc.run(contest_code).await?.problems;
+        let actual = problems
+            .iter()
+            .map(|problem| problem.problem_index.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, indexes);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn task_urls_resolve_to_the_imported_problem() -> Result<()> {
+    let repo = Arc::new(DummyProblemRepository {
+        stored: vec![problem("abc395_a", "abc395")],
+        ..Default::default()
+    });
+    let uc = ResolveProblemUrlUsecase::new(repo);
+
+    for url in [
+        "https://atcoder.jp/contests/abc395/tasks/abc395_a",
+        "https://atcoder.jp/contests/adt_easy_20250430_3/tasks/abc395_a?lang=en",
+        "https://atcoder.jp/contests/abc395/submissions?f.Task=abc395_a",
+    ] {
+        assert_eq!(uc.run(url).await?.id, "abc395_a", "{url}");
+    }
+
+    // ADTのURLでも未取り込みなら元のコンテストを返す
+    let err = uc
+        .run("https://atcoder.jp/contests/adt_all_20250501_1/tasks/abc396_b")
+        .await
+        .expect_err("unknown problem should not be imported");
+    assert!(matches!(
+        err,
+        ResolveProblemUrlError::NotImported { ref problem_id, ref contest_code }
+            if problem_id == "abc396_b" && contest_code == "abc396"
+    ));
+
+    for url in [
+        "",
+        "https://example.com/tasks/abc395_a",
+        "https://atcoder.jp/contests/abc395",
+    ] {
+        let err = uc.run(url).await.expect_err("should be bad request");
+        assert!(
+            matches!(err, ResolveProblemUrlError::BadRequest(_)),
+            "{url}"
+        );
+    }
+    Ok(())
+}
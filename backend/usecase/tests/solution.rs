@@ -1,18 +1,44 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
-use domain::error::repository::RepositoryError;
+use domain::{
+    error::repository::RepositoryError,
+    model::{
+        explanation_request::{ExplanationRequest, WantedProblem, WantedProblemFilter},
+        problem::{
+            ContestMetadata, ContestSeries, ContestSeriesCatalog, ContestSortKey, DifficultyBand,
+            DifficultyRange, Problem, ProblemAppearance,
+        },
+    },
+    ports::repository::{
+        explanation_request::ExplanationRequestRepository, problem::ProblemRepository,
+    },
+};
 use usecase::{
+    explanation_request::{
+        list_wanted::ListWantedProblemsUsecase, request::RequestExplanationUsecase,
+    },
+    model::explanation_request::ExplanationRequestError,
+    model::problem::ProblemError,
     model::solution::{
-        SolutionComment, SolutionDetails, SolutionError, SolutionListItem, SolutionListSort,
-        UserSolutionListItem,
+        ProblemSolutionStats, SolutionComment, SolutionDetails, SolutionError, SolutionListItem,
+        SolutionListSort, TagCount, UserSolutionListItem,
     },
+    problem::get_summary::{GetProblemSummaryUsecase, SOLUTION_BODY_PREVIEW_CHARS},
     service::solution::SolutionService,
     solution::{
+        get_by_problem_appearance::GetSolutionsByProblemAppearanceUsecase,
         get_by_problem_id::GetSolutionsByProblemIdUsecase,
-        get_by_user_name::GetSolutionsByUserNameUsecase, get_latest::GetLatestSolutionsUsecase,
+        get_by_user_name::GetSolutionsByUserNameUsecase,
+        get_latest::GetLatestSolutionsUsecase,
+        get_stats_by_problem_ids::{
+            GetSolutionStatsByProblemIdsUsecase, MAX_SOLUTION_STATS_PROBLEM_IDS,
+        },
     },
 };
 use uuid::Uuid;
@@ -20,6 +46,7 @@ use uuid::Uuid;
 struct DummySolutionService {
     problem_exists: bool,
     user_exists: bool,
+    last_problem_id: Mutex<Option<String>>,
     last_problem_sort: Mutex<Option<SolutionListSort>>,
     last_user_sort: Mutex<Option<SolutionListSort>>,
 }
@@ -29,6 +56,7 @@ impl DummySolutionService {
         Self {
             problem_exists,
             user_exists,
+            last_problem_id: Mutex::new(None),
             last_problem_sort: Mutex::new(None),
             last_user_sort: Mutex::new(None),
         }
@@ -40,6 +68,7 @@ impl SolutionService for DummySolutionService {
     async fn get_latest_solutions(
         &self,
         _limit: Option<i32>,
+        _difficulty: DifficultyRange,
     ) -> Result<Vec<SolutionListItem>, RepositoryError> {
         Ok(vec![SolutionListItem {
             id: Uuid::now_v7(),
@@ -55,10 +84,11 @@ impl SolutionService for DummySolutionService {
 
     async fn get_solutions_by_problem_id(
         &self,
-        _problem_id: String,
+        problem_id: String,
         sort: SolutionListSort,
         _limit: Option<i32>,
     ) -> Result<Vec<SolutionListItem>, RepositoryError> {
+        *self.last_problem_id.lock().unwrap() = Some(problem_id);
         *self.last_problem_sort.lock().unwrap() = Some(sort);
         Ok(vec![SolutionListItem {
             id: Uuid::now_v7(),
@@ -74,17 +104,29 @@ impl SolutionService for DummySolutionService {
 
     async fn get_solution_by_solution_id(
         &self,
-        _solution_id: Uuid,
+        solution_id: Uuid,
     ) -> Result<SolutionDetails, RepositoryError> {
-        Err(RepositoryError::Unexpected(
-            "not used in this test".to_string(),
-        ))
+        Ok(SolutionDetails {
+            id: solution_id,
+            title: "t".to_string(),
+            problem_id: "abc100_a".to_string(),
+            contest_code: "abc100".to_string(),
+            problem_title: "A - Sample".to_string(),
+            user_id: "uid".to_string(),
+            user_name: "alice".to_string(),
+            tags: vec!["greedy".to_string()],
+            body_md: "あ".repeat(300),
+            submit_url: String::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
     }
 
     async fn get_solutions_by_user_name(
         &self,
         _user_name: String,
         sort: SolutionListSort,
+        _difficulty: DifficultyRange,
     ) -> Result<Vec<UserSolutionListItem>, RepositoryError> {
         *self.last_user_sort.lock().unwrap() = Some(sort);
         Ok(vec![UserSolutionListItem {
@@ -146,6 +188,37 @@ impl SolutionService for DummySolutionService {
     async fn problem_exists(&self, _problem_id: &str) -> Result<bool, RepositoryError> {
         Ok(self.problem_exists)
     }
+
+    // abc100_aにだけ解説がある
+    async fn get_solution_stats_by_problem_ids(
+        &self,
+        problem_ids: &[String],
+    ) -> Result<Vec<ProblemSolutionStats>, RepositoryError> {
+        Ok(problem_ids
+            .iter()
+            .filter(|problem_id| problem_id.as_str() == "abc100_a")
+            .map(|problem_id| ProblemSolutionStats {
+                solution_count: 2,
+                total_votes: 3,
+                ..ProblemSolutionStats::empty(problem_id.to_owned())
+            })
+            .collect())
+    }
+
+    async fn get_top_tags_by_problem_id(
+        &self,
+        _problem_id: &str,
+        limit: i64,
+    ) -> Result<Vec<TagCount>, RepositoryError> {
+        Ok(["greedy", "dp"]
+            .into_iter()
+            .take(limit as usize)
+            .map(|name| TagCount {
+                name: name.to_string(),
+                solution_count: 1,
+            })
+            .collect())
+    }
 }
 
 #[tokio::test]
@@ -194,12 +267,237 @@ async fn get_solutions_by_problem_id_passes_sort_to_service() -> Result<()> {
     Ok(())
 }
 
+// 出題から問題のidを引く、問題をidやコンテストで引くためだけに使う
+#[derive(Default)]
+struct AppearanceRepository {
+    appearances: Vec<ProblemAppearance>,
+    problems: Vec<Problem>,
+}
+
+#[async_trait]
+impl ProblemRepository for AppearanceRepository {
+    async fn create_records(
+        &self,
+        _problems: Vec<Problem>,
+        _catalog: &ContestSeriesCatalog,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_all_problems(&self) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_problem_ids_with_difficulty(
+        &self,
+        _problem_ids: &[String],
+    ) -> Result<Vec<String>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_problems_by_contest_series(
+        &self,
+        _series: ContestSeries,
+    ) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_contest_codes_by_series(
+        &self,
+        _series: ContestSeries,
+        _difficulty: DifficultyRange,
+        _limit: i64,
+        _offset: i64,
+    ) -> Result<Vec<String>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_problems_by_contest_codes(
+        &self,
+        _contest_codes: &[String],
+        _difficulty: DifficultyRange,
+    ) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn search_problems_by_contest_series(
+        &self,
+        _series: ContestSeries,
+        _query: &str,
+        _difficulty: DifficultyRange,
+    ) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_problem_by_id(&self, problem_id: &str) -> Result<Problem, RepositoryError> {
+        self.problems
+            .iter()
+            .find(|p| p.id == problem_id)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound(problem_id.to_string()))
+    }
+
+    async fn get_problems_by_contest(
+        &self,
+        contest: &str,
+    ) -> Result<Vec<Problem>, RepositoryError> {
+        Ok(self
+            .problems
+            .iter()
+            .filter(|p| p.contest_code == contest)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_all_problem_appearances(&self) -> Result<Vec<ProblemAppearance>, RepositoryError> {
+        Ok(self.appearances.to_owned())
+    }
+
+    async fn get_problem_id_by_appearance(
+        &self,
+        contest_code: &str,
+        problem_index: &str,
+    ) -> Result<String, RepositoryError> {
+        self.appearances
+            .iter()
+            .find(|a| a.contest_code == contest_code && a.problem_index.as_str() == problem_index)
+            .map(|a| a.problem_id.clone())
+            .ok_or_else(|| RepositoryError::NotFound(format!("{contest_code}/{problem_index}")))
+    }
+
+    async fn get_contest_sort_keys(
+        &self,
+        _contest_codes: &[String],
+    ) -> Result<Vec<ContestSortKey>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_all_contest_metadata(&self) -> Result<Vec<ContestMetadata>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_contest_metadata(
+        &self,
+        _contest: &str,
+    ) -> Result<Option<ContestMetadata>, RepositoryError> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn get_solutions_by_problem_appearance_uses_the_canonical_problem() -> Result<()> {
+    let service = Arc::new(DummySolutionService::new(true, true));
+    let repo = Arc::new(AppearanceRepository {
+        appearances: vec![ProblemAppearance {
+            contest_code: "adt_easy_20250430_3".into(),
+            problem_index: "B".into(),
+            problem_id: "abc395_a".into(),
+        }],
+        ..Default::default()
+    });
+    let uc = GetSolutionsByProblemAppearanceUsecase::new(repo, service.clone());
+
+    let result = uc
+        .run("adt_easy_20250430_3", "B", SolutionListSort::Votes, None)
+        .await?;
+    assert_eq!(result.len(), 1);
+    assert_eq!(
+        service.last_problem_id.lock().unwrap().as_deref(),
+        Some("abc395_a")
+    );
+
+    let err = uc
+        .run("adt_easy_20250430_3", "C", SolutionListSort::Latest, None)
+        .await
+        .err()
+        .expect("unknown appearance should be not found");
+    assert!(matches!(err, SolutionError::NotFound(_)));
+
+    let err = uc
+        .run(" ", "B", SolutionListSort::Latest, None)
+        .await
+        .err()
+        .expect("blank contest should be bad request");
+    assert!(matches!(err, SolutionError::BadRequest(_)));
+    Ok(())
+}
+
+fn problem(id: &str, contest_code: &str, problem_index: &str) -> Problem {
+    Problem {
+        id: id.into(),
+        contest_code: contest_code.into(),
+        problem_index: problem_index.into(),
+        title: id.into(),
+        difficulty: None,
+    }
+}
+
+#[tokio::test]
+async fn get_problem_summary_collects_stats_and_neighbors() -> Result<()> {
+    let service = Arc::new(DummySolutionService::new(true, true));
+    // 挿入順ではなく問題のindex順で前後を決める
+    let repo = Arc::new(AppearanceRepository {
+        problems: vec![
+            problem("abc100_c", "abc100", "C"),
+            problem("abc100_a", "abc100", "A"),
+            problem("abc100_b", "abc100", "B"),
+            problem("abc101_a", "abc101", "A"),
+        ],
+        ..Default::default()
+    });
+    let uc = GetProblemSummaryUsecase::new(repo, service.clone());
+
+    let summary = uc.run("abc100_b").await?;
+    assert_eq!(summary.problem.id, "abc100_b");
+    assert_eq!(
+        summary.previous_problem.map(|p| p.id).as_deref(),
+        Some("abc100_a")
+    );
+    assert_eq!(
+        summary.next_problem.map(|p| p.id).as_deref(),
+        Some("abc100_c")
+    );
+    // abc100_bには解説がない
+    assert_eq!(summary.solution_count, 0);
+    assert_eq!(summary.top_tags.len(), 2);
+    let top = summary
+        .top_solution
+        .expect("top solution should be present");
+    assert_eq!(top.tags, vec!["greedy".to_string()]);
+    assert_eq!(
+        top.body_preview.chars().count(),
+        SOLUTION_BODY_PREVIEW_CHARS
+    );
+    assert!(matches!(
+        *service.last_problem_sort.lock().unwrap(),
+        Some(SolutionListSort::Votes)
+    ));
+
+    let summary = uc.run("abc100_a").await?;
+    assert!(summary.previous_problem.is_none());
+    assert_eq!(summary.solution_count, 2);
+    assert_eq!(summary.total_votes, 3);
+
+    let err = uc
+        .run("abc999_a")
+        .await
+        .expect_err("unknown problem should be not found");
+    assert!(matches!(err, ProblemError::NotFound(_)));
+
+    let err = uc
+        .run(" ")
+        .await
+        .expect_err("blank problem id should be bad request");
+    assert!(matches!(err, ProblemError::BadRequest(_)));
+    Ok(())
+}
+
 #[tokio::test]
 async fn get_latest_solutions_returns_items() -> Result<()> {
     let service = Arc::new(DummySolutionService::new(true, true));
     let uc = GetLatestSolutionsUsecase::new(service);
 
-    let result = uc.run(Some(10)).await?;
+    let result = uc.run(Some(10), DifficultyRange::default()).await?;
     assert_eq!(result.len(), 1);
     assert_eq!(result[0].title, "latest");
 
@@ -212,7 +510,11 @@ async fn get_solutions_by_user_name_returns_not_found_for_unknown_user() -> Resu
     let uc = GetSolutionsByUserNameUsecase::new(service);
 
     let err = uc
-        .run("alice".to_string(), SolutionListSort::Latest)
+        .run(
+            "alice".to_string(),
+            SolutionListSort::Latest,
+            DifficultyRange::default(),
+        )
         .await
         .err()
         .expect("unknown user should be not found");
@@ -225,7 +527,13 @@ async fn get_solutions_by_user_name_passes_sort_to_service() -> Result<()> {
     let service = Arc::new(DummySolutionService::new(true, true));
     let uc = GetSolutionsByUserNameUsecase::new(service.clone());
 
-    let result = uc.run("alice".to_string(), SolutionListSort::Votes).await?;
+    let result = uc
+        .run(
+            "alice".to_string(),
+            SolutionListSort::Votes,
+            DifficultyRange::default(),
+        )
+        .await?;
     assert_eq!(result.len(), 1);
     assert_eq!(result[0].problem_title, "A - Sample");
 
@@ -233,3 +541,143 @@ async fn get_solutions_by_user_name_passes_sort_to_service() -> Result<()> {
     assert!(matches!(sort, Some(SolutionListSort::Votes)));
     Ok(())
 }
+
+#[tokio::test]
+async fn get_solution_stats_fills_problems_without_solutions_in_request_order() -> Result<()> {
+    let service = Arc::new(DummySolutionService::new(true, true));
+    let uc = GetSolutionStatsByProblemIdsUsecase::new(service);
+
+    let stats = uc
+        .run(vec![
+            "abc100_b".into(),
+            " abc100_a ".into(),
+            "abc100_b".into(),
+        ])
+        .await?;
+    assert_eq!(
+        stats
+            .iter()
+            .map(|s| (s.problem_id.as_str(), s.solution_count, s.total_votes))
+            .collect::<Vec<_>>(),
+        vec![("abc100_b", 0, 0), ("abc100_a", 2, 3)]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_solution_stats_rejects_empty_blank_and_too_many_ids() -> Result<()> {
+    let service = Arc::new(DummySolutionService::new(true, true));
+    let uc = GetSolutionStatsByProblemIdsUsecase::new(service);
+
+    let too_many = (0..=MAX_SOLUTION_STATS_PROBLEM_IDS)
+        .map(|i| format!("abc{i}_a"))
+        .collect::<Vec<_>>();
+    for problem_ids in [vec![], vec!["abc100_a".into(), " ".into()], too_many] {
+        let err = uc
+            .run(problem_ids)
+            .await
+            .expect_err("should be bad request");
+        assert!(matches!(err, SolutionError::BadRequest(_)));
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct DummyExplanationRequestRepository {
+    requests: Mutex<HashSet<(String, String)>>,
+    last_filter: Mutex<Option<WantedProblemFilter>>,
+}
+
+#[async_trait]
+impl ExplanationRequestRepository for DummyExplanationRequestRepository {
+    async fn create(&self, user_id: &str, problem_id: &str) -> Result<bool, RepositoryError> {
+        Ok(self
+            .requests
+            .lock()
+            .unwrap()
+            .insert((user_id.to_string(), problem_id.to_string())))
+    }
+
+    async fn delete(&self, user_id: &str, problem_id: &str) -> Result<(), RepositoryError> {
+        self.requests
+            .lock()
+            .unwrap()
+            .remove(&(user_id.to_string(), problem_id.to_string()));
+        Ok(())
+    }
+
+    async fn list_by_user(
+        &self,
+        _user_id: &str,
+    ) -> Result<Vec<ExplanationRequest>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn list_wanted(
+        &self,
+        filter: &WantedProblemFilter,
+    ) -> Result<Vec<WantedProblem>, RepositoryError> {
+        *self.last_filter.lock().unwrap() = Some(filter.clone());
+        Ok(vec![])
+    }
+}
+
+#[tokio::test]
+async fn request_explanation_is_accepted_once_per_user_and_problem() -> Result<()> {
+    let repository = Arc::new(DummyExplanationRequestRepository::default());
+    let service = Arc::new(DummySolutionService::new(true, true));
+    let uc = RequestExplanationUsecase::new(repository.clone(), service);
+
+    assert!(uc.run("alice".into(), " abc100_b ").await?);
+    assert!(!uc.run("alice".into(), "abc100_b").await?);
+    assert!(uc.run("bob".into(), "abc100_b").await?);
+    assert_eq!(repository.requests.lock().unwrap().len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_explanation_rejects_solved_and_unknown_problems() -> Result<()> {
+    let repository = Arc::new(DummyExplanationRequestRepository::default());
+
+    let uc = RequestExplanationUsecase::new(
+        repository.clone(),
+        Arc::new(DummySolutionService::new(true, true)),
+    );
+    let err = uc
+        .run("alice".into(), "abc100_a")
+        .await
+        .expect_err("should be conflict");
+    assert!(matches!(err, ExplanationRequestError::Conflict(_)));
+
+    let uc = RequestExplanationUsecase::new(
+        repository.clone(),
+        Arc::new(DummySolutionService::new(false, true)),
+    );
+    let err = uc
+        .run("alice".into(), "abc999_z")
+        .await
+        .expect_err("should be not found");
+    assert!(matches!(err, ExplanationRequestError::NotFound(_)));
+
+    assert!(repository.requests.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_wanted_problems_clamps_limit() -> Result<()> {
+    let repository = Arc::new(DummyExplanationRequestRepository::default());
+    let uc = ListWantedProblemsUsecase::new(repository.clone());
+
+    uc.run(
+        ContestSeries::ARC,
+        Some(DifficultyBand::Green),
+        Some(1000),
+        Some(20),
+    )
+    .await?;
+    let filter = repository.last_filter.lock().unwrap().clone().unwrap();
+    assert_eq!(filter.series, ContestSeries::ARC);
+    assert_eq!(filter.band, Some(DifficultyBand::Green));
+    assert_eq!((filter.limit, filter.offset), (100, 20));
+    Ok(())
+}
@@ -4,15 +4,17 @@ use anyhow::Result;
 use async_trait::async_trait;
 use domain::{
     error::repository::RepositoryError,
+    model::problem::DifficultyRange,
     ports::repository::solution::tx::{
-        CommentRepositoryTx, SolutionRespositoryTx, SolutionTxManager, TagRepositoryTx, UnitOfWork,
-        VoteRepositoryTx,
+        CommentRepositoryTx, ExplanationRequestRepositoryTx, SolutionRespositoryTx,
+        SolutionTxManager, TagRepositoryTx, UnitOfWork, VoteRepositoryTx,
     },
 };
 use usecase::{
     model::solution::{
-        SolutionComment, SolutionDetails, SolutionError, SolutionListItem, SolutionListSort,
-        UserSolutionListItem, update::UpdateSolutionInput, update_comment::UpdateCommentInput,
+        ProblemSolutionStats, SolutionComment, SolutionDetails, SolutionError, SolutionListItem,
+        SolutionListSort, TagCount, UserSolutionListItem, update::UpdateSolutionInput,
+        update_comment::UpdateCommentInput,
     },
     service::solution::SolutionService,
     solution::{
@@ -47,6 +49,7 @@ impl SolutionService for GateService {
     async fn get_latest_solutions(
         &self,
         _limit: Option<i32>,
+        _difficulty: DifficultyRange,
     ) -> Result<Vec<SolutionListItem>, RepositoryError> {
         Ok(vec![])
     }
@@ -71,6 +74,7 @@ impl SolutionService for GateService {
         &self,
         _user_name: String,
         _sort: SolutionListSort,
+        _difficulty: DifficultyRange,
     ) -> Result<Vec<UserSolutionListItem>, RepositoryError> {
         Ok(vec![])
     }
@@ -121,6 +125,21 @@ impl SolutionService for GateService {
     async fn problem_exists(&self, _problem_id: &str) -> Result<bool, RepositoryError> {
         Ok(true)
     }
+
+    async fn get_solution_stats_by_problem_ids(
+        &self,
+        _problem_ids: &[String],
+    ) -> Result<Vec<ProblemSolutionStats>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_top_tags_by_problem_id(
+        &self,
+        _problem_id: &str,
+        _limit: i64,
+    ) -> Result<Vec<TagCount>, RepositoryError> {
+        Ok(vec![])
+    }
 }
 
 #[tokio::test]
@@ -309,6 +328,13 @@ impl SolutionRespositoryTx for _NoopSolutionRepo {
     async fn delete(&mut self, _solution_id: Uuid) -> Result<(), RepositoryError> {
         Err(RepositoryError::Unexpected("unused".to_string()))
     }
+    async fn set_hidden(
+        &mut self,
+        _solution_id: Uuid,
+        _hidden: bool,
+    ) -> Result<(), RepositoryError> {
+        Err(RepositoryError::Unexpected("unused".to_string()))
+    }
     async fn replace_tags(
         &mut self,
         _solution_id: Uuid,
@@ -329,7 +355,7 @@ impl TagRepositoryTx for _NoopTagRepo {
 struct _NoopVoteRepo;
 #[async_trait]
 impl VoteRepositoryTx for _NoopVoteRepo {
-    async fn like(&mut self, _user_id: &str, _solution_id: Uuid) -> Result<(), RepositoryError> {
+    async fn like(&mut self, _user_id: &str, _solution_id: Uuid) -> Result<bool, RepositoryError> {
         Err(RepositoryError::Unexpected("unused".to_string()))
     }
     async fn unlike(&mut self, _user_id: &str, _solution_id: Uuid) -> Result<(), RepositoryError> {
@@ -358,6 +384,25 @@ impl CommentRepositoryTx for _NoopCommentRepo {
     async fn delete_comment(&mut self, _comment_id: Uuid) -> Result<(), RepositoryError> {
         Err(RepositoryError::Unexpected("unused".to_string()))
     }
+    async fn set_comment_hidden(
+        &mut self,
+        _comment_id: Uuid,
+        _hidden: bool,
+    ) -> Result<(), RepositoryError> {
+        Err(RepositoryError::Unexpected("unused".to_string()))
+    }
+}
+
+struct _NoopExplanationRequestRepo;
+#[async_trait]
+impl ExplanationRequestRepositoryTx for _NoopExplanationRequestRepo {
+    async fn fulfill_explanation_requests(
+        &mut self,
+        _problem_id: &str,
+        _solution_id: Uuid,
+    ) -> Result<u64, RepositoryError> {
+        Err(RepositoryError::Unexpected("unused".to_string()))
+    }
 }
 
 #[allow(dead_code)]
@@ -366,6 +411,7 @@ struct _NoopUow {
     t: _NoopTagRepo,
     v: _NoopVoteRepo,
     c: _NoopCommentRepo,
+    e: _NoopExplanationRequestRepo,
 }
 
 #[async_trait]
@@ -382,6 +428,9 @@ impl UnitOfWork for _NoopUow {
     fn comments(&mut self) -> &mut dyn CommentRepositoryTx {
         &mut self.c
     }
+    fn explanation_requests(&mut self) -> &mut dyn ExplanationRequestRepositoryTx {
+        &mut self.e
+    }
     async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
         let _ = self;
         Ok(())
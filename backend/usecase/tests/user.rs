@@ -2,15 +2,23 @@ use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use domain::{
     error::repository::RepositoryError,
-    model::user::{Role, User},
-    ports::repository::user::UserRepository,
+    model::{
+        session::{SessionActivity, SessionStatus, TouchedSession, UserSession},
+        user::{Role, User},
+    },
+    ports::repository::{session::SessionRepository, user::UserRepository},
 };
 use usecase::{
     model::user::{UserError, create::CreateUserInput},
-    user::create_user::CreateUserUsecase,
+    user::{
+        change_role::ChangeRoleUsecase, create_user::CreateUserUsecase,
+        list_sessions::ListSessionsUsecase, revoke_tokens::RevokeTokensUsecase,
+    },
 };
+use uuid::Uuid;
 
 struct DummyUserRepository {
     calls: Mutex<Vec<User>>,
@@ -37,6 +45,18 @@ impl UserRepository for DummyUserRepository {
         Err(RepositoryError::NotFound("Not found".into()))
     }
 
+    async fn update_role(&self, uid: &str, role: Role) -> Result<(), RepositoryError> {
+        if uid != "valid id" {
+            return Err(RepositoryError::NotFound("Not found".into()));
+        }
+        self.calls.lock().unwrap().push(User {
+            id: uid.into(),
+            role,
+            user_name: "valid user".into(),
+        });
+        Ok(())
+    }
+
     async fn delete_by_uid(&self, _uid: &str) -> Result<(), RepositoryError> {
         Ok(())
     }
@@ -50,6 +70,54 @@ impl UserRepository for DummyUserRepository {
     }
 }
 
+#[derive(Default)]
+struct DummySessionRepository {
+    revoked_all: Mutex<Vec<String>>,
+}
+
+fn session(authenticated_at: i64) -> UserSession {
+    let at = DateTime::<Utc>::from_timestamp(authenticated_at, 0).unwrap();
+    UserSession {
+        id: Uuid::now_v7(),
+        user_id: "valid id".into(),
+        authenticated_at: at,
+        user_agent: None,
+        ip_address: None,
+        created_at: at,
+        last_seen_at: at,
+    }
+}
+
+#[async_trait]
+impl SessionRepository for DummySessionRepository {
+    async fn touch(&self, _activity: &SessionActivity) -> Result<TouchedSession, RepositoryError> {
+        Ok(TouchedSession {
+            id: Uuid::nil(),
+            revoked: false,
+        })
+    }
+    async fn find_status(
+        &self,
+        _user_id: &str,
+        _authenticated_at: DateTime<Utc>,
+    ) -> Result<Option<SessionStatus>, RepositoryError> {
+        Ok(None)
+    }
+    async fn list_active_by_user(
+        &self,
+        _user_id: &str,
+    ) -> Result<Vec<UserSession>, RepositoryError> {
+        Ok(vec![session(1_700_000_000), session(1_700_000_100)])
+    }
+    async fn revoke(&self, _user_id: &str, _session_id: Uuid) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn revoke_all_by_user(&self, user_id: &str) -> Result<(), RepositoryError> {
+        self.revoked_all.lock().unwrap().push(user_id.into());
+        Ok(())
+    }
+}
+
 #[tokio::test]
 async fn usecase_create_user_ok() -> Result<()> {
     let repo = Arc::new(DummyUserRepository {
@@ -134,3 +202,60 @@ async fn usecase_create_user_bad_request_when_user_name_contains_non_ascii() ->
 
     Ok(())
 }
+
+#[tokio::test]
+async fn usecase_list_sessions_marks_current_session() -> Result<()> {
+    let uc = ListSessionsUsecase::new(Arc::new(DummySessionRepository::default()));
+
+    let sessions = uc.run("valid id".into(), 1_700_000_100).await?;
+
+    assert_eq!(sessions.len(), 2);
+    assert!(!sessions[0].current);
+    assert!(sessions[1].current);
+    Ok(())
+}
+
+#[tokio::test]
+async fn usecase_revoke_tokens_also_revokes_sessions() -> Result<()> {
+    let users = Arc::new(DummyUserRepository {
+        calls: Mutex::new(vec![]),
+    });
+    let sessions = Arc::new(DummySessionRepository::default());
+    let uc = RevokeTokensUsecase::new(users, sessions.to_owned());
+
+    let output = uc.run("valid id".into()).await?;
+
+    assert_eq!(output.id, "valid id");
+    assert_eq!(*sessions.revoked_all.lock().unwrap(), vec!["valid id"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn change_role_updates_the_user_role() -> Result<()> {
+    let repo = Arc::new(DummyUserRepository {
+        calls: Mutex::new(vec![]),
+    });
+    let uc = ChangeRoleUsecase::new(repo.clone());
+
+    let output = uc.run("valid id".into(), Role::Admin).await?;
+    assert_eq!(output.role, Role::Admin);
+    let calls = repo.calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].role, Role::Admin);
+    Ok(())
+}
+
+#[tokio::test]
+async fn change_role_for_unknown_user_is_not_found() -> Result<()> {
+    let repo = Arc::new(DummyUserRepository {
+        calls: Mutex::new(vec![]),
+    });
+    let uc = ChangeRoleUsecase::new(repo);
+
+    let err = uc
+        .run("unknown".into(), Role::Admin)
+        .await
+        .expect_err("should be not found");
+    assert!(matches!(err, UserError::NotFound(_)));
+    Ok(())
+}